@@ -1,6 +1,14 @@
-use edidr::parse;
+use edidr::{parse, EDID};
 
-use nom::Err::{Incomplete, Error, Failure};
+// Prints the debug representation, or JSON with `--json` and the serde feature
+fn print(parsed: &EDID) {
+    #[cfg(feature = "serde")]
+    if std::env::args().any(|arg| arg == "--json") {
+        println!("{}", parsed.to_json());
+        return;
+    }
+    println!("parsed: {:#?}", parsed);
+}
 
 fn main() {
 
@@ -8,13 +16,10 @@ fn main() {
 
     match parse(d) {
         Ok((_, parsed)) => {
-            println!("parsed: {:#?}", parsed);
+            print(&parsed);
         },
-        Err(Incomplete(_needed)) => { 
-            panic!("Incomplete");
-         },
-        Err(Error(e)) | Err(Failure(e)) => { 
-            panic!("{}", format!("{:?}", e));
-         }
+        Err(e) => {
+            panic!("{}", e);
+        }
     }
 }
\ No newline at end of file
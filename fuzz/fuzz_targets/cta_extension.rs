@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(cta) = edidr::fuzzing::parse_cta_extension(data) {
+        let _ = cta.short_video_descriptors().count();
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok((_, edid)) = edidr::parse(data) {
+        // Whatever parses must also go through the accessors and back
+        let _ = edid.product_info();
+        let _ = edid.native_mode();
+        let _ = edid.capabilities();
+        if let Ok(bytes) = edid.to_bytes() {
+            let _ = edidr::parse(&bytes);
+        }
+    }
+});
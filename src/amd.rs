@@ -0,0 +1,78 @@
+use nom::{
+    combinator::opt, error::VerboseError, number::complete::le_u8, sequence::tuple, IResult,
+};
+
+use crate::{cta::VendorSpecific, extended::HdrStaticMetadata};
+
+/// AMD FreeSync range and flags, the payload following the AMD OUI.
+///
+/// AMD doesn't publish the layout, this follows what edid-decode reverse
+/// engineered.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FreeSync {
+    pub version: (u8, u8),
+    /// Minimum refresh rate in Hz.
+    pub min_refresh_rate: u8,
+    /// Maximum refresh rate in Hz.
+    pub max_refresh_rate: u8,
+    /// FreeSync 1.x flags, `MCCS_MASK` bits signal the range is switched
+    /// over VESA MCCS.
+    pub flags: u8,
+    /// FreeSync 2.x flags, bit 2 probably being local dimming.
+    pub flags_v2: Option<u8>,
+    /// Luminance codes, as in `HdrStaticMetadata`.
+    pub max_luminance: Option<u8>,
+    pub min_luminance: Option<u8>,
+}
+
+impl FreeSync {
+    pub const MCCS_MASK: u8 = 0xe6;
+
+    pub fn max_luminance_cd_m2(&self) -> Option<f64> {
+        self.max_luminance.map(HdrStaticMetadata::luminance)
+    }
+
+    pub fn min_luminance_cd_m2(&self) -> Option<f64> {
+        Some(HdrStaticMetadata::min_luminance_from(
+            self.max_luminance?,
+            self.min_luminance?,
+        ))
+    }
+}
+
+fn parse_freesync(payload: &[u8]) -> IResult<&[u8], FreeSync, VerboseError<&[u8]>> {
+    let (payload, (major, minor, min_refresh_rate, max_refresh_rate, flags)) =
+        tuple((le_u8, le_u8, le_u8, le_u8, le_u8))(payload)?;
+    // FreeSync 2 blocks add flags and the luminance range
+    let (payload, v2) = opt(tuple((le_u8, le_u8, le_u8)))(payload)?;
+    Ok((
+        payload,
+        FreeSync {
+            version: (major, minor),
+            min_refresh_rate,
+            max_refresh_rate,
+            flags,
+            flags_v2: v2.map(|(flags, _, _)| flags),
+            max_luminance: v2.map(|(_, max, _)| max),
+            min_luminance: v2.map(|(_, _, min)| min),
+        },
+    ))
+}
+
+impl VendorSpecific {
+    pub const AMD_OUI: u32 = 0x00001a;
+
+    /// Decodes the payload of AMD blocks, `None` for other vendors or if the
+    /// payload is too short.
+    pub fn freesync(&self) -> Option<FreeSync> {
+        if self.oui() != VendorSpecific::AMD_OUI {
+            return None;
+        }
+        parse_freesync(&self.payload)
+            .ok()
+            .map(|(_, freesync)| freesync)
+    }
+}
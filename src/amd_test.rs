@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use crate::{amd::*, builder::*, cta::*, edid::*};
+
+    fn freesync(payload: &[u8]) -> Option<FreeSync> {
+        let mut block = vec![0x1a, 0x00, 0x00];
+        block.extend_from_slice(payload);
+        let cta = CtaBuilder::new().raw_data_block(3, &block).build().unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&cta);
+
+        let (_, edid) = parse(&data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        match &cta.blocks[0] {
+            DataBlock::VendorSpecific(vsdb) => vsdb.freesync(),
+            block => panic!("unexpected block {:?}", block),
+        }
+    }
+
+    #[test]
+    fn test_freesync() {
+        assert_eq!(
+            freesync(&[0x01, 0x00, 48, 144, 0x00]),
+            Some(FreeSync {
+                version: (1, 0),
+                min_refresh_rate: 48,
+                max_refresh_rate: 144,
+                flags: 0,
+                flags_v2: None,
+                max_luminance: None,
+                min_luminance: None,
+            })
+        );
+
+        let v2 = freesync(&[0x02, 0x00, 40, 165, 0x00, 0x07, 128, 50]).unwrap();
+        assert_eq!(v2.flags_v2, Some(0x07));
+        assert_eq!(v2.max_luminance_cd_m2(), Some(800.0));
+        assert!((v2.min_luminance_cd_m2().unwrap() - 0.3075740).abs() < 1e-6);
+
+        assert_eq!(freesync(&[0x01, 0x00, 48]), None);
+    }
+}
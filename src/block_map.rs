@@ -0,0 +1,108 @@
+use nom::{bytes::complete::take, error::VerboseError, IResult};
+
+use crate::{edid::EDID, extension::Extension};
+
+/// Block Map extension, listing the tags of the blocks following it.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BlockMap {
+    /// Unused trailing entries are dropped.
+    pub tags: Vec<u8>,
+}
+
+pub(crate) fn parse_block_map(input: &[u8]) -> IResult<&[u8], BlockMap, VerboseError<&[u8]>> {
+    let (input, block) = take(128u8)(input)?;
+    let tags = block[1..127]
+        .iter()
+        .take_while(|tag| **tag != 0)
+        .copied()
+        .collect();
+    Ok((input, BlockMap { tags }))
+}
+
+/// A block whose tag doesn't match the one listed in the Block Map.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct BlockMapMismatch {
+    /// Index of the block, the base block being 0.
+    pub block: usize,
+    /// Tag listed in the Block Map, if any.
+    pub expected: Option<u8>,
+    /// Tag of the actual block, if present.
+    pub found: Option<u8>,
+}
+
+impl EDID {
+    /// Inserts an extension block at `index` in the extension list, i.e.
+    /// as block `index + 1`, and updates the Block Maps.
+    ///
+    /// The extension count and the checksums are derived from the list when
+    /// the EDID is encoded, so they need no updating.
+    ///
+    /// Panics if `index > extensions.len()`.
+    pub fn insert_extension(&mut self, index: usize, extension: Extension) {
+        self.extensions.insert(index, extension);
+        self.update_block_maps();
+    }
+
+    /// Appends an extension block and updates the Block Maps.
+    pub fn push_extension(&mut self, extension: Extension) {
+        self.extensions.push(extension);
+        self.update_block_maps();
+    }
+
+    /// Removes the extension block at `index` and updates the Block Maps.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove_extension(&mut self, index: usize) -> Extension {
+        let extension = self.extensions.remove(index);
+        self.update_block_maps();
+        extension
+    }
+
+    /// Rewrites each Block Map to list the blocks following it.
+    fn update_block_maps(&mut self) {
+        let tags: Vec<u8> = self.extensions.iter().map(Extension::tag).collect();
+        for (i, extension) in self.extensions.iter_mut().enumerate() {
+            if let Extension::BlockMap(map) = extension {
+                map.tags = tags[i + 1..]
+                    .iter()
+                    .copied()
+                    .take_while(|tag| *tag != Extension::BLOCK_MAP)
+                    .take(126)
+                    .collect();
+            }
+        }
+    }
+
+    /// Compares the Block Maps against the extension blocks following them.
+    pub fn block_map_mismatches(&self) -> Vec<BlockMapMismatch> {
+        let mut mismatches = Vec::new();
+        for (i, extension) in self.extensions.iter().enumerate() {
+            let map = match extension {
+                Extension::BlockMap(map) => map,
+                _ => continue,
+            };
+
+            // A Block Map covers the blocks up to the next Block Map
+            let following: Vec<u8> = self.extensions[i + 1..]
+                .iter()
+                .map(Extension::tag)
+                .take_while(|tag| *tag != Extension::BLOCK_MAP)
+                .collect();
+            for j in 0..map.tags.len().max(following.len()) {
+                let expected = map.tags.get(j).copied();
+                let found = following.get(j).copied();
+                if expected != found {
+                    mismatches.push(BlockMapMismatch {
+                        block: i + j + 2,
+                        expected,
+                        found,
+                    });
+                }
+            }
+        }
+        mismatches
+    }
+}
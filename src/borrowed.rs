@@ -0,0 +1,142 @@
+use std::borrow::Cow;
+
+use nom::{bytes::complete::take, error::VerboseError, sequence::pair, IResult};
+
+use crate::{
+    edid::{
+        descriptor_text, parse_detailed_timing, parse_display, parse_header, parse_range_limits,
+        parse_standard_timing_entry, DetailedTiming, Display, Header, RangeLimits, StandardTiming,
+        BLOCK_SIZE, EDID,
+    },
+    error::EdidError,
+};
+
+/// An EDID borrowing the bytes it was parsed from.
+///
+/// Parsing only reads the header and sizes the extension blocks, the rest
+/// is decoded on access without allocating. Extension blocks are returned
+/// raw and aren't validated, use `to_edid` for the full data model.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct EdidRef<'a> {
+    pub header: Header,
+    pub display: Display,
+    data: &'a [u8],
+}
+
+/// A base block descriptor, borrowing its text when it's printable ASCII.
+#[derive(Debug, PartialEq, Clone)]
+pub enum DescriptorRef<'a> {
+    DetailedTiming(DetailedTiming),
+    SerialNumber(Cow<'a, str>),
+    UnspecifiedText(Cow<'a, str>),
+    RangeLimits(RangeLimits),
+    ProductName(Cow<'a, str>),
+    /// Any other display descriptor, with the 13 bytes after the flags.
+    Other {
+        tag: u8,
+        payload: &'a [u8],
+    },
+}
+
+impl<'a> DescriptorRef<'a> {
+    fn parse(data: &'a [u8]) -> Self {
+        if data[0] != 0 || data[1] != 0 {
+            let (_, timing) = parse_detailed_timing(data).unwrap();
+            return DescriptorRef::DetailedTiming(timing);
+        }
+        let (tag, flags, payload) = (data[3], data[4], &data[5..]);
+        match tag {
+            0xFF => DescriptorRef::SerialNumber(descriptor_text(payload)),
+            0xFE => DescriptorRef::UnspecifiedText(descriptor_text(payload)),
+            0xFD => DescriptorRef::RangeLimits(parse_range_limits(flags, payload)),
+            0xFC => DescriptorRef::ProductName(descriptor_text(payload)),
+            _ => DescriptorRef::Other { tag, payload },
+        }
+    }
+}
+
+/// The extension count of an EEODB in a raw CTA extension block, if any.
+fn extension_override(block: &[u8]) -> Option<usize> {
+    if block[0] != 0x02 || block[2] < 4 {
+        return None;
+    }
+    let mut blocks = &block[4..(block[2] as usize).min(127)];
+    while let Some((&header, rest)) = blocks.split_first() {
+        let len = (header & 0x1f) as usize;
+        let payload = rest.get(..len)?;
+        if header >> 5 == 7 && len >= 2 && payload[0] == 0x78 {
+            return Some(payload[1] as usize);
+        }
+        blocks = &rest[len..];
+    }
+    None
+}
+
+fn parse_edid_ref(input: &[u8]) -> IResult<&[u8], EdidRef<'_>, VerboseError<&[u8]>> {
+    let (_, (header, display)) = pair(parse_header, parse_display)(input)?;
+    let (rest, base) = take(BLOCK_SIZE)(input)?;
+    let mut count = base[126] as usize;
+    if count > 0 {
+        // HDMI 2.1 sinks may declare the real count in the first extension
+        let (_, first) = take(BLOCK_SIZE)(rest)?;
+        if let Some(total) = extension_override(first) {
+            count = count.max(total);
+        }
+    }
+    let (rest, _) = take(BLOCK_SIZE * count)(rest)?;
+    let data = &input[..BLOCK_SIZE * (count + 1)];
+    Ok((
+        rest,
+        EdidRef {
+            header,
+            display,
+            data,
+        },
+    ))
+}
+
+impl<'a> EdidRef<'a> {
+    /// Like [`EDID::parse`], without copying `data`. Bytes after the last
+    /// extension block are ignored.
+    pub fn parse(data: &'a [u8]) -> Result<Self, EdidError> {
+        parse_edid_ref(data)
+            .map(|(_, edid)| edid)
+            .map_err(|err| EdidError::from_nom(data, err))
+    }
+
+    /// The base block and the extension blocks.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn standard_timings(&self) -> impl Iterator<Item = StandardTiming> + 'a {
+        let (version, revision) = (self.header.version, self.header.revision);
+        self.data[38..54].chunks(2).filter_map(move |entry| {
+            parse_standard_timing_entry(entry, version, revision)
+                .ok()
+                .and_then(|(_, timing)| timing)
+        })
+    }
+
+    pub fn descriptors(&self) -> impl Iterator<Item = DescriptorRef<'a>> + 'a {
+        self.data[54..126].chunks(18).map(DescriptorRef::parse)
+    }
+
+    /// The raw 128-byte extension blocks.
+    pub fn extension_blocks(&self) -> impl Iterator<Item = &'a [u8]> + 'a {
+        self.data[BLOCK_SIZE..].chunks(BLOCK_SIZE)
+    }
+
+    /// The first product name descriptor.
+    pub fn product_name(&self) -> Option<Cow<'a, str>> {
+        self.descriptors().find_map(|d| match d {
+            DescriptorRef::ProductName(name) => Some(name),
+            _ => None,
+        })
+    }
+
+    /// Parses the full data model, extension blocks included.
+    pub fn to_edid(&self) -> Result<EDID, EdidError> {
+        EDID::parse(self.as_bytes())
+    }
+}
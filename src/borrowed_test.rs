@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use crate::{borrowed::*, edid::*};
+
+    const SAMPLES: &[&[u8]] = &[
+        include_bytes!("../testdata/card0-HDMI-1.bin"),
+        include_bytes!("../testdata/card0-HDMI-2.bin"),
+        include_bytes!("../testdata/card0-LVDS-1.bin"),
+        include_bytes!("../testdata/card0-VGA-1.bin"),
+        include_bytes!("../testdata/card0-eDP-1.bin"),
+    ];
+
+    #[test]
+    fn test_matches_owned() {
+        for data in SAMPLES {
+            let edid = EDID::parse(data).unwrap();
+            let borrowed = EdidRef::parse(data).unwrap();
+            assert_eq!(borrowed.header, edid.header);
+            assert_eq!(borrowed.display, edid.display);
+            assert_eq!(
+                borrowed.standard_timings().collect::<Vec<_>>(),
+                edid.standard_timing
+            );
+            assert_eq!(borrowed.extension_blocks().count(), edid.extensions.len());
+            assert_eq!(borrowed.to_edid().unwrap(), edid);
+
+            for (d, owned) in borrowed.descriptors().zip(&edid.descriptors) {
+                match (d, owned) {
+                    (DescriptorRef::DetailedTiming(a), Descriptor::DetailedTiming(b)) => {
+                        assert_eq!(&a, b)
+                    }
+                    (DescriptorRef::ProductName(a), Descriptor::ProductName(b))
+                    | (DescriptorRef::SerialNumber(a), Descriptor::SerialNumber(b))
+                    | (DescriptorRef::UnspecifiedText(a), Descriptor::UnspecifiedText(b)) => {
+                        assert_eq!(&a, b)
+                    }
+                    (DescriptorRef::RangeLimits(a), Descriptor::RangeLimits(b)) => {
+                        assert_eq!(&a, b)
+                    }
+                    (DescriptorRef::Other { .. }, _) => {}
+                    (a, b) => panic!("{:?} parsed as {:?}", a, b),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_ascii_text() {
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        let name = EdidRef::parse(&data).unwrap().product_name().unwrap();
+        assert!(matches!(name, Cow::Borrowed(_)));
+        assert_eq!(name, "SyncMaster");
+
+        // The product name descriptor is the third one
+        data[90 + 5] = 0x81;
+        let name = EdidRef::parse(&data).unwrap().product_name().unwrap();
+        assert!(matches!(name, Cow::Owned(_)));
+        assert_eq!(name, "üyncMaster");
+    }
+
+    #[test]
+    fn test_trailing_and_truncated() {
+        let d = SAMPLES[0];
+        let mut data = d.to_vec();
+        data.extend_from_slice(&[0xaa; 7]);
+        assert_eq!(EdidRef::parse(&data).unwrap().as_bytes(), d);
+
+        assert_eq!(
+            EdidRef::parse(&d[..200]),
+            Err(EDID::parse(&d[..200]).unwrap_err())
+        );
+    }
+}
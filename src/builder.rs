@@ -0,0 +1,532 @@
+use std::fmt;
+
+use crate::{
+    cp437,
+    edid::{parse, DetailedTiming, Display, Header, EDID},
+    encode,
+    error::EdidError,
+    limits::MAX_EXTENSIONS,
+    mode::cvt_timing,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum BuildError {
+    /// A data block payload doesn't fit in the 5-bit length field.
+    DataBlockTooLong { tag: u8, len: usize },
+    /// The data blocks and DTDs don't fit in a single extension block.
+    BlockOverflow { len: usize },
+    /// More than 4 descriptors were added to the base block.
+    TooManyDescriptors { count: usize },
+    /// Descriptor strings hold at most 13 bytes.
+    TextTooLong { len: usize },
+    /// More extension blocks than the parser accepts.
+    TooManyExtensions { count: usize },
+    /// The PNP manufacturer ID isn't three letters from `A` to `Z`.
+    InvalidVendor { vendor: [char; 3] },
+    /// A detailed timing field doesn't fit in its bits of the descriptor,
+    /// or the porch and sync don't fit in the blanking.
+    InvalidTiming { field: &'static str, value: u32 },
+    /// The built EDID doesn't parse back, e.g. because a raw data block
+    /// exceeds a parser limit.
+    Parse(EdidError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BuildError::DataBlockTooLong { tag, len } => write!(
+                f,
+                "data block with tag {} has a {} byte payload, at most 31 are allowed",
+                tag, len
+            ),
+            BuildError::BlockOverflow { len } => write!(
+                f,
+                "extension content is {} bytes, at most 123 fit in a block",
+                len
+            ),
+            BuildError::TooManyDescriptors { count } => write!(
+                f,
+                "{} descriptors were added, the base block holds 4",
+                count
+            ),
+            BuildError::TextTooLong { len } => write!(
+                f,
+                "descriptor text is {} bytes, at most 13 are allowed",
+                len
+            ),
+            BuildError::TooManyExtensions { count } => write!(
+                f,
+                "{} extension blocks were added, at most {} are allowed",
+                count, MAX_EXTENSIONS
+            ),
+            BuildError::InvalidVendor { vendor } => write!(
+                f,
+                "vendor {:?} isn't three letters from A to Z",
+                vendor.iter().collect::<String>()
+            ),
+            BuildError::InvalidTiming { field, value } => {
+                write!(f, "detailed timing {} {} can't be encoded", field, value)
+            }
+            BuildError::Parse(err) => write!(f, "built EDID doesn't parse: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+/// Builds a CTA-861 extension block.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CtaBuilder {
+    revision: u8,
+    flags: u8,
+    data_blocks: Vec<(u8, Vec<u8>)>,
+    detailed_timings: Vec<[u8; 18]>,
+}
+
+impl Default for CtaBuilder {
+    fn default() -> Self {
+        CtaBuilder {
+            revision: 3,
+            flags: 0,
+            data_blocks: Vec::new(),
+            detailed_timings: Vec::new(),
+        }
+    }
+}
+
+impl CtaBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn revision(mut self, revision: u8) -> Self {
+        self.revision = revision;
+        self
+    }
+
+    /// Sets the support flags of byte 3, see `CtaExtensions::DTD_*`. The
+    /// number of native DTDs is kept in the lower bits.
+    pub fn flags(mut self, flags: u8) -> Self {
+        self.flags = (flags & 0xf0) | (self.flags & 0x0f);
+        self
+    }
+
+    pub fn native_dtds(mut self, count: u8) -> Self {
+        self.flags = (self.flags & 0xf0) | (count & 0x0f);
+        self
+    }
+
+    /// Appends a data block as is, e.g. a vendor block the crate doesn't
+    /// model. Only the lower 3 bits of `tag` are used.
+    pub fn raw_data_block(mut self, tag: u8, payload: &[u8]) -> Self {
+        self.data_blocks.push((tag & 0x7, payload.to_vec()));
+        self
+    }
+
+    /// Appends an encoded 18-byte detailed timing descriptor.
+    pub fn raw_detailed_timing(mut self, dtd: [u8; 18]) -> Self {
+        self.detailed_timings.push(dtd);
+        self
+    }
+
+    pub fn detailed_timing(self, timing: &DetailedTiming) -> Self {
+        self.raw_detailed_timing(timing.to_bytes())
+    }
+
+    /// Encodes the 128-byte extension block, including its checksum.
+    pub fn build(&self) -> Result<Vec<u8>, BuildError> {
+        let mut collection = Vec::new();
+        for (tag, payload) in &self.data_blocks {
+            if payload.len() > 31 {
+                return Err(BuildError::DataBlockTooLong {
+                    tag: *tag,
+                    len: payload.len(),
+                });
+            }
+            encode::push_data_block(*tag, payload, &mut collection);
+        }
+
+        let len = collection.len() + self.detailed_timings.len() * 18;
+        if len > 123 {
+            return Err(BuildError::BlockOverflow { len });
+        }
+        Ok(encode::cta_layout(
+            self.revision,
+            self.flags,
+            &collection,
+            &self.detailed_timings,
+        ))
+    }
+}
+
+/// Builds a detailed timing, with the blanking and pixel clock of a CVT
+/// timing at 60 Hz until set otherwise.
+#[derive(Debug, PartialEq, Clone)]
+pub struct DetailedTimingBuilder {
+    timing: DetailedTiming,
+}
+
+impl DetailedTimingBuilder {
+    pub fn new(width: u16, height: u16) -> Self {
+        DetailedTimingBuilder {
+            timing: DetailedTiming {
+                horizontal_active_pixels: width,
+                ..cvt_timing(width, height, 60.0)
+            },
+        }
+    }
+
+    /// Recomputes the CVT blanking and pixel clock for `refresh` Hz.
+    pub fn refresh_rate(mut self, refresh: f64) -> Self {
+        let t = &self.timing;
+        self.timing = DetailedTiming {
+            horizontal_active_pixels: t.horizontal_active_pixels,
+            horizontal_size: t.horizontal_size,
+            vertical_size: t.vertical_size,
+            horizontal_border_pixels: t.horizontal_border_pixels,
+            vertical_border_pixels: t.vertical_border_pixels,
+            features: t.features,
+            ..cvt_timing(t.horizontal_active_pixels, t.vertical_active_lines, refresh)
+        };
+        self
+    }
+
+    /// Pixel clock in kHz, encoded in units of 10 kHz.
+    pub fn pixel_clock(mut self, pixel_clock: u32) -> Self {
+        self.timing.pixel_clock = pixel_clock;
+        self
+    }
+
+    pub fn horizontal_blanking(mut self, blanking: u16, front_porch: u16, sync_width: u16) -> Self {
+        self.timing.horizontal_blanking_pixels = blanking;
+        self.timing.horizontal_front_porch = front_porch;
+        self.timing.horizontal_sync_width = sync_width;
+        self
+    }
+
+    pub fn vertical_blanking(mut self, blanking: u16, front_porch: u16, sync_width: u16) -> Self {
+        self.timing.vertical_blanking_lines = blanking;
+        self.timing.vertical_front_porch = front_porch;
+        self.timing.vertical_sync_width = sync_width;
+        self
+    }
+
+    /// Sets the image size in millimeters.
+    pub fn size_mm(mut self, width: u16, height: u16) -> Self {
+        self.timing.horizontal_size = width;
+        self.timing.vertical_size = height;
+        self
+    }
+
+    pub fn border(mut self, horizontal: u8, vertical: u8) -> Self {
+        self.timing.horizontal_border_pixels = horizontal;
+        self.timing.vertical_border_pixels = vertical;
+        self
+    }
+
+    /// Uses digital separate sync with the given polarities.
+    pub fn sync_polarity(mut self, hsync_positive: bool, vsync_positive: bool) -> Self {
+        let mut features = (self.timing.features & !0x1e) | 0x18;
+        if vsync_positive {
+            features |= 0x04;
+        }
+        if hsync_positive {
+            features |= 0x02;
+        }
+        self.timing.features = features;
+        self
+    }
+
+    /// Vertical values then describe a field, i.e. half the frame lines.
+    pub fn interlaced(mut self, interlaced: bool) -> Self {
+        self.timing.features = (self.timing.features & !0x80) | if interlaced { 0x80 } else { 0 };
+        self
+    }
+
+    /// Checks that every field can be encoded in a descriptor.
+    pub fn build(&self) -> Result<DetailedTiming, BuildError> {
+        let t = &self.timing;
+        let check = |field, value: u32, max: u32| {
+            if value > max {
+                Err(BuildError::InvalidTiming { field, value })
+            } else {
+                Ok(())
+            }
+        };
+        if t.pixel_clock == 0 || !t.pixel_clock.is_multiple_of(10) {
+            return Err(BuildError::InvalidTiming {
+                field: "pixel_clock",
+                value: t.pixel_clock,
+            });
+        }
+        check("pixel_clock", t.pixel_clock, 655_350)?;
+        check(
+            "horizontal_active_pixels",
+            t.horizontal_active_pixels.into(),
+            4095,
+        )?;
+        check(
+            "horizontal_blanking_pixels",
+            t.horizontal_blanking_pixels.into(),
+            4095,
+        )?;
+        check(
+            "vertical_active_lines",
+            t.vertical_active_lines.into(),
+            4095,
+        )?;
+        check(
+            "vertical_blanking_lines",
+            t.vertical_blanking_lines.into(),
+            4095,
+        )?;
+        check(
+            "horizontal_front_porch",
+            t.horizontal_front_porch.into(),
+            1023,
+        )?;
+        check(
+            "horizontal_sync_width",
+            t.horizontal_sync_width.into(),
+            1023,
+        )?;
+        check("vertical_front_porch", t.vertical_front_porch.into(), 63)?;
+        check("vertical_sync_width", t.vertical_sync_width.into(), 63)?;
+        check("horizontal_size", t.horizontal_size.into(), 4095)?;
+        check("vertical_size", t.vertical_size.into(), 4095)?;
+        check(
+            "horizontal_front_porch",
+            (t.horizontal_front_porch + t.horizontal_sync_width).into(),
+            t.horizontal_blanking_pixels.into(),
+        )?;
+        check(
+            "vertical_front_porch",
+            (t.vertical_front_porch + t.vertical_sync_width).into(),
+            t.vertical_blanking_lines.into(),
+        )?;
+        Ok(*t)
+    }
+}
+
+/// Builds a complete EDID, the base block followed by its extensions.
+///
+/// Chromaticity and established timings are left zeroed and all standard
+/// timing slots unused.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdidBuilder {
+    vendor: [char; 3],
+    product: u16,
+    serial: u32,
+    week: u8,
+    year: u8,
+    version: u8,
+    revision: u8,
+    video_input: u8,
+    width: u8,
+    height: u8,
+    gamma: u8,
+    features: u8,
+    descriptors: Vec<BaseDescriptor>,
+    extensions: Vec<CtaBuilder>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum BaseDescriptor {
+    DetailedTiming([u8; 18]),
+    Text { tag: u8, text: Vec<u8> },
+}
+
+impl Default for EdidBuilder {
+    fn default() -> Self {
+        EdidBuilder {
+            vendor: ['X', 'X', 'X'],
+            product: 0,
+            serial: 0,
+            week: 0,
+            year: 0,
+            version: 1,
+            revision: 4,
+            video_input: 0x80,
+            width: 0,
+            height: 0,
+            gamma: 120,
+            features: 0x02,
+            descriptors: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+}
+
+impl EdidBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the PNP manufacturer ID, three letters from `A` to `Z`.
+    pub fn vendor(mut self, vendor: [char; 3]) -> Self {
+        self.vendor = vendor;
+        self
+    }
+
+    pub fn product(mut self, product: u16) -> Self {
+        self.product = product;
+        self
+    }
+
+    pub fn serial(mut self, serial: u32) -> Self {
+        self.serial = serial;
+        self
+    }
+
+    /// Sets the week and the full year of manufacture, e.g. 2020.
+    pub fn manufacture_date(mut self, week: u8, year: u16) -> Self {
+        self.week = week;
+        self.year = year.saturating_sub(1990).min(255) as u8;
+        self
+    }
+
+    pub fn version(mut self, version: u8, revision: u8) -> Self {
+        self.version = version;
+        self.revision = revision;
+        self
+    }
+
+    /// Sets the raw video input definition byte, see `Display::video_input`.
+    pub fn video_input(mut self, video_input: u8) -> Self {
+        self.video_input = video_input;
+        self
+    }
+
+    pub fn screen_size_cm(mut self, width: u8, height: u8) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Sets the raw gamma byte, i.e. `gamma * 100 - 100`.
+    pub fn gamma(mut self, gamma: u8) -> Self {
+        self.gamma = gamma;
+        self
+    }
+
+    /// Sets the raw feature support byte.
+    pub fn features(mut self, features: u8) -> Self {
+        self.features = features;
+        self
+    }
+
+    /// Appends an encoded 18-byte detailed timing descriptor.
+    pub fn raw_detailed_timing(mut self, dtd: [u8; 18]) -> Self {
+        self.descriptors.push(BaseDescriptor::DetailedTiming(dtd));
+        self
+    }
+
+    /// Appends a detailed timing descriptor, the first one being the
+    /// preferred timing.
+    pub fn detailed_timing(self, timing: &DetailedTiming) -> Self {
+        self.raw_detailed_timing(timing.to_bytes())
+    }
+
+    pub fn product_name(mut self, name: &str) -> Self {
+        self.descriptors.push(BaseDescriptor::Text {
+            tag: 0xFC,
+            text: cp437::encode(name),
+        });
+        self
+    }
+
+    pub fn serial_number(mut self, serial: &str) -> Self {
+        self.descriptors.push(BaseDescriptor::Text {
+            tag: 0xFF,
+            text: cp437::encode(serial),
+        });
+        self
+    }
+
+    /// Appends a CTA-861 extension block.
+    pub fn cta(mut self, cta: CtaBuilder) -> Self {
+        self.extensions.push(cta);
+        self
+    }
+
+    fn base_block(&self) -> Result<Vec<u8>, BuildError> {
+        if !self.vendor.iter().all(char::is_ascii_uppercase) {
+            return Err(BuildError::InvalidVendor {
+                vendor: self.vendor,
+            });
+        }
+        if self.descriptors.len() > 4 {
+            return Err(BuildError::TooManyDescriptors {
+                count: self.descriptors.len(),
+            });
+        }
+        if self.extensions.len() > MAX_EXTENSIONS {
+            return Err(BuildError::TooManyExtensions {
+                count: self.extensions.len(),
+            });
+        }
+
+        let header = Header {
+            vendor: self.vendor,
+            product: self.product,
+            serial: self.serial,
+            week: self.week,
+            year: self.year,
+            version: self.version,
+            revision: self.revision,
+        };
+        let display = Display {
+            video_input: self.video_input,
+            width: self.width,
+            height: self.height,
+            gamma: self.gamma,
+            features: self.features,
+        };
+        let mut block = encode::header_bytes(&header, &display);
+        // Chromaticity and established timings
+        block.resize(38, 0);
+        // Unused standard timings
+        block.resize(54, 0x01);
+
+        for descriptor in &self.descriptors {
+            match descriptor {
+                BaseDescriptor::DetailedTiming(dtd) => block.extend_from_slice(dtd),
+                BaseDescriptor::Text { tag, text } => {
+                    if text.len() > 13 {
+                        return Err(BuildError::TextTooLong { len: text.len() });
+                    }
+                    let payload = encode::text_payload(text);
+                    block.extend_from_slice(&encode::display_descriptor(*tag, 0, payload));
+                }
+            }
+        }
+        // Fill the remaining slots with dummy descriptors
+        for _ in self.descriptors.len()..4 {
+            block.extend_from_slice(&encode::display_descriptor(0x10, 0, [0; 13]));
+        }
+
+        block.push(self.extensions.len() as u8);
+        Ok(encode::finish_block(block))
+    }
+
+    /// Encodes the base block and all extension blocks.
+    pub fn build(&self) -> Result<Vec<u8>, BuildError> {
+        let mut data = self.base_block()?;
+        for extension in &self.extensions {
+            data.extend_from_slice(&extension.build()?);
+        }
+        Ok(data)
+    }
+
+    /// Encodes the EDID and parses it back.
+    pub fn build_edid(&self) -> Result<EDID, BuildError> {
+        let data = self.build()?;
+        let (_, edid) = parse(&data).map_err(BuildError::Parse)?;
+        Ok(edid)
+    }
+
+    /// Renders the EDID being built as text, see `EDID::to_text`.
+    pub fn preview(&self) -> Result<String, BuildError> {
+        Ok(self.build_edid()?.to_text())
+    }
+}
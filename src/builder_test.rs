@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod tests {
+    use crate::{builder::*, cta::*, edid::*, error::*};
+
+    #[test]
+    fn test_raw_data_block() {
+        let hdmi = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let block = CtaBuilder::new()
+            .flags(CtaExtensions::DTD_BASIC_AUDIO)
+            .native_dtds(1)
+            .raw_data_block(3, &[0x12, 0x34, 0x56, 0xde, 0xad])
+            .raw_data_block(6, &[0xbe, 0xef])
+            .raw_detailed_timing(hdmi[54..72].try_into().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(block.len(), 128);
+        assert_eq!(block.iter().fold(0u8, |sum, b| sum.wrapping_add(*b)), 0);
+        assert_eq!(block[2], 4 + 6 + 3);
+
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+
+        assert_eq!(cta.native_dtd.basic_audio, 1);
+        assert_eq!(cta.native_dtd.number_of_native_dtd, 1);
+        assert_eq!(
+            cta.blocks,
+            vec![
+                DataBlock::VendorSpecific(VendorSpecific {
+                    header: DataBlockHeader {
+                        type_tag: 3,
+                        len: 5,
+                    },
+                    identifier: [0x12, 0x34, 0x56],
+                    payload: vec![0xde, 0xad],
+                }),
+                DataBlock::Reserved(DataBlockReserved {
+                    header: DataBlockHeader {
+                        type_tag: 6,
+                        len: 2,
+                    },
+                    payload: vec![0xbe, 0xef],
+                }),
+            ]
+        );
+        assert_eq!(cta.descriptors.len(), 1);
+    }
+
+    #[test]
+    fn test_build_errors() {
+        assert_eq!(
+            CtaBuilder::new().raw_data_block(3, &[0; 32]).build(),
+            Err(BuildError::DataBlockTooLong { tag: 3, len: 32 })
+        );
+        assert_eq!(
+            CtaBuilder::new()
+                .raw_data_block(3, &[0; 31])
+                .raw_data_block(3, &[0; 31])
+                .raw_data_block(3, &[0; 31])
+                .raw_data_block(3, &[0; 31])
+                .build(),
+            Err(BuildError::BlockOverflow { len: 128 })
+        );
+        assert_eq!(CtaBuilder::new().build().unwrap()[2], 0);
+    }
+
+    #[test]
+    fn test_edid_builder() {
+        let hdmi = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let builder = EdidBuilder::new()
+            .vendor(['A', 'B', 'C'])
+            .product(0x1234)
+            .serial(42)
+            .manufacture_date(10, 2020)
+            .screen_size_cm(60, 34)
+            .raw_detailed_timing(hdmi[54..72].try_into().unwrap())
+            .product_name("Test Panel")
+            .serial_number("0123456789ABC")
+            .cta(CtaBuilder::new().raw_data_block(2, &[0x10]));
+        let data = builder.build().unwrap();
+        assert_eq!(data.len(), 256);
+        assert_eq!(
+            data[..128].iter().fold(0u8, |sum, b| sum.wrapping_add(*b)),
+            0
+        );
+
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(edid.header.vendor, ['A', 'B', 'C']);
+        assert_eq!(edid.header.product, 0x1234);
+        assert_eq!(edid.header.serial, 42);
+        assert_eq!(edid.header.year_ad(), 2020);
+        assert_eq!((edid.display.width, edid.display.height), (60, 34));
+        let info = edid.product_info();
+        assert_eq!(info.product_name.as_deref(), Some("Test Panel"));
+        assert_eq!(info.serial_number.as_deref(), Some("0123456789ABC"));
+        assert_eq!(edid.descriptors[3], Descriptor::Dummy);
+        assert_eq!(edid.cta_extensions().count(), 1);
+
+        let preview = builder.preview().unwrap();
+        assert!(preview.contains("Manufacturer: ABC"));
+        assert!(preview.contains("Made in: week 10 of 2020"));
+        assert!(preview.contains("Display Product Name: 'Test Panel'"));
+        assert!(preview.contains("Block 1, CTA-861 Extension Block:"));
+        assert!(preview.contains("VIC  16"));
+    }
+
+    #[test]
+    fn test_edid_builder_text_cp437() {
+        let data = EdidBuilder::new()
+            .product_name("Écran ½ €")
+            .serial_number("Nº 12")
+            .build()
+            .unwrap();
+        assert_eq!(&data[59..64], &[0x90, b'c', b'r', b'a', b'n']);
+
+        let (_, edid) = parse(&data).unwrap();
+        let info = edid.product_info();
+        assert_eq!(info.product_name.as_deref(), Some("Écran ½ ?"));
+        assert_eq!(info.serial_number.as_deref(), Some("Nº 12"));
+    }
+
+    #[test]
+    fn test_edid_builder_errors() {
+        let dtd = [1; 18];
+        let builder = (0..5).fold(EdidBuilder::new(), |b, _| b.raw_detailed_timing(dtd));
+        assert_eq!(
+            builder.preview(),
+            Err(BuildError::TooManyDescriptors { count: 5 })
+        );
+        assert_eq!(
+            EdidBuilder::new().product_name("Fourteen chars").build(),
+            Err(BuildError::TextTooLong { len: 14 })
+        );
+
+        // An EEODB announcing an extension block that isn't there
+        let builder = EdidBuilder::new().cta(CtaBuilder::new().raw_data_block(7, &[0x78, 2]));
+        assert!(matches!(
+            builder.preview(),
+            Err(BuildError::Parse(EdidError {
+                kind: EdidErrorKind::Truncated { .. },
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_detailed_timing_builder() {
+        let timing = DetailedTimingBuilder::new(1920, 1080)
+            .refresh_rate(75.0)
+            .size_mm(527, 296)
+            .sync_polarity(true, false)
+            .build()
+            .unwrap();
+        assert_eq!(timing.horizontal_active_pixels, 1920);
+        assert_eq!(timing.vertical_active_lines, 1080);
+        assert!((timing.refresh_rate() - 75.0).abs() < 0.5);
+        assert_eq!((timing.horizontal_size, timing.vertical_size), (527, 296));
+        assert_eq!(timing.features, 0x1a);
+
+        let edid = EdidBuilder::new()
+            .detailed_timing(&timing)
+            .build_edid()
+            .unwrap();
+        assert_eq!(edid.preferred_timing(), Some(&timing));
+    }
+
+    #[test]
+    fn test_detailed_timing_builder_errors() {
+        let builder = DetailedTimingBuilder::new(1920, 1080);
+        assert_eq!(
+            builder.clone().pixel_clock(148_505).build(),
+            Err(BuildError::InvalidTiming {
+                field: "pixel_clock",
+                value: 148_505
+            })
+        );
+        assert_eq!(
+            builder.clone().vertical_blanking(45, 40, 30).build(),
+            Err(BuildError::InvalidTiming {
+                field: "vertical_front_porch",
+                value: 70
+            })
+        );
+        assert_eq!(
+            DetailedTimingBuilder::new(5000, 1080).build(),
+            Err(BuildError::InvalidTiming {
+                field: "horizontal_active_pixels",
+                value: 5000
+            })
+        );
+        assert_eq!(
+            EdidBuilder::new().vendor(['a', 'B', '1']).build(),
+            Err(BuildError::InvalidVendor {
+                vendor: ['a', 'B', '1']
+            })
+        );
+    }
+}
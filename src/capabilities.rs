@@ -0,0 +1,125 @@
+#[cfg(feature = "displayid")]
+use crate::extension::Extension;
+#[cfg(feature = "cta")]
+use crate::hdmi::HdmiForumVsdb;
+use crate::{edid::EDID, vrr::VrrSource};
+
+/// The block a capability was read from.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum CapabilitySource {
+    /// Feature support byte of the base block.
+    BaseFeatures,
+    /// Byte 3 of a CTA extension.
+    CtaExtension,
+    /// HF-VSDB of a CTA extension, or its HF-SCDB if there is no HF-VSDB.
+    HdmiForum,
+    /// A DisplayID extension.
+    DisplayId,
+}
+
+/// Capabilities aggregated over the base block and the extensions.
+///
+/// Each field is `None` if the capability isn't advertised, or the source of
+/// the first block advertising it.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct EdidCapabilities {
+    pub dpms_standby: Option<CapabilitySource>,
+    pub dpms_suspend: Option<CapabilitySource>,
+    pub dpms_active_off: Option<CapabilitySource>,
+    pub srgb_default: Option<CapabilitySource>,
+    pub preferred_timing_native: Option<CapabilitySource>,
+    pub continuous_frequency: Option<CapabilitySource>,
+    pub ycbcr444: Option<CapabilitySource>,
+    pub ycbcr422: Option<CapabilitySource>,
+    pub underscan: Option<CapabilitySource>,
+    pub basic_audio: Option<CapabilitySource>,
+    /// SCDC, i.e. the sink's status and control registers.
+    pub scdc: Option<CapabilitySource>,
+    /// Auto Low Latency Mode.
+    pub allm: Option<CapabilitySource>,
+    /// Display Stream Compression.
+    pub dsc: Option<CapabilitySource>,
+    /// Variable refresh rate, with a known range.
+    pub vrr: Option<CapabilitySource>,
+    /// The display is one tile of a tiled display.
+    pub tiled: Option<CapabilitySource>,
+}
+
+fn advertise(field: &mut Option<CapabilitySource>, supported: bool, source: CapabilitySource) {
+    if supported && field.is_none() {
+        *field = Some(source);
+    }
+}
+
+impl EDID {
+    pub fn capabilities(&self) -> EdidCapabilities {
+        let mut caps = EdidCapabilities::default();
+
+        let features = self.display.decoded_features();
+        let digital = self.display.is_digital();
+        let v14 = (self.header.version, self.header.revision) >= (1, 4);
+        let base = CapabilitySource::BaseFeatures;
+        advertise(&mut caps.dpms_standby, features.dpms_standby, base);
+        advertise(&mut caps.dpms_suspend, features.dpms_suspend, base);
+        advertise(&mut caps.dpms_active_off, features.dpms_active_off, base);
+        advertise(&mut caps.srgb_default, features.srgb_default, base);
+        // Always set before EDID 1.4
+        advertise(
+            &mut caps.preferred_timing_native,
+            !v14 || features.preferred_timing_native,
+            base,
+        );
+        advertise(
+            &mut caps.continuous_frequency,
+            v14 && features.continuous_frequency,
+            base,
+        );
+        // Display type bits are the supported color encodings on EDID 1.4 digital inputs
+        let encodings = if v14 && digital {
+            features.display_type
+        } else {
+            0
+        };
+        advertise(&mut caps.ycbcr444, encodings & 0x1 != 0, base);
+        advertise(&mut caps.ycbcr422, encodings & 0x2 != 0, base);
+
+        #[cfg(feature = "cta")]
+        for cta in self.cta_extensions() {
+            let source = CapabilitySource::CtaExtension;
+            let native_dtd = &cta.native_dtd;
+            advertise(&mut caps.ycbcr444, native_dtd.ycbcr444 != 0, source);
+            advertise(&mut caps.ycbcr422, native_dtd.ycbcr422 != 0, source);
+            advertise(&mut caps.underscan, native_dtd.underscan != 0, source);
+            advertise(&mut caps.basic_audio, native_dtd.basic_audio != 0, source);
+
+            if let Some(hf) = cta.hdmi_forum() {
+                let source = CapabilitySource::HdmiForum;
+                let scdc = hf.flags & HdmiForumVsdb::SCDC_PRESENT != 0;
+                let allm = hf.features & HdmiForumVsdb::FEATURE_ALLM != 0;
+                advertise(&mut caps.scdc, scdc, source);
+                advertise(&mut caps.allm, allm, source);
+                advertise(&mut caps.dsc, hf.dsc.is_some(), source);
+            }
+        }
+
+        for range in self.vrr_capabilities().ranges {
+            let source = match range.source {
+                VrrSource::HdmiForum => CapabilitySource::HdmiForum,
+                VrrSource::DisplayIdAdaptiveSync => CapabilitySource::DisplayId,
+                // Not tied to a capability byte of the CTA extension
+                VrrSource::FreeSync => continue,
+            };
+            advertise(&mut caps.vrr, true, source);
+        }
+
+        #[cfg(feature = "displayid")]
+        for ext in &self.extensions {
+            if let Extension::DisplayId(displayid) = ext {
+                let tiled = displayid.tiled_topology().is_some();
+                advertise(&mut caps.tiled, tiled, CapabilitySource::DisplayId);
+            }
+        }
+
+        caps
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::{capabilities::*, edid::*};
+
+    #[test]
+    fn test_capabilities_provenance() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+
+        let base = Some(CapabilitySource::BaseFeatures);
+        let cta = Some(CapabilitySource::CtaExtension);
+        assert_eq!(
+            edid.capabilities(),
+            EdidCapabilities {
+                dpms_standby: base,
+                dpms_suspend: base,
+                dpms_active_off: base,
+                srgb_default: None,
+                preferred_timing_native: base,
+                continuous_frequency: None,
+                ycbcr444: cta,
+                ycbcr422: cta,
+                underscan: cta,
+                basic_audio: cta,
+                scdc: None,
+                allm: None,
+                dsc: None,
+                vrr: None,
+                tiled: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_capabilities_hdmi_forum() {
+        use crate::builder::{CtaBuilder, EdidBuilder};
+
+        // HF-VSDB: SCDC, ALLM and a 48-120 Hz VRR range
+        let hf_vsdb = [0xd8, 0x5d, 0xc4, 0x01, 0x78, 0x80, 0x00, 0x02, 0x30, 0x78];
+        let data = EdidBuilder::new()
+            .cta(CtaBuilder::new().raw_data_block(3, &hf_vsdb))
+            .build()
+            .unwrap();
+        let (_, edid) = parse(&data).unwrap();
+
+        let hdmi_forum = Some(CapabilitySource::HdmiForum);
+        let caps = edid.capabilities();
+        assert_eq!(caps.scdc, hdmi_forum);
+        assert_eq!(caps.allm, hdmi_forum);
+        assert_eq!(caps.dsc, None);
+        assert_eq!(caps.vrr, hdmi_forum);
+        assert_eq!(caps.tiled, None);
+    }
+
+    #[cfg(feature = "displayid")]
+    #[test]
+    fn test_capabilities_displayid() {
+        use crate::{displayid::*, extension::Extension};
+
+        let d = include_bytes!("../testdata/card0-eDP-1.bin");
+        let (_, mut edid) = parse(d).unwrap();
+        edid.extensions.push(Extension::DisplayId(DisplayId {
+            version: 1,
+            revision: 3,
+            product_type: 0,
+            extension_count: 0,
+            blocks: vec![DisplayIdDataBlock::TiledTopology(TiledTopology {
+                single_enclosure: true,
+                multi_tile_behavior: 0,
+                single_tile_behavior: 0,
+                horizontal_tiles: 2,
+                vertical_tiles: 1,
+                horizontal_location: 0,
+                vertical_location: 0,
+                tile_width: 2560,
+                tile_height: 2880,
+                bezel: None,
+                vendor_id: *b"ACR",
+                product_code: 0x1234,
+                serial_number: 42,
+            })],
+            checksum: 0,
+            checksum_valid: true,
+        }));
+        assert_eq!(edid.capabilities().tiled, Some(CapabilitySource::DisplayId));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    edid::BLOCK_SIZE,
+    error::{EdidError, EdidErrorKind},
+};
+
+/// A 128-byte block whose bytes don't sum to 0 modulo 256.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ChecksumMismatch {
+    /// Index of the block, 0 being the base block.
+    pub block: usize,
+    /// The checksum byte that would make the block valid.
+    pub expected: u8,
+    pub found: u8,
+}
+
+/// Verifies the checksum of the base block and of every extension block in
+/// a raw EDID, trailing bytes short of a block are ignored.
+///
+/// `parse` doesn't reject bad checksums, so that such EDIDs stay usable; it
+/// lists them in `EDID::checksum_mismatches`.
+pub fn checksum_mismatches(data: &[u8]) -> Vec<ChecksumMismatch> {
+    data.chunks_exact(BLOCK_SIZE)
+        .enumerate()
+        .filter_map(|(block, bytes)| {
+            let sum = bytes.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+            let found = bytes[BLOCK_SIZE - 1];
+            (sum != 0).then(|| ChecksumMismatch {
+                block,
+                expected: found.wrapping_sub(sum),
+                found,
+            })
+        })
+        .collect()
+}
+
+/// Patches the checksum byte of the base block and of every extension block
+/// in a raw EDID, e.g. after editing it by hand. Trailing bytes short of a
+/// block are left alone.
+///
+/// Returns the mismatches that were fixed.
+pub fn fix_checksums(data: &mut [u8]) -> Vec<ChecksumMismatch> {
+    let mismatches = checksum_mismatches(data);
+    for mismatch in &mismatches {
+        data[mismatch.block * BLOCK_SIZE + BLOCK_SIZE - 1] = mismatch.expected;
+    }
+    mismatches
+}
+
+impl From<ChecksumMismatch> for EdidError {
+    fn from(mismatch: ChecksumMismatch) -> Self {
+        EdidError {
+            kind: EdidErrorKind::Checksum {
+                block: mismatch.block,
+                expected: mismatch.expected,
+                found: mismatch.found,
+            },
+            offset: mismatch.block * BLOCK_SIZE + BLOCK_SIZE - 1,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+    use crate::{checksum::*, edid::parse};
+
+    #[test]
+    fn test_checksum_mismatches() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        assert_eq!(checksum_mismatches(d), vec![]);
+
+        // Corrupt a data block byte of the CTA extension
+        let mut data = d.to_vec();
+        data[128 + 20] ^= 0x01;
+        assert_eq!(
+            checksum_mismatches(&data),
+            vec![ChecksumMismatch {
+                block: 1,
+                expected: d[255].wrapping_sub(data[128 + 20].wrapping_sub(d[128 + 20])),
+                found: d[255],
+            }]
+        );
+
+        // Both blocks, and a truncated trailing block is ignored
+        data[10] ^= 0x01;
+        data.extend_from_slice(&[0xff; 64]);
+        let blocks: Vec<usize> = checksum_mismatches(&data)
+            .iter()
+            .map(|mismatch| mismatch.block)
+            .collect();
+        assert_eq!(blocks, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_parse_checksum_mismatches() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(edid.checksum_mismatches, vec![]);
+
+        // Still parsed, with the extension block reported
+        let mut data = d.to_vec();
+        data[255] ^= 0x01;
+        data.extend_from_slice(&[0xff; 128]);
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(
+            edid.checksum_mismatches,
+            vec![ChecksumMismatch {
+                block: 1,
+                expected: d[255],
+                found: data[255],
+            }]
+        );
+
+        // Encoding computes every checksum
+        let (_, reparsed) = parse(&edid.to_bytes().unwrap()).unwrap();
+        assert_eq!(reparsed.checksum_mismatches, vec![]);
+    }
+
+    #[test]
+    fn test_fix_checksums() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut data = d.to_vec();
+        assert_eq!(fix_checksums(&mut data), vec![]);
+
+        data[20] = 0x78;
+        data[128 + 20] ^= 0x01;
+        data.extend_from_slice(&[0xff; 64]);
+        let fixed = fix_checksums(&mut data);
+        assert_eq!(fixed.len(), 2);
+        assert_eq!(checksum_mismatches(&data), vec![]);
+        assert_eq!(data[255], fixed[1].expected);
+        assert_eq!(&data[256..], &[0xff; 64]);
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(feature = "displayid")]
+use crate::displayid::DisplayIdDataBlock;
+#[cfg(feature = "cta")]
+use crate::{
+    cta::{CtaExtensions, DataBlock},
+    extended::{ExtendedDataBlock, VendorSpecificAudioPayload, VendorSpecificVideoPayload},
+};
+use crate::{
+    edid::{Descriptor, EDID},
+    extension::Extension,
+};
+
+/// How many bytes of an EDID the crate decoded into typed structures, vs
+/// kept as raw bytes or skipped.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Coverage {
+    pub decoded: usize,
+    pub raw: usize,
+}
+
+impl Coverage {
+    pub fn total(&self) -> usize {
+        self.decoded + self.raw
+    }
+
+    /// Share of decoded bytes, from 0 to 100.
+    pub fn percentage(&self) -> f64 {
+        match self.total() {
+            0 => 100.0,
+            total => self.decoded as f64 * 100.0 / total as f64,
+        }
+    }
+}
+
+fn descriptor_raw_bytes(descriptor: &Descriptor) -> usize {
+    match descriptor {
+        Descriptor::DetailedTiming(_)
+        | Descriptor::SerialNumber(_)
+        | Descriptor::UnspecifiedText(_)
+        | Descriptor::RangeLimits(_)
+        | Descriptor::ProductName(_)
+        | Descriptor::Dummy => 0,
+        // Only the tag of the other descriptors is kept
+        _ => 18,
+    }
+}
+
+#[cfg(feature = "cta")]
+fn cta_raw_bytes(cta: &CtaExtensions) -> usize {
+    cta.blocks
+        .iter()
+        .map(|block| match block {
+            DataBlock::Reserved(block) | DataBlock::TruncatedVendorSpecific(block) => {
+                1 + block.header.len as usize
+            }
+            DataBlock::VendorSpecific(vsdb)
+                if vsdb.hdmi().is_none()
+                    && vsdb.hdmi_forum().is_none()
+                    && vsdb.freesync().is_none()
+                    && vsdb.microsoft().is_none() =>
+            {
+                vsdb.payload.len()
+            }
+            DataBlock::Extended(ExtendedDataBlock::Reserved(block)) => {
+                1 + block.header.len as usize
+            }
+            DataBlock::Extended(ExtendedDataBlock::VendorSpecificVideo(vsvdb)) => {
+                match &vsvdb.payload {
+                    VendorSpecificVideoPayload::Raw(payload) => payload.len(),
+                    _ => 0,
+                }
+            }
+            DataBlock::Extended(ExtendedDataBlock::VendorSpecificAudio(vsadb)) => {
+                match &vsadb.payload {
+                    VendorSpecificAudioPayload::Raw(payload) => payload.len(),
+                    _ => 0,
+                }
+            }
+            _ => 0,
+        })
+        .sum()
+}
+
+fn extension_raw_bytes(extension: &Extension) -> usize {
+    match extension {
+        #[cfg(feature = "cta")]
+        Extension::Cta(cta) => cta_raw_bytes(cta),
+        #[cfg(feature = "displayid")]
+        Extension::DisplayId(displayid) => displayid
+            .blocks
+            .iter()
+            .map(|block| match block {
+                DisplayIdDataBlock::Reserved(block) => 3 + block.payload.len(),
+                _ => 0,
+            })
+            .sum(),
+        // Bytes 0x37-0x50 aren't kept, only the typed fields of the interface
+        // and display device sections are decoded
+        #[cfg(feature = "di-ext")]
+        Extension::DiExt(di_ext) => {
+            (0x51 - 0x37)
+                + (di_ext.interface.raw.len() - 4)
+                + (di_ext.display_device.raw.len() - 1)
+                + di_ext.capabilities.len()
+                + di_ext.transfer_characteristics.len()
+        }
+        Extension::Unknown { .. } => 128,
+        _ => 0,
+    }
+}
+
+impl EDID {
+    /// Counts the bytes decoded into typed structures. Chromaticity, unknown
+    /// descriptors, reserved data blocks, vendor payloads without a typed
+    /// decode and the DI-EXT sections kept as bytes count as raw.
+    pub fn coverage(&self) -> Coverage {
+        // Chromaticity isn't decoded yet
+        let mut raw = 10;
+        raw += self
+            .descriptors
+            .iter()
+            .map(descriptor_raw_bytes)
+            .sum::<usize>();
+        raw += self
+            .extensions
+            .iter()
+            .map(extension_raw_bytes)
+            .sum::<usize>();
+        Coverage {
+            decoded: 128 * (1 + self.extensions.len()) - raw,
+            raw,
+        }
+    }
+}
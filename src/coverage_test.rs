@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use crate::{coverage::*, edid::*};
+
+    #[test]
+    fn test_coverage() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        // Chromaticity
+        assert_eq!(
+            edid.coverage(),
+            Coverage {
+                decoded: 118,
+                raw: 10
+            }
+        );
+
+        // Plus an unknown descriptor
+        let d = include_bytes!("../testdata/card0-eDP-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(edid.coverage().raw, 10 + 18);
+        assert_eq!(edid.coverage().total(), 128);
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_coverage_cta() {
+        let d = include_bytes!("../testdata/card0-HDMI-2.bin");
+        let (_, edid) = parse(d).unwrap();
+        // Both HDMI VSDBs are decoded
+        let coverage = edid.coverage();
+        assert_eq!(coverage.raw, 10);
+        assert_eq!(coverage.total(), 256);
+        assert!((coverage.percentage() - 96.09375).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "di-ext")]
+    #[test]
+    fn test_coverage_di_ext() {
+        use crate::extension::Extension;
+
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.push(Extension::DI_EXT);
+        data.resize(256, 0);
+        let (_, edid) = parse(&data).unwrap();
+        // Only the version, interface type, version, content protection,
+        // color encoding and display technology are decoded
+        assert_eq!(edid.coverage().raw, 10 + 128 - 8);
+    }
+}
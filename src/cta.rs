@@ -0,0 +1,855 @@
+use std::{fmt, str::FromStr};
+
+use nom::{
+    bytes::complete::{tag, take},
+    combinator::{map, not, peek},
+    error::{context, VerboseError},
+    multi::many0,
+    number::complete::le_u8,
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{
+    edid::{parse_detailed_timing, DetailedTiming, EDID},
+    extended::{parse_extended_data_block, ExtendedDataBlock, NativeVideoResolution},
+    extension::{from_name, Extension, ParseNameError},
+    limits::{many0_capped, MAX_DATA_BLOCKS},
+};
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NativeDTDs {
+    pub underscan: u8,
+    pub basic_audio: u8,
+    pub ycbcr444: u8,
+    pub ycbcr422: u8,
+    pub number_of_native_dtd: u8,
+}
+
+fn parse_native_dtds(input: &[u8]) -> IResult<&[u8], NativeDTDs, VerboseError<&[u8]>> {
+    let (input, v) = le_u8(input)?;
+    Ok((
+        input,
+        NativeDTDs {
+            underscan: (v & 0x80u8) >> 7,
+            basic_audio: (v & 0x40u8) >> 6,
+            ycbcr444: (v & 0x20u8) >> 5,
+            ycbcr422: (v & 0x10u8) >> 4,
+            number_of_native_dtd: v & 0xfu8,
+        },
+    ))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DataBlockHeader {
+    pub type_tag: u8,
+    pub len: u8,
+}
+
+// impl fmt::Display for DataBlockHeader {
+//     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//         write!(f, "{:03b}{:05b}", self.type_tag, self.len)
+//     }
+// }
+
+pub(crate) fn parse_data_block_header(
+    input: &[u8],
+) -> IResult<&[u8], DataBlockHeader, VerboseError<&[u8]>> {
+    map(le_u8, |v| DataBlockHeader {
+        type_tag: (v & 0xe0u8) >> 5,
+        len: v & 0x1fu8,
+    })(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DataBlock {
+    Reserved(DataBlockReserved),
+    AudioBlock(AudioBlock),
+    VideoBlock(VideoBlock),
+    VendorSpecific(VendorSpecific),
+    /// Vendor-specific block too short for its OUI, the payload is kept.
+    TruncatedVendorSpecific(DataBlockReserved),
+    SpeakerAllocation(SpeakerAllocation),
+    Extended(ExtendedDataBlock),
+}
+
+pub(crate) fn parse_blocks(
+    input: &[u8],
+    revision: u8,
+) -> IResult<&[u8], Vec<DataBlock>, VerboseError<&[u8]>> {
+    many0_capped(MAX_DATA_BLOCKS, "data blocks", |i| {
+        parse_data_block(i, revision)
+    })(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AudioBlock {
+    pub header: DataBlockHeader,
+    pub descriptors: Vec<ShortAudioDescriptor>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShortAudioDescriptor {
+    pub audio_format: u8,
+    pub number_of_channels: u8,
+    pub sampling_frequences: u8,
+    pub format_dependent_value: u8,
+    pub audio_format_extended_code: u8,
+}
+
+impl ShortAudioDescriptor {
+    /// Sampling rates in Hz, indexed by their bit in `sampling_frequences`.
+    pub const SAMPLING_RATES: [u32; 7] = [32000, 44100, 48000, 88200, 96000, 176400, 192000];
+
+    /// The audio format, with the extension type code folded in.
+    pub fn format(&self) -> AudioFormat {
+        match AudioFormat::ALL[self.audio_format as usize & 0xf] {
+            AudioFormat::Extended(_) => AudioFormat::Extended(ExtendedAudioFormat::from_code(
+                self.audio_format_extended_code,
+            )),
+            format => format,
+        }
+    }
+
+    /// Decodes `format_dependent_value` according to the format.
+    pub fn format_detail(&self) -> FormatDetail {
+        let value = self.format_dependent_value;
+        match self.format() {
+            AudioFormat::Lpcm => FormatDetail::Lpcm {
+                bit_depths: [16, 20, 24]
+                    .iter()
+                    .enumerate()
+                    .filter(|(bit, _)| value & (1u8 << bit) != 0)
+                    .map(|(_, depth)| *depth)
+                    .collect(),
+            },
+            AudioFormat::Ac3
+            | AudioFormat::Mpeg1
+            | AudioFormat::Mp3
+            | AudioFormat::Mpeg2
+            | AudioFormat::AacLc
+            | AudioFormat::Dts
+            | AudioFormat::Atrac => FormatDetail::MaxBitrate(value as u32 * 8),
+            _ => FormatDetail::Other(value),
+        }
+    }
+
+    /// The supported sampling rates in Hz, in ascending order.
+    pub fn sampling_rates(&self) -> Vec<u32> {
+        ShortAudioDescriptor::SAMPLING_RATES
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| self.sampling_frequences & (1u8 << bit) != 0)
+            .map(|(_, rate)| *rate)
+            .collect()
+    }
+}
+
+/// The format-dependent byte of a short audio descriptor.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum FormatDetail {
+    /// Supported sample sizes in bits, out of 16, 20 and 24.
+    Lpcm { bit_depths: Vec<u8> },
+    /// Maximum bitrate in kbit/s, for AC-3 through ATRAC.
+    MaxBitrate(u32),
+    /// Left undecoded for the other formats.
+    Other(u8),
+}
+
+/// Audio format code of a Short Audio Descriptor.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AudioFormat {
+    Reserved,
+    Lpcm,
+    Ac3,
+    Mpeg1,
+    Mp3,
+    Mpeg2,
+    AacLc,
+    Dts,
+    Atrac,
+    OneBitAudio,
+    EnhancedAc3,
+    DtsHd,
+    Mat,
+    Dst,
+    WmaPro,
+    /// The format is given by the extension type code, `None` if reserved.
+    Extended(Option<ExtendedAudioFormat>),
+}
+
+impl AudioFormat {
+    /// Indexed by the format code.
+    pub const ALL: [AudioFormat; 16] = [
+        AudioFormat::Reserved,
+        AudioFormat::Lpcm,
+        AudioFormat::Ac3,
+        AudioFormat::Mpeg1,
+        AudioFormat::Mp3,
+        AudioFormat::Mpeg2,
+        AudioFormat::AacLc,
+        AudioFormat::Dts,
+        AudioFormat::Atrac,
+        AudioFormat::OneBitAudio,
+        AudioFormat::EnhancedAc3,
+        AudioFormat::DtsHd,
+        AudioFormat::Mat,
+        AudioFormat::Dst,
+        AudioFormat::WmaPro,
+        AudioFormat::Extended(None),
+    ];
+
+    /// Stable snake_case identifier, e.g. for config files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AudioFormat::Reserved => "reserved",
+            AudioFormat::Lpcm => "lpcm",
+            AudioFormat::Ac3 => "ac3",
+            AudioFormat::Mpeg1 => "mpeg1",
+            AudioFormat::Mp3 => "mp3",
+            AudioFormat::Mpeg2 => "mpeg2",
+            AudioFormat::AacLc => "aac_lc",
+            AudioFormat::Dts => "dts",
+            AudioFormat::Atrac => "atrac",
+            AudioFormat::OneBitAudio => "one_bit_audio",
+            AudioFormat::EnhancedAc3 => "enhanced_ac3",
+            AudioFormat::DtsHd => "dts_hd",
+            AudioFormat::Mat => "mat",
+            AudioFormat::Dst => "dst",
+            AudioFormat::WmaPro => "wma_pro",
+            AudioFormat::Extended(Some(format)) => format.as_str(),
+            AudioFormat::Extended(None) => "extended",
+        }
+    }
+}
+
+/// Human-readable name, e.g. for UIs.
+impl fmt::Display for AudioFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            AudioFormat::Reserved => "Reserved",
+            AudioFormat::Lpcm => "LPCM",
+            AudioFormat::Ac3 => "AC-3",
+            AudioFormat::Mpeg1 => "MPEG-1",
+            AudioFormat::Mp3 => "MP3",
+            AudioFormat::Mpeg2 => "MPEG-2",
+            AudioFormat::AacLc => "AAC-LC",
+            AudioFormat::Dts => "DTS",
+            AudioFormat::Atrac => "ATRAC",
+            AudioFormat::OneBitAudio => "DSD",
+            AudioFormat::EnhancedAc3 => "E-AC-3",
+            AudioFormat::DtsHd => "DTS-HD",
+            AudioFormat::Mat => "TrueHD",
+            AudioFormat::Dst => "DST",
+            AudioFormat::WmaPro => "WMA Pro",
+            AudioFormat::Extended(Some(format)) => return format.fmt(f),
+            AudioFormat::Extended(None) => "Extension",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for AudioFormat {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_name(&AudioFormat::ALL, AudioFormat::as_str, s)
+            .or_else(|_| s.parse().map(|format| AudioFormat::Extended(Some(format))))
+    }
+}
+
+/// Audio format extension type code, for audio format code 15.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ExtendedAudioFormat {
+    MpegHeAac,
+    MpegHeAacV2,
+    MpegAacLc,
+    Dra,
+    MpegHeAacSurround,
+    MpegAacLcSurround,
+    MpegH3dAudio,
+    Ac4,
+    Lpcm3dAudio,
+    AuroCx,
+    MpegDUsac,
+}
+
+impl ExtendedAudioFormat {
+    pub const ALL: [ExtendedAudioFormat; 11] = [
+        ExtendedAudioFormat::MpegHeAac,
+        ExtendedAudioFormat::MpegHeAacV2,
+        ExtendedAudioFormat::MpegAacLc,
+        ExtendedAudioFormat::Dra,
+        ExtendedAudioFormat::MpegHeAacSurround,
+        ExtendedAudioFormat::MpegAacLcSurround,
+        ExtendedAudioFormat::MpegH3dAudio,
+        ExtendedAudioFormat::Ac4,
+        ExtendedAudioFormat::Lpcm3dAudio,
+        ExtendedAudioFormat::AuroCx,
+        ExtendedAudioFormat::MpegDUsac,
+    ];
+
+    /// `None` for reserved and deprecated codes.
+    pub fn from_code(code: u8) -> Option<Self> {
+        ExtendedAudioFormat::ALL
+            .iter()
+            .find(|format| format.code() == code)
+            .copied()
+    }
+
+    pub fn code(&self) -> u8 {
+        match self {
+            ExtendedAudioFormat::MpegHeAac => 4,
+            ExtendedAudioFormat::MpegHeAacV2 => 5,
+            ExtendedAudioFormat::MpegAacLc => 6,
+            ExtendedAudioFormat::Dra => 7,
+            ExtendedAudioFormat::MpegHeAacSurround => 8,
+            ExtendedAudioFormat::MpegAacLcSurround => 10,
+            ExtendedAudioFormat::MpegH3dAudio => 11,
+            ExtendedAudioFormat::Ac4 => 12,
+            ExtendedAudioFormat::Lpcm3dAudio => 13,
+            ExtendedAudioFormat::AuroCx => 14,
+            ExtendedAudioFormat::MpegDUsac => 15,
+        }
+    }
+
+    /// Stable snake_case identifier, e.g. for config files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExtendedAudioFormat::MpegHeAac => "mpeg4_he_aac",
+            ExtendedAudioFormat::MpegHeAacV2 => "mpeg4_he_aac_v2",
+            ExtendedAudioFormat::MpegAacLc => "mpeg4_aac_lc",
+            ExtendedAudioFormat::Dra => "dra",
+            ExtendedAudioFormat::MpegHeAacSurround => "mpeg4_he_aac_mps",
+            ExtendedAudioFormat::MpegAacLcSurround => "mpeg4_aac_lc_mps",
+            ExtendedAudioFormat::MpegH3dAudio => "mpegh_3d_audio",
+            ExtendedAudioFormat::Ac4 => "ac4",
+            ExtendedAudioFormat::Lpcm3dAudio => "lpcm_3d_audio",
+            ExtendedAudioFormat::AuroCx => "auro_cx",
+            ExtendedAudioFormat::MpegDUsac => "mpegd_usac",
+        }
+    }
+}
+
+/// Human-readable name, e.g. for UIs.
+impl fmt::Display for ExtendedAudioFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ExtendedAudioFormat::MpegHeAac => "MPEG-4 HE-AAC",
+            ExtendedAudioFormat::MpegHeAacV2 => "MPEG-4 HE-AAC v2",
+            ExtendedAudioFormat::MpegAacLc => "MPEG-4 AAC-LC",
+            ExtendedAudioFormat::Dra => "DRA",
+            ExtendedAudioFormat::MpegHeAacSurround => "MPEG-4 HE-AAC + MPEG Surround",
+            ExtendedAudioFormat::MpegAacLcSurround => "MPEG-4 AAC-LC + MPEG Surround",
+            ExtendedAudioFormat::MpegH3dAudio => "MPEG-H 3D Audio",
+            ExtendedAudioFormat::Ac4 => "AC-4",
+            ExtendedAudioFormat::Lpcm3dAudio => "L-PCM 3D Audio",
+            ExtendedAudioFormat::AuroCx => "Auro-Cx",
+            ExtendedAudioFormat::MpegDUsac => "MPEG-D USAC",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for ExtendedAudioFormat {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_name(&ExtendedAudioFormat::ALL, ExtendedAudioFormat::as_str, s)
+    }
+}
+
+fn parse_audio_block(input: &[u8]) -> IResult<&[u8], AudioBlock, VerboseError<&[u8]>> {
+    context("audio data blocks", |i| {
+        let (i, header) = parse_data_block_header(i)?;
+        let (i, payload) = take(header.len)(i)?;
+        let (_i, descriptors) = many0(map(
+            tuple((le_u8, le_u8, le_u8)),
+            |(format_and_channels, sampling_frequences, bitrate_or_bitdepth)| {
+                let audio_format = (format_and_channels & 0x78u8) >> 3;
+                // Only format 15 splits the third byte with an extension code
+                let (audio_format_extended_code, format_dependent_value) = if audio_format == 15 {
+                    (
+                        (bitrate_or_bitdepth & 0xf8u8) >> 3,
+                        bitrate_or_bitdepth & 0x7u8,
+                    )
+                } else {
+                    (0, bitrate_or_bitdepth)
+                };
+                ShortAudioDescriptor {
+                    audio_format,
+                    number_of_channels: (format_and_channels & 0x7u8) + 1u8,
+                    sampling_frequences,
+                    audio_format_extended_code,
+                    format_dependent_value,
+                }
+            },
+        ))(payload)?;
+        Ok((
+            i,
+            AudioBlock {
+                header,
+                descriptors,
+            },
+        ))
+    })(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShortVideoDescriptor {
+    /// Only VICs 1-64 can be flagged native.
+    pub is_native: u8,
+    /// The VIC, 1-127 or 193-253.
+    pub cea861_index: u8,
+}
+
+pub(crate) fn parse_short_video_descriptor(
+    input: &[u8],
+) -> IResult<&[u8], ShortVideoDescriptor, VerboseError<&[u8]>> {
+    map(le_u8, |payload| {
+        // Bit 7 is the native flag for 129-192 only, above are 8-bit VICs
+        let is_native = (129..=192).contains(&payload);
+        ShortVideoDescriptor {
+            is_native: is_native as u8,
+            cea861_index: if is_native { payload & 0x7fu8 } else { payload },
+        }
+    })(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VideoBlock {
+    pub header: DataBlockHeader,
+    pub descriptors: Vec<ShortVideoDescriptor>,
+}
+
+fn parse_video_block(input: &[u8]) -> IResult<&[u8], VideoBlock, VerboseError<&[u8]>> {
+    context("video data blocks", |i| {
+        let (i, header) = parse_data_block_header(i)?;
+        let (i, payload) = take(header.len)(i)?;
+        let (_i, descriptors) = many0(parse_short_video_descriptor)(payload)?;
+        Ok((
+            i,
+            VideoBlock {
+                header,
+                descriptors,
+            },
+        ))
+    })(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VendorSpecific {
+    pub header: DataBlockHeader,
+    pub identifier: [u8; 3],
+    pub payload: Vec<u8>,
+}
+
+impl VendorSpecific {
+    /// The IEEE OUI, `identifier` being least significant byte first.
+    pub fn oui(&self) -> u32 {
+        u32::from_le_bytes([
+            self.identifier[0],
+            self.identifier[1],
+            self.identifier[2],
+            0,
+        ])
+    }
+}
+
+fn parse_vendor_specific(input: &[u8]) -> IResult<&[u8], VendorSpecific, VerboseError<&[u8]>> {
+    context("vendor specific data block", |i| {
+        let (i, header) = parse_data_block_header(i)?;
+        let (i, payload) = take(header.len)(i)?;
+        let (payload, identifier) = take(3u8)(payload)?;
+        let (_i, payload) = take(header.len - 3)(payload)?;
+        Ok((
+            i,
+            VendorSpecific {
+                header,
+                identifier: identifier.try_into().unwrap(),
+                payload: Vec::from(payload),
+            },
+        ))
+    })(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpeakerAllocation {
+    pub header: DataBlockHeader,
+    pub speakers: Speakers,
+}
+
+/// Speaker positions of a Speaker Allocation Data Block, a bit set over the
+/// three payload bytes with the first byte in the low bits.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Speakers(pub u32);
+
+impl Speakers {
+    pub const FL_FR: Speakers = Speakers(1 << 0);
+    pub const LFE1: Speakers = Speakers(1 << 1);
+    pub const FC: Speakers = Speakers(1 << 2);
+    pub const BL_BR: Speakers = Speakers(1 << 3);
+    pub const BC: Speakers = Speakers(1 << 4);
+    pub const FLC_FRC: Speakers = Speakers(1 << 5);
+    /// Deprecated.
+    pub const RLC_RRC: Speakers = Speakers(1 << 6);
+    pub const FLW_FRW: Speakers = Speakers(1 << 7);
+    pub const TPFL_TPFR: Speakers = Speakers(1 << 8);
+    pub const TPC: Speakers = Speakers(1 << 9);
+    pub const TPFC: Speakers = Speakers(1 << 10);
+    pub const LS_RS: Speakers = Speakers(1 << 11);
+    pub const LFE2: Speakers = Speakers(1 << 12);
+    pub const TPBC: Speakers = Speakers(1 << 13);
+    pub const SIL_SIR: Speakers = Speakers(1 << 14);
+    pub const TPSIL_TPSIR: Speakers = Speakers(1 << 15);
+    pub const TPBL_TPBR: Speakers = Speakers(1 << 16);
+    pub const BTFC: Speakers = Speakers(1 << 17);
+    pub const BTFL_BTFR: Speakers = Speakers(1 << 18);
+    /// Deprecated.
+    pub const TPLS_TPRS: Speakers = Speakers(1 << 19);
+    /// HDMI only.
+    pub const LSD_RSD: Speakers = Speakers(1 << 20);
+
+    /// The defined speaker positions, in bit order.
+    pub const ALL: [Speakers; 21] = [
+        Speakers::FL_FR,
+        Speakers::LFE1,
+        Speakers::FC,
+        Speakers::BL_BR,
+        Speakers::BC,
+        Speakers::FLC_FRC,
+        Speakers::RLC_RRC,
+        Speakers::FLW_FRW,
+        Speakers::TPFL_TPFR,
+        Speakers::TPC,
+        Speakers::TPFC,
+        Speakers::LS_RS,
+        Speakers::LFE2,
+        Speakers::TPBC,
+        Speakers::SIL_SIR,
+        Speakers::TPSIL_TPSIR,
+        Speakers::TPBL_TPBR,
+        Speakers::BTFC,
+        Speakers::BTFL_BTFR,
+        Speakers::TPLS_TPRS,
+        Speakers::LSD_RSD,
+    ];
+
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(&self, other: Speakers) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// The individual speaker positions present, in bit order. Reserved bits
+    /// are skipped.
+    pub fn iter(&self) -> impl Iterator<Item = Speakers> + '_ {
+        Speakers::ALL
+            .iter()
+            .copied()
+            .filter(move |speaker| self.contains(*speaker))
+    }
+
+    /// CTA-861 abbreviation of a single speaker position, e.g. "FL/FR".
+    pub fn name(&self) -> Option<&'static str> {
+        const NAMES: [&str; 21] = [
+            "FL/FR",
+            "LFE1",
+            "FC",
+            "BL/BR",
+            "BC",
+            "FLc/FRc",
+            "RLC/RRC",
+            "FLw/FRw",
+            "TpFL/TpFR",
+            "TpC",
+            "TpFC",
+            "LS/RS",
+            "LFE2",
+            "TpBC",
+            "SiL/SiR",
+            "TpSiL/TpSiR",
+            "TpBL/TpBR",
+            "BtFC",
+            "BtFL/BtFR",
+            "TpLS/TpRS",
+            "LSd/RSd",
+        ];
+        Speakers::ALL
+            .iter()
+            .position(|speaker| speaker == self)
+            .map(|i| NAMES[i])
+    }
+}
+
+impl std::ops::BitOr for Speakers {
+    type Output = Speakers;
+
+    fn bitor(self, rhs: Speakers) -> Speakers {
+        Speakers(self.0 | rhs.0)
+    }
+}
+
+fn parse_speaker_allocation(
+    input: &[u8],
+) -> IResult<&[u8], SpeakerAllocation, VerboseError<&[u8]>> {
+    context("speaker allocation data block", |i| {
+        let (i, header) = parse_data_block_header(i)?;
+        let (i, payload) = take(header.len)(i)?;
+        let (_i, speakers) = take(3u8)(payload)?;
+        Ok((
+            i,
+            SpeakerAllocation {
+                header,
+                speakers: Speakers(u32::from_le_bytes([
+                    speakers[0],
+                    speakers[1],
+                    speakers[2],
+                    0,
+                ])),
+            },
+        ))
+    })(input)
+}
+
+fn parse_data_block_reserved(
+    input: &[u8],
+) -> IResult<&[u8], DataBlockReserved, VerboseError<&[u8]>> {
+    let (input, header) = parse_data_block_header(input)?;
+    let (input, payload) = take(header.len)(input)?;
+
+    Ok((
+        input,
+        DataBlockReserved {
+            header,
+            payload: payload.to_vec(),
+        },
+    ))
+}
+
+fn parse_data_block(input: &[u8], revision: u8) -> IResult<&[u8], DataBlock, VerboseError<&[u8]>> {
+    let (remaining, header) = peek(parse_data_block_header)(input)?;
+    match header.type_tag {
+        0b001 => map(parse_audio_block, DataBlock::AudioBlock)(remaining),
+        0b010 => map(parse_video_block, DataBlock::VideoBlock)(remaining),
+        0b011 if header.len >= 3 => {
+            map(parse_vendor_specific, DataBlock::VendorSpecific)(remaining)
+        }
+        0b011 => map(
+            parse_data_block_reserved,
+            DataBlock::TruncatedVendorSpecific,
+        )(remaining),
+        0b100 => map(parse_speaker_allocation, |v| {
+            DataBlock::SpeakerAllocation(v)
+        })(remaining),
+        // 0b101 => map(parse_audio_block, |v| DataBlock::AudioBlock(v))(input),
+        // 0b110 => map(parse_audio_block, |v| DataBlock::AudioBlock(v))(input),
+        // Extended tags only exist since revision 3
+        0b111 if revision >= 3 => map(parse_extended_data_block, DataBlock::Extended)(remaining),
+        // _ => Ok((
+        //     remaining,
+        //     DataBlock::Reserved(DataBlockReserved {
+        //         header,
+        //         payload: Vec::from(block_data),
+        //     }),
+        // )),
+        _ => map(parse_data_block_reserved, DataBlock::Reserved)(remaining),
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DataBlockReserved {
+    pub header: DataBlockHeader,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CtaExtensions {
+    pub extension_tag: u8,
+    pub reserved: u8,
+    pub native_dtd: NativeDTDs,
+    pub blocks: Vec<DataBlock>,
+    pub descriptors: Vec<DetailedTiming>,
+}
+
+impl CtaExtensions {
+    // native DTD information bits
+    pub const DTD_UNDERSCAN: u8 = (1u8 << 7); // display supports underscan
+    pub const DTD_BASIC_AUDIO: u8 = (1u8 << 6); // display supports basic audio
+    pub const DTD_YUV444: u8 = (1u8 << 5); // display supports YCbCr 4∶4∶4
+    pub const DTD_YUV422: u8 = (1u8 << 4); // display supports YCbCr 4∶2∶2
+}
+
+impl CtaExtensions {
+    /// The CTA extension revision, stored in the `reserved` byte.
+    pub fn revision(&self) -> u8 {
+        self.reserved
+    }
+
+    /// Whether the block declares revision 3 semantics, i.e. a data block
+    /// collection with extended tags. Later CTA-861 versions keep revision 3.
+    pub fn is_v3(&self) -> bool {
+        self.reserved >= 3
+    }
+
+    /// The extension count of the EEODB, only meaningful in the first
+    /// extension block.
+    pub fn extension_override(&self) -> Option<u8> {
+        self.blocks.iter().find_map(|block| match block {
+            DataBlock::Extended(ExtendedDataBlock::ExtensionOverride(eeodb)) => {
+                Some(eeodb.extension_count)
+            }
+            _ => None,
+        })
+    }
+
+    /// The Native Video Resolution Data Block, if there is one.
+    pub fn native_video_resolution(&self) -> Option<&NativeVideoResolution> {
+        self.blocks.iter().find_map(|block| match block {
+            DataBlock::Extended(ExtendedDataBlock::NativeVideoResolution(nvrdb)) => Some(nvrdb),
+            _ => None,
+        })
+    }
+
+    /// The SVDs of all the Video Data Blocks, in order.
+    pub fn short_video_descriptors(&self) -> impl Iterator<Item = &ShortVideoDescriptor> {
+        self.blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::VideoBlock(video) => Some(&video.descriptors),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// The SVDs also supporting YCbCr 4:2:0, per the 4:2:0 Capability Map.
+    pub fn ycbcr420_capable_svds(&self) -> Vec<&ShortVideoDescriptor> {
+        let map = self.blocks.iter().find_map(|block| match block {
+            DataBlock::Extended(ExtendedDataBlock::Ycbcr420CapabilityMap(map)) => Some(map),
+            _ => None,
+        });
+        match map {
+            Some(map) => map.resolve(self.short_video_descriptors()),
+            None => Vec::new(),
+        }
+    }
+}
+
+impl EDID {
+    pub fn cta_extensions(&self) -> impl Iterator<Item = &CtaExtensions> {
+        self.extensions.iter().filter_map(|ext| match ext {
+            Extension::Cta(cta) => Some(cta),
+            _ => None,
+        })
+    }
+
+    /// The data blocks of all CTA extensions, followed by those embedded in
+    /// DisplayID sections.
+    pub fn cta_data_blocks(&self) -> impl Iterator<Item = &DataBlock> {
+        let embedded = self.extensions.iter().flat_map(|ext| match ext {
+            #[cfg(feature = "displayid")]
+            Extension::DisplayId(displayid) => displayid.cta_data_blocks().collect(),
+            _ => Vec::new(),
+        });
+        self.cta_extensions()
+            .flat_map(|cta| cta.blocks.iter())
+            .chain(embedded)
+    }
+}
+
+fn parse_descriptors(input: &[u8]) -> IResult<&[u8], Vec<DetailedTiming>, VerboseError<&[u8]>> {
+    many0(map(
+        tuple((peek(not(tag(&[0, 0]))), take(18u8))),
+        |(_, data)| {
+            let (_, detailed_timing) = parse_detailed_timing(data).unwrap();
+            detailed_timing
+        },
+    ))(input)
+}
+
+pub(crate) fn parse_extension(input: &[u8]) -> IResult<&[u8], CtaExtensions, VerboseError<&[u8]>> {
+    let (input, block) = take(128u8)(input)?;
+    let (rest, (extension_tag, reserved, dtd_flag)) = tuple((le_u8, le_u8, le_u8))(block)?;
+    // Revision 1 blocks have neither capability flags nor data blocks
+    let (_, native_dtd) = if reserved >= 2 {
+        parse_native_dtds(rest)?
+    } else {
+        (rest, NativeDTDs::default())
+    };
+
+    // Offset 0 means no data blocks and no DTDs, the invalid offsets read
+    // the same. Both areas end at the checksum byte.
+    let (data_end, dtd_start) = match dtd_flag as usize {
+        offset @ 4..=127 => (offset, offset),
+        _ => (4, 127),
+    };
+    let extension_data = if reserved >= 2 {
+        &block[4..data_end]
+    } else {
+        &[]
+    };
+    let (_, data_block) = parse_blocks(extension_data, reserved)?;
+    let (_, detailed_timing) = parse_descriptors(&block[dtd_start..127])?;
+
+    Ok((
+        input,
+        CtaExtensions {
+            extension_tag,
+            reserved,
+            native_dtd,
+            blocks: data_block,
+            descriptors: detailed_timing,
+        },
+    ))
+}
@@ -0,0 +1,165 @@
+use nom::{bytes::complete::take, error::VerboseError, IResult};
+
+// See VESA Display Information Extension Block (DI-EXT) Standard, Release A
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ContentProtection {
+    None,
+    Hdcp,
+    Dtcp,
+    Dpa,
+    Reserved(u8),
+}
+
+/// Digital interface data format, byte 0x06.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ColorEncoding {
+    /// 8-bit over 8-bit RGB.
+    Rgb8,
+    /// 12-bit over 12-bit RGB.
+    Rgb12,
+    /// 24-bit MSB-aligned RGB, single link.
+    Rgb24,
+    /// 48-bit MSB-aligned RGB, dual link high resolution.
+    Rgb48HighResolution,
+    /// 48-bit MSB-aligned RGB, dual link high color.
+    Rgb48HighColor,
+    Reserved(u8),
+}
+
+impl ColorEncoding {
+    pub fn code(self) -> u8 {
+        match self {
+            ColorEncoding::Rgb8 => 0x15,
+            ColorEncoding::Rgb12 => 0x19,
+            ColorEncoding::Rgb24 => 0x24,
+            ColorEncoding::Rgb48HighResolution => 0x48,
+            ColorEncoding::Rgb48HighColor => 0x49,
+            ColorEncoding::Reserved(code) => code,
+        }
+    }
+
+    /// Bits per color and pixels per clock of the format.
+    fn layout(self) -> Option<(u8, u8)> {
+        match self {
+            ColorEncoding::Rgb8 | ColorEncoding::Rgb24 => Some((8, 1)),
+            ColorEncoding::Rgb12 => Some((12, 1)),
+            // Two pixels per clock, one on each link
+            ColorEncoding::Rgb48HighResolution => Some((8, 2)),
+            ColorEncoding::Rgb48HighColor => Some((16, 1)),
+            ColorEncoding::Reserved(_) => None,
+        }
+    }
+}
+
+impl From<u8> for ColorEncoding {
+    fn from(code: u8) -> ColorEncoding {
+        match code {
+            0x15 => ColorEncoding::Rgb8,
+            0x19 => ColorEncoding::Rgb12,
+            0x24 => ColorEncoding::Rgb24,
+            0x48 => ColorEncoding::Rgb48HighResolution,
+            0x49 => ColorEncoding::Rgb48HighColor,
+            other => ColorEncoding::Reserved(other),
+        }
+    }
+}
+
+/// Required digital interface section, bytes 0x02-0x0D.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DiExtInterface {
+    pub interface_type: u8,
+    pub interface_version: u8,
+    pub interface_release: u8,
+    pub content_protection: ContentProtection,
+    pub color_encoding: ColorEncoding,
+    /// Bits per color of the color encoding, `None` if it is reserved.
+    pub bits_per_color: Option<u8>,
+    /// Pixels per clock of the color encoding, `None` if it is reserved.
+    pub pixels_per_clock: Option<u8>,
+    pub raw: [u8; 12],
+}
+
+impl DiExtInterface {
+    pub const ANALOG: u8 = 0x00;
+}
+
+/// Display device section, bytes 0x0E-0x13.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DiExtDisplayDevice {
+    /// Display technology, bits 7:4 of byte 0x0E.
+    pub technology: u8,
+    /// Technology sub-type, bits 3:0 of byte 0x0E.
+    pub technology_subtype: u8,
+    pub raw: [u8; 6],
+}
+
+/// Display Information extension (tag 0x40).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DiExt {
+    pub version: u8,
+    pub interface: DiExtInterface,
+    pub display_device: DiExtDisplayDevice,
+    /// Display capabilities and feature support set, bytes 0x14-0x36.
+    pub capabilities: Vec<u8>,
+    /// Display transfer characteristics, bytes 0x51-0x7E.
+    pub transfer_characteristics: Vec<u8>,
+}
+
+fn parse_interface(b: &[u8]) -> DiExtInterface {
+    let color_encoding = ColorEncoding::from(b[4]);
+    let layout = color_encoding.layout();
+    DiExtInterface {
+        interface_type: b[0],
+        interface_version: b[1] >> 4,
+        interface_release: b[1] & 0xf,
+        content_protection: match b[2] {
+            0x00 => ContentProtection::None,
+            0x01 => ContentProtection::Hdcp,
+            0x02 => ContentProtection::Dtcp,
+            0x03 => ContentProtection::Dpa,
+            other => ContentProtection::Reserved(other),
+        },
+        color_encoding,
+        bits_per_color: layout.map(|(bits, _)| bits),
+        pixels_per_clock: layout.map(|(_, pixels)| pixels),
+        raw: b.try_into().unwrap(),
+    }
+}
+
+fn parse_display_device(b: &[u8]) -> DiExtDisplayDevice {
+    DiExtDisplayDevice {
+        technology: b[0] >> 4,
+        technology_subtype: b[0] & 0xf,
+        raw: b.try_into().unwrap(),
+    }
+}
+
+pub(crate) fn parse_di_ext(input: &[u8]) -> IResult<&[u8], DiExt, VerboseError<&[u8]>> {
+    let (input, block) = take(128u8)(input)?;
+    Ok((
+        input,
+        DiExt {
+            version: block[0x01],
+            interface: parse_interface(&block[0x02..0x0E]),
+            display_device: parse_display_device(&block[0x0E..0x14]),
+            capabilities: block[0x14..0x37].to_vec(),
+            transfer_characteristics: block[0x51..0x7F].to_vec(),
+        },
+    ))
+}
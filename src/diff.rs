@@ -0,0 +1,394 @@
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Write},
+};
+
+use crate::edid::{Descriptor, DetailedTiming, RangeLimits, EDID};
+#[cfg(feature = "cta")]
+use crate::{
+    cta::DataBlock,
+    extended::{ExtendedDataBlock, HdrStaticMetadata},
+};
+
+/// Entries only in the old or only in the new EDID, sorted.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct SetDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl SetDiff {
+    fn new(old: BTreeSet<String>, new: BTreeSet<String>) -> Self {
+        SetDiff {
+            added: new.difference(&old).cloned().collect(),
+            removed: old.difference(&new).cloned().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Capability changes between two revisions of a monitor's EDID.
+///
+/// Modes are named `WxH@Hz` for timings and `vicN` for SVDs, audio formats
+/// `name:Nch` and HDR entries e.g. `eotf:pq` or `max_luminance:603`.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct CapabilityDiff {
+    pub modes: SetDiff,
+    pub audio_formats: SetDiff,
+    pub hdr: SetDiff,
+}
+
+fn timing_name(timing: &DetailedTiming) -> String {
+    format!(
+        "{}x{}@{}",
+        timing.horizontal_active_pixels,
+        timing.vertical_active_lines,
+        timing.refresh_rate().round()
+    )
+}
+
+fn modes(edid: &EDID) -> BTreeSet<String> {
+    let mut modes = BTreeSet::new();
+    for descriptor in &edid.descriptors {
+        if let Descriptor::DetailedTiming(timing) = descriptor {
+            modes.insert(timing_name(timing));
+        }
+    }
+    for st in &edid.standard_timing {
+        modes.insert(format!(
+            "{}x{}@{}",
+            st.horizontal_active_pixels,
+            st.vertical_active_lines(),
+            st.refresh_rate
+        ));
+    }
+    #[cfg(feature = "cta")]
+    for cta in edid.cta_extensions() {
+        modes.extend(cta.descriptors.iter().map(timing_name));
+        modes.extend(
+            cta.short_video_descriptors()
+                .map(|svd| format!("vic{}", svd.cea861_index)),
+        );
+    }
+    modes
+}
+
+#[cfg(feature = "cta")]
+fn audio_formats(edid: &EDID) -> BTreeSet<String> {
+    let mut formats = BTreeSet::new();
+    for cta in edid.cta_extensions() {
+        for block in &cta.blocks {
+            if let DataBlock::AudioBlock(audio) = block {
+                formats.extend(
+                    audio.descriptors.iter().map(|sad| {
+                        format!("{}:{}ch", sad.format().as_str(), sad.number_of_channels)
+                    }),
+                );
+            }
+        }
+    }
+    formats
+}
+
+#[cfg(feature = "cta")]
+fn hdr_static(hdr: &HdrStaticMetadata, entries: &mut BTreeSet<String>) {
+    entries.extend(
+        hdr.supported_eotfs()
+            .map(|eotf| format!("eotf:{}", eotf.as_str())),
+    );
+    if hdr.static_metadata_descriptors & HdrStaticMetadata::STATIC_METADATA_TYPE1 != 0 {
+        entries.insert("static_metadata:type1".to_string());
+    }
+    if let Some(max) = hdr.max_luminance_cd_m2() {
+        entries.insert(format!("max_luminance:{:.0}", max));
+    }
+    if let Some(avg) = hdr.max_frame_avg_luminance_cd_m2() {
+        entries.insert(format!("max_frame_avg_luminance:{:.0}", avg));
+    }
+    if let Some(min) = hdr.min_luminance_cd_m2() {
+        entries.insert(format!("min_luminance:{:.4}", min));
+    }
+}
+
+#[cfg(feature = "cta")]
+fn hdr(edid: &EDID) -> BTreeSet<String> {
+    let mut entries = BTreeSet::new();
+    for cta in edid.cta_extensions() {
+        for block in &cta.blocks {
+            match block {
+                DataBlock::Extended(ExtendedDataBlock::HdrStaticMetadata(hdr)) => {
+                    hdr_static(hdr, &mut entries)
+                }
+                DataBlock::Extended(ExtendedDataBlock::HdrDynamicMetadata(dynamic)) => entries
+                    .extend(
+                        dynamic.metadata_types.iter().map(|t| {
+                            format!("dynamic_metadata:{}:v{}", t.metadata_type, t.version())
+                        }),
+                    ),
+                _ => {}
+            }
+        }
+    }
+    entries
+}
+
+// Audio and HDR capabilities all come from CTA extensions
+#[cfg(not(feature = "cta"))]
+fn audio_formats(_edid: &EDID) -> BTreeSet<String> {
+    BTreeSet::new()
+}
+
+#[cfg(not(feature = "cta"))]
+fn hdr(_edid: &EDID) -> BTreeSet<String> {
+    BTreeSet::new()
+}
+
+fn json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn json_list(out: &mut String, list: &[String]) {
+    out.push('[');
+    for (i, entry) in list.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        json_string(out, entry);
+    }
+    out.push(']');
+}
+
+impl CapabilityDiff {
+    pub fn new(old: &EDID, new: &EDID) -> Self {
+        CapabilityDiff {
+            modes: SetDiff::new(modes(old), modes(new)),
+            audio_formats: SetDiff::new(audio_formats(old), audio_formats(new)),
+            hdr: SetDiff::new(hdr(old), hdr(new)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modes.is_empty() && self.audio_formats.is_empty() && self.hdr.is_empty()
+    }
+
+    /// Compact JSON, e.g.
+    /// `{"modes":{"added":["vic97"],"removed":[]},"audio_formats":…,"hdr":…}`.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{");
+        let sections = [
+            ("modes", &self.modes),
+            ("audio_formats", &self.audio_formats),
+            ("hdr", &self.hdr),
+        ];
+        for (i, (name, diff)) in sections.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            json_string(&mut out, name);
+            out.push_str(":{\"added\":");
+            json_list(&mut out, &diff.added);
+            out.push_str(",\"removed\":");
+            json_list(&mut out, &diff.removed);
+            out.push('}');
+        }
+        out.push('}');
+        out
+    }
+}
+
+/// Shorthand for `CapabilityDiff::new(old, new).to_json()`.
+pub fn capability_diff_json(old: &EDID, new: &EDID) -> String {
+    CapabilityDiff::new(old, new).to_json()
+}
+
+/// A field-level difference between two EDIDs, see `EDID::diff`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Difference {
+    /// A field with a different value, both rendered as text.
+    Changed {
+        field: &'static str,
+        old: String,
+        new: String,
+    },
+    /// An entry, e.g. a mode, only in the new EDID.
+    Added { field: &'static str, value: String },
+    /// An entry only in the old EDID.
+    Removed { field: &'static str, value: String },
+}
+
+impl Difference {
+    pub fn field(&self) -> &'static str {
+        match self {
+            Difference::Changed { field, .. }
+            | Difference::Added { field, .. }
+            | Difference::Removed { field, .. } => field,
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Difference::Changed { field, old, new } => write!(f, "{}: {} -> {}", field, old, new),
+            Difference::Added { field, value } => write!(f, "{} added: {}", field, value),
+            Difference::Removed { field, value } => write!(f, "{} removed: {}", field, value),
+        }
+    }
+}
+
+fn optional_text(text: Option<String>) -> String {
+    text.map_or_else(|| "none".to_string(), |text| format!("{:?}", text))
+}
+
+fn timing_text(timing: Option<&DetailedTiming>) -> String {
+    timing.map_or_else(
+        || "none".to_string(),
+        |timing| format!("{} ({} kHz)", timing_name(timing), timing.pixel_clock),
+    )
+}
+
+fn range_limits_text(limits: Option<&RangeLimits>) -> String {
+    limits.map_or_else(
+        || "none".to_string(),
+        |limits| {
+            format!(
+                "{}-{} Hz, {}-{} kHz, {} MHz",
+                limits.min_vertical_rate,
+                limits.max_vertical_rate,
+                limits.min_horizontal_rate,
+                limits.max_horizontal_rate,
+                limits.max_pixel_clock / 1000
+            )
+        },
+    )
+}
+
+fn extensions_text(edid: &EDID) -> String {
+    let tags: Vec<String> = edid
+        .extensions
+        .iter()
+        .map(|extension| match extension.extension_tag() {
+            Some(tag) => tag.as_str().to_string(),
+            None => format!("{:#04x}", extension.tag()),
+        })
+        .collect();
+    format!("[{}]", tags.join(", "))
+}
+
+impl EDID {
+    /// Compares the identification, display parameters, preferred timing,
+    /// range limits and extension list field by field, then the modes,
+    /// audio formats and HDR capabilities as in `CapabilityDiff`.
+    ///
+    /// Empty when both describe the same monitor the same way, even if
+    /// their bytes differ.
+    pub fn diff(&self, other: &EDID) -> Vec<Difference> {
+        let (old_info, new_info) = (self.product_info(), other.product_info());
+        let fields = [
+            (
+                "version",
+                format!("{}.{}", self.header.version, self.header.revision),
+                format!("{}.{}", other.header.version, other.header.revision),
+            ),
+            (
+                "vendor",
+                self.header.vendor_string(),
+                other.header.vendor_string(),
+            ),
+            (
+                "product",
+                format!("{:#06x}", self.header.product),
+                format!("{:#06x}", other.header.product),
+            ),
+            (
+                "serial",
+                old_info.serial.to_string(),
+                new_info.serial.to_string(),
+            ),
+            (
+                "manufacture_date",
+                format!("week {} of {}", self.header.week, self.header.year_ad()),
+                format!("week {} of {}", other.header.week, other.header.year_ad()),
+            ),
+            (
+                "product_name",
+                optional_text(old_info.product_name),
+                optional_text(new_info.product_name),
+            ),
+            (
+                "serial_number",
+                optional_text(old_info.serial_number),
+                optional_text(new_info.serial_number),
+            ),
+            (
+                "video_input",
+                format!("{:#04x}", self.display.video_input),
+                format!("{:#04x}", other.display.video_input),
+            ),
+            (
+                "screen_size",
+                format!("{:?}", self.screen_size()),
+                format!("{:?}", other.screen_size()),
+            ),
+            (
+                "gamma",
+                self.display.gamma.to_string(),
+                other.display.gamma.to_string(),
+            ),
+            (
+                "features",
+                format!("{:#04x}", self.display.features),
+                format!("{:#04x}", other.display.features),
+            ),
+            (
+                "preferred_timing",
+                timing_text(self.preferred_timing()),
+                timing_text(other.preferred_timing()),
+            ),
+            (
+                "range_limits",
+                range_limits_text(self.range_limits()),
+                range_limits_text(other.range_limits()),
+            ),
+            ("extensions", extensions_text(self), extensions_text(other)),
+        ];
+        let mut differences: Vec<Difference> = fields
+            .into_iter()
+            .filter(|(_, old, new)| old != new)
+            .map(|(field, old, new)| Difference::Changed { field, old, new })
+            .collect();
+
+        let capabilities = CapabilityDiff::new(self, other);
+        for (field, diff) in [
+            ("mode", capabilities.modes),
+            ("audio_format", capabilities.audio_formats),
+            ("hdr", capabilities.hdr),
+        ] {
+            differences.extend(
+                diff.added
+                    .into_iter()
+                    .map(|value| Difference::Added { field, value }),
+            );
+            differences.extend(
+                diff.removed
+                    .into_iter()
+                    .map(|value| Difference::Removed { field, value }),
+            );
+        }
+        differences
+    }
+}
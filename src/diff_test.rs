@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod tests {
+    use crate::{diff::*, edid::*};
+
+    #[test]
+    fn test_capability_diff_identical() {
+        let d = include_bytes!("../testdata/card0-HDMI-2.bin");
+        let (_, edid) = parse(d).unwrap();
+        let diff = CapabilityDiff::new(&edid, &edid);
+        assert!(diff.is_empty());
+        assert_eq!(
+            capability_diff_json(&edid, &edid),
+            concat!(
+                r#"{"modes":{"added":[],"removed":[]},"#,
+                r#""audio_formats":{"added":[],"removed":[]},"#,
+                r#""hdr":{"added":[],"removed":[]}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_capability_diff() {
+        let (_, old) = parse(include_bytes!("../testdata/card0-HDMI-1.bin")).unwrap();
+        let (_, new) = parse(include_bytes!("../testdata/card0-HDMI-2.bin")).unwrap();
+        let diff = CapabilityDiff::new(&old, &new);
+        assert!(diff.modes.added.contains(&"3840x2160@60".to_string()));
+        assert!(diff.modes.added.contains(&"vic97".to_string()));
+        assert!(diff.modes.removed.contains(&"vic1".to_string()));
+        // Both only have 2 channel LPCM
+        assert!(diff.audio_formats.is_empty());
+        assert_eq!(
+            diff.hdr.added,
+            vec![
+                "eotf:pq",
+                "eotf:traditional_sdr",
+                "max_frame_avg_luminance:486",
+                "max_luminance:486",
+                "min_luminance:0.4666",
+                "static_metadata:type1",
+            ]
+        );
+        assert!(diff.hdr.removed.is_empty());
+        assert!(diff
+            .to_json()
+            .ends_with(r#""hdr":{"added":["eotf:pq","eotf:traditional_sdr","max_frame_avg_luminance:486","max_luminance:486","min_luminance:0.4666","static_metadata:type1"],"removed":[]}}"#));
+    }
+
+    #[test]
+    fn test_edid_diff() {
+        let (_, old) = parse(include_bytes!("../testdata/card0-HDMI-1.bin")).unwrap();
+        let (_, new) = parse(include_bytes!("../testdata/card0-HDMI-2.bin")).unwrap();
+        assert_eq!(old.diff(&old), vec![]);
+
+        let differences = old.diff(&new);
+        assert!(differences.contains(&Difference::Changed {
+            field: "product_name",
+            old: r#""DELL S2440L""#.to_string(),
+            new: r#""HDP-V104""#.to_string(),
+        }));
+        assert!(differences.contains(&Difference::Added {
+            field: "mode",
+            value: "vic97".to_string(),
+        }));
+        let preferred = differences
+            .iter()
+            .find(|d| d.field() == "preferred_timing")
+            .unwrap();
+        assert_eq!(
+            preferred.to_string(),
+            "preferred_timing: 1920x1080@60 (148500 kHz) -> 3840x2160@60 (533120 kHz)"
+        );
+        assert!(!differences.iter().any(|d| d.field() == "extensions"));
+    }
+}
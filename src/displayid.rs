@@ -0,0 +1,431 @@
+use nom::{
+    branch::alt,
+    bytes::complete::take,
+    combinator::{map, peek, verify},
+    error::VerboseError,
+    multi::many0,
+    number::complete::{le_u16, le_u24, le_u32, le_u8},
+    sequence::tuple,
+    IResult,
+};
+
+#[cfg(feature = "cta")]
+use crate::cta::{parse_blocks, DataBlock};
+use crate::{
+    edid::DetailedTiming,
+    limits::{many0_capped, MAX_DATA_BLOCKS},
+};
+
+/// DisplayID extension (tag 0x70), holding a single DisplayID section.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DisplayId {
+    pub version: u8,
+    pub revision: u8,
+    /// Product type (DisplayID 1.x) or primary use case (DisplayID 2.x).
+    pub product_type: u8,
+    pub extension_count: u8,
+    pub blocks: Vec<DisplayIdDataBlock>,
+    pub checksum: u8,
+    /// Whether the section bytes sum to zero.
+    pub checksum_valid: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DisplayIdBlockHeader {
+    pub tag: u8,
+    /// Block revision, bits 2:0. The upper bits are block-specific flags.
+    pub revision: u8,
+    pub len: u8,
+}
+
+fn parse_block_header(input: &[u8]) -> IResult<&[u8], DisplayIdBlockHeader, VerboseError<&[u8]>> {
+    map(tuple((le_u8, le_u8, le_u8)), |(tag, revision, len)| {
+        DisplayIdBlockHeader { tag, revision, len }
+    })(input)
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DisplayIdDataBlock {
+    /// Type I Detailed Timing Data Block (DisplayID 1.x).
+    TypeITimings(Vec<DisplayIdTiming>),
+    /// Type VII Detailed Timing Data Block (DisplayID 2.x).
+    TypeViiTimings(Vec<DisplayIdTiming>),
+    TiledTopology(TiledTopology),
+    ProductId(ProductId),
+    /// CTA DisplayID Data Block, holding CTA-861 data blocks.
+    #[cfg(feature = "cta")]
+    Cta(Vec<DataBlock>),
+    Reserved(DisplayIdBlockReserved),
+}
+
+impl DisplayIdDataBlock {
+    pub const PRODUCT_ID: u8 = 0x00;
+    pub const PRODUCT_ID_V2: u8 = 0x20;
+    pub const TYPE_I_TIMING: u8 = 0x03;
+    pub const TYPE_VII_TIMING: u8 = 0x22;
+    pub const TILED_TOPOLOGY: u8 = 0x12;
+    pub const TILED_TOPOLOGY_V2: u8 = 0x28;
+    pub const CTA: u8 = 0x81;
+}
+
+/// A 20-byte Type I or Type VII detailed timing descriptor.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DisplayIdTiming {
+    /// The timing, with the sync polarities and interlacing in `features`
+    /// as for a DTD.
+    pub timing: DetailedTiming,
+    pub preferred: bool,
+    /// Stereo 3D support: 0 mono, 1 stereo, 2 user selectable.
+    pub stereo: u8,
+    /// Aspect ratio code: 0 1:1, 1 5:4, 2 4:3, 3 15:9, 4 16:9, 5 16:10,
+    /// 6 64:27, 7 256:135, 8 undefined.
+    pub aspect_ratio: u8,
+}
+
+/// Parses a timing descriptor, `clock_unit` being the pixel clock
+/// resolution in kHz.
+fn parse_timing(
+    clock_unit: u32,
+) -> impl FnMut(&[u8]) -> IResult<&[u8], DisplayIdTiming, VerboseError<&[u8]>> {
+    move |input| {
+        let (input, (clock, options)) = tuple((le_u24, le_u8))(input)?;
+        let (input, (h_active, h_blank, h_front, h_sync)) =
+            tuple((le_u16, le_u16, le_u16, le_u16))(input)?;
+        let (input, (v_active, v_blank, v_front, v_sync)) =
+            tuple((le_u16, le_u16, le_u16, le_u16))(input)?;
+
+        // Every field is stored minus one, bit 15 of the front porches is
+        // the sync polarity
+        let plus_one = |v: u16| v.saturating_add(1);
+        let mut features = 0x18;
+        if h_front & 0x8000 != 0 {
+            features |= 0x02;
+        }
+        if v_front & 0x8000 != 0 {
+            features |= 0x04;
+        }
+        if options & 0x10 != 0 {
+            features |= 0x80;
+        }
+        Ok((
+            input,
+            DisplayIdTiming {
+                timing: DetailedTiming {
+                    pixel_clock: (clock + 1) * clock_unit,
+                    horizontal_active_pixels: plus_one(h_active),
+                    horizontal_blanking_pixels: plus_one(h_blank),
+                    vertical_active_lines: plus_one(v_active),
+                    vertical_blanking_lines: plus_one(v_blank),
+                    horizontal_front_porch: plus_one(h_front & 0x7fff),
+                    horizontal_sync_width: plus_one(h_sync),
+                    vertical_front_porch: plus_one(v_front & 0x7fff),
+                    vertical_sync_width: plus_one(v_sync),
+                    features,
+                    ..Default::default()
+                },
+                preferred: options & 0x80 != 0,
+                stereo: (options >> 5) & 0b11,
+                aspect_ratio: options & 0xf,
+            },
+        ))
+    }
+}
+
+fn parse_timings(
+    clock_unit: u32,
+    input: &[u8],
+) -> IResult<&[u8], Vec<DisplayIdTiming>, VerboseError<&[u8]>> {
+    let (input, header) = parse_block_header(input)?;
+    let (input, payload) = take(header.len)(input)?;
+    let (_, timings) = many0(parse_timing(clock_unit))(payload)?;
+    Ok((input, timings))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DisplayIdBlockReserved {
+    pub header: DisplayIdBlockHeader,
+    pub payload: Vec<u8>,
+}
+
+/// Tiled Display Topology Data Block, describing where this tile sits in a
+/// display driven as several tiles.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TiledTopology {
+    /// All tiles share one physical enclosure.
+    pub single_enclosure: bool,
+    /// Behaviour when several but not all tiles receive an image:
+    /// 0 undefined, 1 image shown at the tile location.
+    pub multi_tile_behavior: u8,
+    /// Behaviour when only this tile receives an image: 0 undefined,
+    /// 1 shown at the tile location, 2 scaled to fit, 3 cloned.
+    pub single_tile_behavior: u8,
+    pub horizontal_tiles: u8,
+    pub vertical_tiles: u8,
+    /// Zero-based column of this tile.
+    pub horizontal_location: u8,
+    /// Zero-based row of this tile.
+    pub vertical_location: u8,
+    pub tile_width: u16,
+    pub tile_height: u16,
+    pub bezel: Option<TileBezel>,
+    /// Manufacturer of the tiled display: a PNP ID for DisplayID 1.x, an
+    /// IEEE OUI for DisplayID 2.x.
+    pub vendor_id: [u8; 3],
+    pub product_code: u16,
+    pub serial_number: u32,
+}
+
+/// Bezel sizes around a tile, in units of `pixel_multiplier / 10` pixels.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TileBezel {
+    pub pixel_multiplier: u8,
+    pub top: u8,
+    pub bottom: u8,
+    pub right: u8,
+    pub left: u8,
+}
+
+impl TileBezel {
+    /// The (top, bottom, right, left) bezel sizes in pixels.
+    pub fn pixels(&self) -> (u16, u16, u16, u16) {
+        let scale = |v: u8| (v as u16 * self.pixel_multiplier as u16) / 10;
+        (
+            scale(self.top),
+            scale(self.bottom),
+            scale(self.right),
+            scale(self.left),
+        )
+    }
+}
+
+fn parse_tiled_topology(input: &[u8]) -> IResult<&[u8], TiledTopology, VerboseError<&[u8]>> {
+    let (input, header) = parse_block_header(input)?;
+    let (input, payload) = take(header.len)(input)?;
+
+    let (rest, (caps, tiles, location, high)) = tuple((le_u8, le_u8, le_u8, le_u8))(payload)?;
+    let (rest, (tile_width, tile_height)) = tuple((le_u16, le_u16))(rest)?;
+    let (rest, bezel) = take(5u8)(rest)?;
+    let (rest, vendor_id) = take(3u8)(rest)?;
+    let (_, (product_code, serial_number)) = tuple((le_u16, le_u32))(rest)?;
+
+    // Tile counts and locations are six bits wide, the upper two bits of
+    // each live in the fourth byte
+    let horizontal_tiles = ((tiles >> 4) | ((high >> 2) & 0x30)) + 1;
+    let vertical_tiles = ((tiles & 0xf) | (high & 0x30)) + 1;
+    let horizontal_location = (location >> 4) | ((high << 2) & 0x30);
+    let vertical_location = (location & 0xf) | ((high << 4) & 0x30);
+
+    Ok((
+        input,
+        TiledTopology {
+            single_enclosure: caps & 0x80 != 0,
+            multi_tile_behavior: (caps >> 3) & 0b11,
+            single_tile_behavior: caps & 0b111,
+            horizontal_tiles,
+            vertical_tiles,
+            horizontal_location,
+            vertical_location,
+            tile_width: tile_width.saturating_add(1),
+            tile_height: tile_height.saturating_add(1),
+            bezel: (caps & 0x40 != 0).then(|| TileBezel {
+                pixel_multiplier: bezel[0],
+                top: bezel[1],
+                bottom: bezel[2],
+                right: bezel[3],
+                left: bezel[4],
+            }),
+            vendor_id: [vendor_id[0], vendor_id[1], vendor_id[2]],
+            product_code,
+            serial_number,
+        },
+    ))
+}
+
+/// Product Identification Data Block.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ProductId {
+    /// A PNP ID for DisplayID 1.x, an IEEE OUI for DisplayID 2.x.
+    pub vendor_id: [u8; 3],
+    pub product_code: u16,
+    pub serial_number: u32,
+    pub week: u8,
+    /// Starting at year 2000. The model year when `week` is 0xFF.
+    pub year: u8,
+    pub product_name: String,
+}
+
+impl ProductId {
+    pub fn year_ad(&self) -> u16 {
+        2000 + self.year as u16
+    }
+}
+
+fn parse_product_id(input: &[u8]) -> IResult<&[u8], ProductId, VerboseError<&[u8]>> {
+    let (input, header) = parse_block_header(input)?;
+    let (input, payload) = take(header.len)(input)?;
+
+    let (rest, vendor_id) = take(3u8)(payload)?;
+    let (rest, (product_code, serial_number, week, year, name_len)) =
+        tuple((le_u16, le_u32, le_u8, le_u8, le_u8))(rest)?;
+    let (_, name) = take(name_len)(rest)?;
+
+    Ok((
+        input,
+        ProductId {
+            vendor_id: [vendor_id[0], vendor_id[1], vendor_id[2]],
+            product_code,
+            serial_number,
+            week,
+            year,
+            product_name: String::from_utf8_lossy(name)
+                .trim_end_matches(|c: char| c == '\0' || c.is_whitespace())
+                .trim_start()
+                .to_string(),
+        },
+    ))
+}
+
+#[cfg(feature = "cta")]
+fn parse_cta(input: &[u8]) -> IResult<&[u8], Vec<DataBlock>, VerboseError<&[u8]>> {
+    let (input, header) = parse_block_header(input)?;
+    let (input, payload) = take(header.len)(input)?;
+    // Embedded blocks follow the current CTA-861 data block collection
+    let (_, blocks) = parse_blocks(payload, 3)?;
+    Ok((input, blocks))
+}
+
+fn parse_block_reserved(
+    input: &[u8],
+) -> IResult<&[u8], DisplayIdBlockReserved, VerboseError<&[u8]>> {
+    let (input, header) = parse_block_header(input)?;
+    let (input, payload) = take(header.len)(input)?;
+    Ok((
+        input,
+        DisplayIdBlockReserved {
+            header,
+            payload: payload.to_vec(),
+        },
+    ))
+}
+
+fn parse_data_block(input: &[u8]) -> IResult<&[u8], DisplayIdDataBlock, VerboseError<&[u8]>> {
+    // Zero bytes start the padding after the last block, a zero tag with a
+    // payload is a DisplayID 1.x product identification block
+    let (_, (tag, _, _)) = peek(verify(tuple((le_u8, le_u8, le_u8)), |(tag, _, len)| {
+        *tag != 0 || *len != 0
+    }))(input)?;
+    match tag {
+        DisplayIdDataBlock::PRODUCT_ID | DisplayIdDataBlock::PRODUCT_ID_V2 => alt((
+            map(parse_product_id, DisplayIdDataBlock::ProductId),
+            map(parse_block_reserved, DisplayIdDataBlock::Reserved),
+        ))(input),
+        DisplayIdDataBlock::TYPE_I_TIMING => {
+            map(|i| parse_timings(10, i), DisplayIdDataBlock::TypeITimings)(input)
+        }
+        DisplayIdDataBlock::TYPE_VII_TIMING => {
+            map(|i| parse_timings(1, i), DisplayIdDataBlock::TypeViiTimings)(input)
+        }
+        DisplayIdDataBlock::TILED_TOPOLOGY | DisplayIdDataBlock::TILED_TOPOLOGY_V2 => {
+            alt((
+                map(parse_tiled_topology, DisplayIdDataBlock::TiledTopology),
+                map(parse_block_reserved, DisplayIdDataBlock::Reserved),
+            ))(input)
+        }
+        #[cfg(feature = "cta")]
+        DisplayIdDataBlock::CTA => alt((
+            map(parse_cta, DisplayIdDataBlock::Cta),
+            map(parse_block_reserved, DisplayIdDataBlock::Reserved),
+        ))(input),
+        _ => map(parse_block_reserved, DisplayIdDataBlock::Reserved)(input),
+    }
+}
+
+fn parse_section(input: &[u8]) -> IResult<&[u8], DisplayId, VerboseError<&[u8]>> {
+    let (i, (version, len, product_type, extension_count)) =
+        tuple((le_u8, le_u8, le_u8, le_u8))(input)?;
+    let (i, payload) = take(len)(i)?;
+    let (i, checksum) = le_u8(i)?;
+    let (_, blocks) = many0_capped(MAX_DATA_BLOCKS, "data blocks", parse_data_block)(payload)?;
+
+    // The checksum covers the section header and payload
+    let sum = input[..len as usize + 5]
+        .iter()
+        .fold(0u8, |sum, b| sum.wrapping_add(*b));
+    Ok((
+        i,
+        DisplayId {
+            version: version >> 4,
+            revision: version & 0xf,
+            product_type,
+            extension_count,
+            blocks,
+            checksum,
+            checksum_valid: sum == 0,
+        },
+    ))
+}
+
+impl DisplayId {
+    /// The Type I and Type VII detailed timings, in block order.
+    pub fn timings(&self) -> impl Iterator<Item = &DisplayIdTiming> {
+        self.blocks.iter().flat_map(|block| match block {
+            DisplayIdDataBlock::TypeITimings(timings)
+            | DisplayIdDataBlock::TypeViiTimings(timings) => timings.iter(),
+            _ => [].iter(),
+        })
+    }
+
+    pub fn product_id(&self) -> Option<&ProductId> {
+        self.blocks.iter().find_map(|block| match block {
+            DisplayIdDataBlock::ProductId(product) => Some(product),
+            _ => None,
+        })
+    }
+
+    /// The CTA data blocks embedded in CTA DisplayID data blocks.
+    #[cfg(feature = "cta")]
+    pub fn cta_data_blocks(&self) -> impl Iterator<Item = &DataBlock> {
+        self.blocks.iter().flat_map(|block| match block {
+            DisplayIdDataBlock::Cta(blocks) => blocks.iter(),
+            _ => [].iter(),
+        })
+    }
+
+    /// The tiled display topology, if this display is one tile of several.
+    pub fn tiled_topology(&self) -> Option<&TiledTopology> {
+        self.blocks.iter().find_map(|block| match block {
+            DisplayIdDataBlock::TiledTopology(topology) => Some(topology),
+            _ => None,
+        })
+    }
+}
+
+pub(crate) fn parse_displayid(input: &[u8]) -> IResult<&[u8], DisplayId, VerboseError<&[u8]>> {
+    let (input, block) = take(128u8)(input)?;
+    let (_, displayid) = parse_section(&block[1..127])?;
+    Ok((input, displayid))
+}
@@ -0,0 +1,272 @@
+#[cfg(test)]
+mod tests {
+    use crate::{displayid::*, edid::*, extension::*};
+
+    fn checksum(bytes: &[u8]) -> u8 {
+        bytes.iter().fold(0u8, |sum, b| sum.wrapping_sub(*b))
+    }
+
+    /// Appends a DisplayID 1.2 extension holding `blocks` to the VGA EDID.
+    fn with_displayid(blocks: &[u8]) -> Vec<u8> {
+        append_displayid(
+            include_bytes!("../testdata/card0-VGA-1.bin").to_vec(),
+            blocks,
+        )
+    }
+
+    fn append_displayid(mut data: Vec<u8>, blocks: &[u8]) -> Vec<u8> {
+        let mut ext = vec![Extension::DISPLAY_ID, 0x12, blocks.len() as u8, 0x00, 0x00];
+        ext.extend_from_slice(blocks);
+        ext.push(checksum(&ext[1..]));
+        ext.resize(127, 0);
+        ext.push(checksum(&ext));
+
+        data[126] = 1;
+        data[127] = checksum(&data[..127]);
+        data.extend_from_slice(&ext);
+        data
+    }
+
+    #[test]
+    fn test_displayid_section() {
+        let data = with_displayid(&[0x7f, 0x01, 0x02, 0xaa, 0xbb]);
+        let (remaining, parsed) = parse(&data).unwrap();
+        assert_eq!(remaining.len(), 0);
+
+        assert_eq!(
+            parsed.extensions,
+            vec![Extension::DisplayId(DisplayId {
+                version: 1,
+                revision: 2,
+                product_type: 0,
+                extension_count: 0,
+                blocks: vec![DisplayIdDataBlock::Reserved(DisplayIdBlockReserved {
+                    header: DisplayIdBlockHeader {
+                        tag: 0x7f,
+                        revision: 1,
+                        len: 2,
+                    },
+                    payload: vec![0xaa, 0xbb],
+                })],
+                checksum: 0x02,
+                checksum_valid: true,
+            })]
+        );
+    }
+
+    #[test]
+    fn test_displayid_bad_checksum() {
+        let mut data = with_displayid(&[]);
+        data[133] ^= 0xff;
+        let (_, parsed) = parse(&data).unwrap();
+
+        match &parsed.extensions[0] {
+            Extension::DisplayId(displayid) => assert!(!displayid.checksum_valid),
+            other => panic!("unexpected extension {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_displayid_malformed_section() {
+        // The section length runs past the block
+        let mut data = with_displayid(&[]);
+        data[130] = 200;
+        let (_, parsed) = parse(&data).unwrap();
+
+        assert_eq!(
+            parsed.extensions,
+            vec![Extension::Unknown {
+                tag: Extension::DISPLAY_ID,
+                payload: data[129..255].to_vec(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_type_i_and_vii_timings() {
+        // 2560x1440@60 CVT-RB, positive hsync, preferred, 16:9
+        let descriptor = [
+            0x55, 0x5e, 0x00, 0x84, 0xff, 0x09, 0x9f, 0x00, 0x2f, 0x80, 0x1f, 0x00, 0x9f, 0x05,
+            0x28, 0x00, 0x02, 0x00, 0x04, 0x00,
+        ];
+        let mut blocks = vec![DisplayIdDataBlock::TYPE_I_TIMING, 0x00, 20];
+        blocks.extend_from_slice(&descriptor);
+        blocks.extend_from_slice(&[DisplayIdDataBlock::TYPE_VII_TIMING, 0x00, 20]);
+        blocks.extend_from_slice(&descriptor);
+
+        let (_, parsed) = parse(&with_displayid(&blocks)).unwrap();
+        let displayid = match &parsed.extensions[0] {
+            Extension::DisplayId(displayid) => displayid,
+            other => panic!("unexpected extension {:?}", other),
+        };
+
+        let timings: Vec<_> = displayid.timings().collect();
+        assert_eq!(timings.len(), 2);
+        assert!(timings[0].preferred);
+        assert_eq!(timings[0].aspect_ratio, 4);
+        assert_eq!(timings[0].stereo, 0);
+        assert_eq!(
+            timings[0].timing,
+            DetailedTiming {
+                pixel_clock: 241_500,
+                horizontal_active_pixels: 2560,
+                horizontal_blanking_pixels: 160,
+                vertical_active_lines: 1440,
+                vertical_blanking_lines: 41,
+                horizontal_front_porch: 48,
+                horizontal_sync_width: 32,
+                vertical_front_porch: 3,
+                vertical_sync_width: 5,
+                features: 0x1a,
+                ..Default::default()
+            }
+        );
+        // Type VII counts the pixel clock in 1 kHz units
+        assert_eq!(timings[1].timing.pixel_clock, 24_150);
+        assert_eq!(timings[1].timing.horizontal_active_pixels, 2560);
+    }
+
+    #[test]
+    fn test_tiled_topology() {
+        // Right half of a 5K display built from two 2560x2880 tiles
+        let payload = [
+            0xc9, 0x10, 0x10, 0x00, 0xff, 0x09, 0x3f, 0x0b, 0x0a, 0x01, 0x02, 0x03, 0x04, b'G',
+            b'S', b'M', 0x08, 0x5b, 0x78, 0x56, 0x34, 0x12,
+        ];
+        let mut blocks = vec![DisplayIdDataBlock::TILED_TOPOLOGY, 0x00, 22];
+        blocks.extend_from_slice(&payload);
+
+        let (_, parsed) = parse(&with_displayid(&blocks)).unwrap();
+        let displayid = match &parsed.extensions[0] {
+            Extension::DisplayId(displayid) => displayid,
+            other => panic!("unexpected extension {:?}", other),
+        };
+        assert_eq!(
+            displayid.tiled_topology(),
+            Some(&TiledTopology {
+                single_enclosure: true,
+                multi_tile_behavior: 1,
+                single_tile_behavior: 1,
+                horizontal_tiles: 2,
+                vertical_tiles: 1,
+                horizontal_location: 1,
+                vertical_location: 0,
+                tile_width: 2560,
+                tile_height: 2880,
+                bezel: Some(TileBezel {
+                    pixel_multiplier: 10,
+                    top: 1,
+                    bottom: 2,
+                    right: 3,
+                    left: 4,
+                }),
+                vendor_id: *b"GSM",
+                product_code: 0x5b08,
+                serial_number: 0x12345678,
+            })
+        );
+
+        // The upper two bits of each count and location, DisplayID 2.x tag
+        let mut v2 = payload;
+        v2[0] = 0x00;
+        v2[3] = 0b0110_0110;
+        let mut blocks = vec![DisplayIdDataBlock::TILED_TOPOLOGY_V2, 0x00, 22];
+        blocks.extend_from_slice(&v2);
+        let (_, parsed) = parse(&with_displayid(&blocks)).unwrap();
+        let topology = match &parsed.extensions[0] {
+            Extension::DisplayId(displayid) => displayid.tiled_topology().cloned().unwrap(),
+            other => panic!("unexpected extension {:?}", other),
+        };
+        assert_eq!(topology.bezel, None);
+        assert_eq!(
+            (topology.horizontal_tiles, topology.vertical_tiles),
+            (18, 33)
+        );
+        assert_eq!(
+            (topology.horizontal_location, topology.vertical_location),
+            (17, 32)
+        );
+
+        // Too short to decode, kept as a raw block
+        let (_, parsed) = parse(&with_displayid(&[0x12, 0x00, 0x02, 0xc9, 0x10])).unwrap();
+        match &parsed.extensions[0] {
+            Extension::DisplayId(displayid) => {
+                assert_eq!(displayid.tiled_topology(), None);
+                assert!(matches!(
+                    displayid.blocks[0],
+                    DisplayIdDataBlock::Reserved(_)
+                ));
+            }
+            other => panic!("unexpected extension {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_product_id() {
+        let mut block = vec![DisplayIdDataBlock::PRODUCT_ID, 0x00, 19];
+        block.extend_from_slice(b"DEL");
+        block.extend_from_slice(&[0x34, 0x12, 0x78, 0x56, 0x34, 0x12, 10, 21, 7]);
+        block.extend_from_slice(b"Tile 5K");
+
+        let product = ProductId {
+            vendor_id: *b"DEL",
+            product_code: 0x1234,
+            serial_number: 0x12345678,
+            week: 10,
+            year: 21,
+            product_name: "Tile 5K".to_string(),
+        };
+        let (_, parsed) = parse(&with_displayid(&block)).unwrap();
+        match &parsed.extensions[0] {
+            Extension::DisplayId(displayid) => {
+                assert_eq!(displayid.blocks.len(), 1);
+                assert_eq!(displayid.product_id(), Some(&product));
+            }
+            other => panic!("unexpected extension {:?}", other),
+        }
+        assert_eq!(product.year_ad(), 2021);
+
+        // The base block has a product name and a serial, DisplayID is ignored
+        let info = parsed.product_info();
+        assert_eq!(info.product_name.as_deref(), Some("SyncMaster"));
+        assert_eq!(info.serial, 1146106418);
+
+        // Without them, the DisplayID block fills them in
+        let mut base = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        base[12..16].fill(0);
+        for descriptor in base[54..126].chunks_exact_mut(18) {
+            if descriptor[..4] == [0, 0, 0, 0xfc] {
+                descriptor[3] = 0x10;
+            }
+        }
+        let (_, parsed) = parse(&append_displayid(base, &block)).unwrap();
+        let info = parsed.product_info();
+        assert_eq!(info.product_name.as_deref(), Some("Tile 5K"));
+        assert_eq!(info.serial, 0x12345678);
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_embedded_cta_blocks() {
+        use crate::cta::DataBlock;
+
+        // An audio data block with one 2-channel LPCM descriptor
+        let blocks = [DisplayIdDataBlock::CTA, 0x00, 0x04, 0x23, 0x09, 0x07, 0x07];
+        let (_, parsed) = parse(&with_displayid(&blocks)).unwrap();
+        let displayid = match &parsed.extensions[0] {
+            Extension::DisplayId(displayid) => displayid,
+            other => panic!("unexpected extension {:?}", other),
+        };
+
+        let embedded: Vec<_> = displayid.cta_data_blocks().collect();
+        assert_eq!(embedded.len(), 1);
+        match embedded[0] {
+            DataBlock::AudioBlock(audio) => {
+                assert_eq!(audio.descriptors.len(), 1);
+                assert_eq!(audio.descriptors[0].number_of_channels, 2);
+            }
+            other => panic!("unexpected data block {:?}", other),
+        }
+        assert_eq!(parsed.cta_data_blocks().collect::<Vec<_>>(), embedded);
+    }
+}
@@ -0,0 +1,98 @@
+use crate::edid::DetailedTiming;
+
+/// Pixel clock in kHz, active pixels, blanking, front porch and sync width
+/// horizontally then vertically, and the DTD features byte.
+pub(crate) type Mode = (u32, [u16; 8], u8);
+
+// Separate sync, negative or positive polarities
+pub(crate) const NN: u8 = 0x18;
+pub(crate) const NP: u8 = 0x1c;
+pub(crate) const PN: u8 = 0x1a;
+pub(crate) const PP: u8 = 0x1e;
+pub(crate) const INTERLACED: u8 = 0x80;
+
+/// The progressive VESA DMT modes a standard timing can describe, by
+/// nominal refresh rate. Where DMT defines both, the 60 Hz mode is the one
+/// with normal blanking.
+const DMT: [(u8, Mode); 51] = [
+    (60, (25175, [640, 160, 16, 96, 480, 45, 10, 2], NN)),
+    (72, (31500, [640, 192, 24, 40, 480, 40, 9, 3], NN)),
+    (75, (31500, [640, 200, 16, 64, 480, 20, 1, 3], NN)),
+    (85, (36000, [640, 192, 56, 56, 480, 29, 1, 3], NN)),
+    (56, (36000, [800, 224, 24, 72, 600, 25, 1, 2], PP)),
+    (60, (40000, [800, 256, 40, 128, 600, 28, 1, 4], PP)),
+    (72, (50000, [800, 240, 56, 120, 600, 66, 37, 6], PP)),
+    (75, (49500, [800, 256, 16, 80, 600, 25, 1, 3], PP)),
+    (85, (56250, [800, 248, 32, 64, 600, 31, 1, 3], PP)),
+    (120, (73250, [800, 160, 48, 32, 600, 36, 3, 4], PN)),
+    (60, (65000, [1024, 320, 24, 136, 768, 38, 3, 6], NN)),
+    (70, (75000, [1024, 304, 24, 136, 768, 38, 3, 6], NN)),
+    (75, (78750, [1024, 288, 16, 96, 768, 32, 1, 3], PP)),
+    (85, (94500, [1024, 352, 48, 96, 768, 40, 1, 3], PP)),
+    (120, (115500, [1024, 160, 48, 32, 768, 45, 3, 4], PN)),
+    (75, (108000, [1152, 448, 64, 128, 864, 36, 1, 3], PP)),
+    (60, (74250, [1280, 370, 110, 40, 720, 30, 5, 5], PP)),
+    (60, (83500, [1280, 400, 72, 128, 800, 31, 3, 6], NP)),
+    (75, (106500, [1280, 416, 80, 128, 800, 38, 3, 6], NP)),
+    (85, (122500, [1280, 432, 80, 136, 800, 43, 3, 6], NP)),
+    (60, (108000, [1280, 520, 96, 112, 960, 40, 1, 3], PP)),
+    (85, (148500, [1280, 448, 64, 160, 960, 51, 1, 3], PP)),
+    (60, (108000, [1280, 408, 48, 112, 1024, 42, 1, 3], PP)),
+    (75, (135000, [1280, 408, 16, 144, 1024, 42, 1, 3], PP)),
+    (85, (157500, [1280, 448, 64, 160, 1024, 48, 1, 3], PP)),
+    (60, (121750, [1400, 464, 88, 144, 1050, 39, 3, 4], NP)),
+    (75, (156000, [1400, 496, 104, 144, 1050, 49, 3, 4], NP)),
+    (85, (179500, [1400, 512, 104, 152, 1050, 55, 3, 4], NP)),
+    (60, (106500, [1440, 464, 80, 152, 900, 34, 3, 6], NP)),
+    (75, (136750, [1440, 496, 96, 152, 900, 42, 3, 6], NP)),
+    (85, (157000, [1440, 512, 104, 152, 900, 48, 3, 6], NP)),
+    (60, (108000, [1600, 200, 24, 80, 900, 100, 1, 3], PP)),
+    (60, (162000, [1600, 560, 64, 192, 1200, 50, 1, 3], PP)),
+    (65, (175500, [1600, 560, 64, 192, 1200, 50, 1, 3], PP)),
+    (70, (189000, [1600, 560, 64, 192, 1200, 50, 1, 3], PP)),
+    (75, (202500, [1600, 560, 64, 192, 1200, 50, 1, 3], PP)),
+    (85, (229500, [1600, 560, 64, 192, 1200, 50, 1, 3], PP)),
+    (60, (146250, [1680, 560, 104, 176, 1050, 39, 3, 6], NP)),
+    (75, (187000, [1680, 592, 120, 176, 1050, 49, 3, 6], NP)),
+    (85, (214750, [1680, 608, 128, 176, 1050, 55, 3, 6], NP)),
+    (60, (204750, [1792, 656, 128, 200, 1344, 50, 1, 3], NP)),
+    (75, (261000, [1792, 664, 96, 216, 1344, 73, 1, 3], NP)),
+    (60, (218250, [1856, 672, 96, 224, 1392, 47, 1, 3], NP)),
+    (75, (288000, [1856, 704, 128, 224, 1392, 108, 1, 3], NP)),
+    (60, (148500, [1920, 280, 88, 44, 1080, 45, 4, 5], PP)),
+    (60, (193250, [1920, 672, 136, 200, 1200, 45, 3, 6], NP)),
+    (75, (245250, [1920, 688, 136, 208, 1200, 55, 3, 6], NP)),
+    (85, (281250, [1920, 704, 144, 208, 1200, 62, 3, 6], NP)),
+    (60, (234000, [1920, 680, 128, 208, 1440, 60, 1, 3], NP)),
+    (75, (297000, [1920, 720, 144, 224, 1440, 60, 1, 3], NP)),
+    (60, (162000, [2048, 202, 26, 80, 1152, 48, 1, 3], PP)),
+];
+
+/// The DMT timing of a mode, if DMT defines it.
+pub(crate) fn dmt_timing(width: u16, height: u16, refresh: u8) -> Option<DetailedTiming> {
+    DMT.iter()
+        .find(|(rate, (_, params, _))| {
+            *rate == refresh && params[0] == width && params[4] == height
+        })
+        .map(|(_, mode)| timing(mode))
+}
+
+pub(crate) fn timing(&(pixel_clock, params, features): &Mode) -> DetailedTiming {
+    let [h_active, h_blanking, h_front, h_sync, v_active, v_blanking, v_front, v_sync] = params;
+    DetailedTiming {
+        pixel_clock,
+        horizontal_active_pixels: h_active,
+        horizontal_blanking_pixels: h_blanking,
+        vertical_active_lines: v_active,
+        vertical_blanking_lines: v_blanking,
+        horizontal_front_porch: h_front,
+        horizontal_sync_width: h_sync,
+        vertical_front_porch: v_front,
+        vertical_sync_width: v_sync,
+        horizontal_size: 0,
+        vertical_size: 0,
+        horizontal_border_pixels: 0,
+        vertical_border_pixels: 0,
+        features,
+    }
+}
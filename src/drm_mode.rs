@@ -0,0 +1,121 @@
+//! Conversions to and from the `drm` crate's `Mode`, behind the `drm`
+//! feature.
+
+use drm::control::{Mode, ModeFlags, ModeTypeFlags};
+use drm_ffi::drm_mode_modeinfo;
+
+use crate::edid::DetailedTiming;
+
+// DTD sync bits of the features byte
+const SEPARATE_SYNC: u8 = 0x18;
+const COMPOSITE_SYNC: u8 = 0x10;
+const HSYNC_POSITIVE: u8 = 0x02;
+const VSYNC_POSITIVE: u8 = 0x04;
+const INTERLACED: u8 = 0x80;
+
+/// A driver mode named after its size, e.g. `1920x1080i`, as the kernel
+/// names the modes it reads from an EDID. Borders and the image size are
+/// dropped.
+impl From<&DetailedTiming> for Mode {
+    fn from(timing: &DetailedTiming) -> Mode {
+        let (h, v) = timing.sync_positions();
+        let mut flags = ModeFlags::empty();
+        match timing.features & SEPARATE_SYNC {
+            SEPARATE_SYNC => {
+                flags |= match timing.features & HSYNC_POSITIVE {
+                    0 => ModeFlags::NHSYNC,
+                    _ => ModeFlags::PHSYNC,
+                };
+                flags |= match timing.features & VSYNC_POSITIVE {
+                    0 => ModeFlags::NVSYNC,
+                    _ => ModeFlags::PVSYNC,
+                };
+            }
+            COMPOSITE_SYNC => {
+                flags |= ModeFlags::CSYNC;
+                flags |= match timing.features & HSYNC_POSITIVE {
+                    0 => ModeFlags::NCSYNC,
+                    _ => ModeFlags::PCSYNC,
+                };
+            }
+            _ => flags |= ModeFlags::CSYNC,
+        }
+        if timing.is_interlaced() {
+            flags |= ModeFlags::INTERLACE;
+        }
+
+        let mut name = [0; 32];
+        let interlace = if timing.is_interlaced() { "i" } else { "" };
+        let label = format!("{}x{}{}", h[0], v[0], interlace);
+        for (c, b) in name.iter_mut().zip(label.bytes().take(31)) {
+            *c = b as _;
+        }
+
+        drm_mode_modeinfo {
+            clock: timing.pixel_clock,
+            hdisplay: h[0] as u16,
+            hsync_start: h[1] as u16,
+            hsync_end: h[2] as u16,
+            htotal: h[3] as u16,
+            vdisplay: v[0] as u16,
+            vsync_start: v[1] as u16,
+            vsync_end: v[2] as u16,
+            vtotal: v[3] as u16,
+            vrefresh: timing.field_rate().round() as u32,
+            flags: flags.bits(),
+            type_: ModeTypeFlags::DRIVER.bits(),
+            name,
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// A DTD with digital sync, or composite sync if the mode has `CSYNC`.
+/// Fields out of range of a DTD are truncated when encoding it.
+impl From<&Mode> for DetailedTiming {
+    fn from(mode: &Mode) -> DetailedTiming {
+        let (h_active, v_active) = mode.size();
+        let (h_sync_start, h_sync_end, h_total) = mode.hsync();
+        let (v_sync_start, v_sync_end, v_total) = mode.vsync();
+        let flags = mode.flags();
+
+        let mut features = if flags.contains(ModeFlags::CSYNC) {
+            let mut features = COMPOSITE_SYNC;
+            if flags.contains(ModeFlags::PCSYNC) {
+                features |= HSYNC_POSITIVE;
+            }
+            features
+        } else {
+            let mut features = SEPARATE_SYNC;
+            if flags.contains(ModeFlags::PHSYNC) {
+                features |= HSYNC_POSITIVE;
+            }
+            if flags.contains(ModeFlags::PVSYNC) {
+                features |= VSYNC_POSITIVE;
+            }
+            features
+        };
+        // Vertical timings of a DTD are per field
+        let fields = if flags.contains(ModeFlags::INTERLACE) {
+            features |= INTERLACED;
+            2
+        } else {
+            1
+        };
+
+        DetailedTiming {
+            pixel_clock: mode.clock(),
+            horizontal_active_pixels: h_active,
+            horizontal_blanking_pixels: h_total.saturating_sub(h_active),
+            horizontal_front_porch: h_sync_start.saturating_sub(h_active),
+            horizontal_sync_width: h_sync_end.saturating_sub(h_sync_start),
+            vertical_active_lines: v_active / fields,
+            vertical_blanking_lines: (v_total / fields).saturating_sub(v_active / fields),
+            vertical_front_porch: v_sync_start.saturating_sub(v_active) / fields,
+            vertical_sync_width: v_sync_end.saturating_sub(v_sync_start) / fields,
+            features,
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use drm::control::{Mode, ModeFlags};
+
+    use crate::{edid::*, mode::TimingSource};
+
+    #[test]
+    fn test_drm_mode() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        let cta_timing = |width, interlaced| {
+            edid.timings()
+                .find(|(source, timing)| {
+                    *source == TimingSource::CtaDescriptor
+                        && timing.horizontal_active_pixels == width
+                        && timing.is_interlaced() == interlaced
+                })
+                .map(|(_, timing)| timing)
+                .unwrap()
+        };
+        let preferred = *edid.preferred_timing().unwrap();
+        let interlaced_timing = cta_timing(1920, true);
+        let ntsc = cta_timing(720, false);
+
+        let mode = Mode::from(&preferred);
+        assert_eq!(mode.name().to_str(), Ok("1920x1080"));
+        assert_eq!(mode.clock(), 148500);
+        assert_eq!(mode.size(), (1920, 1080));
+        assert_eq!(mode.hsync(), (2008, 2052, 2200));
+        assert_eq!(mode.vsync(), (1084, 1089, 1125));
+        assert_eq!(mode.vrefresh(), 60);
+        assert_eq!(mode.flags(), ModeFlags::PHSYNC | ModeFlags::PVSYNC);
+
+        let interlaced = Mode::from(&interlaced_timing);
+        assert_eq!(interlaced.name().to_str(), Ok("1920x1080i"));
+        assert_eq!(interlaced.vsync(), (1084, 1094, 1125));
+        assert_eq!(interlaced.vrefresh(), 60);
+        assert!(interlaced.flags().contains(ModeFlags::INTERLACE));
+
+        // Back to the DTD, less the image size
+        for timing in [preferred, interlaced_timing, ntsc] {
+            assert_eq!(
+                DetailedTiming::from(&Mode::from(&timing)),
+                DetailedTiming {
+                    horizontal_size: 0,
+                    vertical_size: 0,
+                    ..timing
+                }
+            );
+        }
+    }
+}
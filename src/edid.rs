@@ -5,13 +5,26 @@ use nom::{
     multi::count,
     number::complete::{be_u16, le_u16, le_u32, le_u8},
     sequence::{terminated, tuple},
-    IResult,
+    IResult, Offset,
 };
-use std::convert::TryInto;
+use std::{borrow::Cow, convert::TryInto};
 
-use crate::{cp437, extension::{parse_extension, CtaExtensions}};
+use crate::{
+    checksum::{checksum_mismatches, ChecksumMismatch},
+    cp437,
+    error::EdidError,
+    established::EstablishedTimings,
+    extension::{parse_extension_block, Extension},
+};
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+/// The first 8 bytes of every EDID.
+pub(crate) const HEADER_MAGIC: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+/// Size of the base block and of each extension block.
+pub(crate) const BLOCK_SIZE: usize = 128;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Header {
     pub vendor: [char; 3],
     pub product: u16,
@@ -22,21 +35,78 @@ pub struct Header {
     pub revision: u8,
 }
 
+/// The year AD of a year byte, counted from 1990.
+pub(crate) fn year_ad(year: u8) -> u16 {
+    1990 + year as u16
+}
+
 fn parse_vendor(v: u16) -> [char; 3] {
     let mask: u8 = 0x1F; // Each letter is 5 bits
-    let i0 = ('A' as u8) - 1; // 0x01 = A
-    return [
+    let i0 = b'A' - 1; // 0x01 = A
+    [
         (((v >> 10) as u8 & mask) + i0) as char,
         (((v >> 5) as u8 & mask) + i0) as char,
-        (((v >> 0) as u8 & mask) + i0) as char,
-    ];
+        ((v as u8 & mask) + i0) as char,
+    ]
+}
+
+// Vendor letters are 5-bit codes, only 'A' to 'Z' encode
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Header {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut vendor = ['A'; 3];
+        for letter in &mut vendor {
+            *letter = (b'A' + u.int_in_range(0..=25)?) as char;
+        }
+        Ok(Header {
+            vendor,
+            product: u.arbitrary()?,
+            serial: u.arbitrary()?,
+            week: u.arbitrary()?,
+            year: u.arbitrary()?,
+            version: u.arbitrary()?,
+            revision: u.arbitrary()?,
+        })
+    }
+}
+
+impl Header {
+    /// The year of manufacture, or the model year when `week` is 0xFF.
+    pub fn year_ad(&self) -> u16 {
+        year_ad(self.year)
+    }
+
+    /// The PNP ID as a string, e.g. "DEL".
+    pub fn vendor_string(&self) -> String {
+        self.vendor.iter().collect()
+    }
+
+    /// The PNP ID as packed in the EDID: three 5-bit letters, big-endian.
+    pub fn vendor_raw(&self) -> u16 {
+        self.vendor.iter().fold(0u16, |v, c| {
+            (v << 5) | ((*c as u16).wrapping_sub(b'@' as u16) & 0x1F)
+        })
+    }
+
+    pub fn vendor_from_raw(raw: u16) -> [char; 3] {
+        parse_vendor(raw)
+    }
+
+    /// Returns `None` unless `s` is three uppercase ASCII letters.
+    pub fn vendor_from_str(s: &str) -> Option<[char; 3]> {
+        let chars: Vec<char> = s.chars().collect();
+        match chars[..] {
+            [a, b, c] if chars.iter().all(char::is_ascii_uppercase) => Some([a, b, c]),
+            _ => None,
+        }
+    }
 }
 
-fn parse_header(input: &[u8]) -> IResult<&[u8], Header, VerboseError<&[u8]>> {
+pub(crate) fn parse_header(input: &[u8]) -> IResult<&[u8], Header, VerboseError<&[u8]>> {
     terminated(
         map(
             tuple((
-                tag(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00][..]),
+                tag(&HEADER_MAGIC[..]),
                 map(be_u16, parse_vendor),
                 le_u16,
                 le_u32,
@@ -60,6 +130,9 @@ fn parse_header(input: &[u8]) -> IResult<&[u8], Header, VerboseError<&[u8]>> {
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Display {
     pub video_input: u8,
     pub width: u8,  // cm
@@ -68,7 +141,112 @@ pub struct Display {
     pub features: u8,
 }
 
-fn parse_display(input: &[u8]) -> IResult<&[u8], Display, VerboseError<&[u8]>> {
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DigitalInterface {
+    Undefined,
+    Dvi,
+    HdmiA,
+    HdmiB,
+    Mddi,
+    DisplayPort,
+    Reserved(u8),
+}
+
+/// Decoded video input definition byte.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VideoInput {
+    Analog {
+        /// Signal level standard, bits 6:5.
+        signal_level: u8,
+        blank_to_black_setup: bool,
+        separate_sync: bool,
+        composite_sync: bool,
+        sync_on_green: bool,
+        vsync_serrated: bool,
+    },
+    /// Bit depth and interface are only defined since EDID 1.4.
+    Digital {
+        color_bit_depth: Option<u8>,
+        interface: DigitalInterface,
+    },
+}
+
+/// Decoded feature support byte.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FeatureSupport {
+    pub dpms_standby: bool,
+    pub dpms_suspend: bool,
+    pub dpms_active_off: bool,
+    /// Bits 4:3: color type for analog displays, supported color encodings
+    /// for digital displays (EDID 1.4).
+    pub display_type: u8,
+    pub srgb_default: bool,
+    pub preferred_timing_native: bool,
+    /// Default GTF supported before EDID 1.4.
+    pub continuous_frequency: bool,
+}
+
+impl Display {
+    pub fn is_digital(&self) -> bool {
+        self.video_input & 0x80 != 0
+    }
+
+    pub fn decoded_video_input(&self) -> VideoInput {
+        let v = self.video_input;
+        if !self.is_digital() {
+            return VideoInput::Analog {
+                signal_level: (v >> 5) & 0x3,
+                blank_to_black_setup: v & 0x10 != 0,
+                separate_sync: v & 0x08 != 0,
+                composite_sync: v & 0x04 != 0,
+                sync_on_green: v & 0x02 != 0,
+                vsync_serrated: v & 0x01 != 0,
+            };
+        }
+
+        let color_bit_depth = match (v >> 4) & 0x7 {
+            0 | 7 => None,
+            depth => Some(depth * 2 + 4),
+        };
+        let interface = match v & 0xf {
+            0 => DigitalInterface::Undefined,
+            1 => DigitalInterface::Dvi,
+            2 => DigitalInterface::HdmiA,
+            3 => DigitalInterface::HdmiB,
+            4 => DigitalInterface::Mddi,
+            5 => DigitalInterface::DisplayPort,
+            other => DigitalInterface::Reserved(other),
+        };
+        VideoInput::Digital {
+            color_bit_depth,
+            interface,
+        }
+    }
+
+    pub fn decoded_features(&self) -> FeatureSupport {
+        let f = self.features;
+        FeatureSupport {
+            dpms_standby: f & 0x80 != 0,
+            dpms_suspend: f & 0x40 != 0,
+            dpms_active_off: f & 0x20 != 0,
+            display_type: (f >> 3) & 0x3,
+            srgb_default: f & 0x04 != 0,
+            preferred_timing_native: f & 0x02 != 0,
+            continuous_frequency: f & 0x01 != 0,
+        }
+    }
+}
+
+pub(crate) fn parse_display(input: &[u8]) -> IResult<&[u8], Display, VerboseError<&[u8]>> {
     map(
         tuple((le_u8, le_u8, le_u8, le_u8, le_u8)),
         |(video_input, width, height, gamma, features)| Display {
@@ -85,26 +263,126 @@ fn parse_chromaticity(input: &[u8]) -> IResult<&[u8], (), VerboseError<&[u8]>> {
     map(take(10u8), |_bytes| ())(input)
 }
 
-fn parse_established_timing(input: &[u8]) -> IResult<&[u8], (), VerboseError<&[u8]>> {
-    map(take(3u8), |_bytes| ())(input)
+fn parse_established_timing(
+    input: &[u8],
+) -> IResult<&[u8], EstablishedTimings, VerboseError<&[u8]>> {
+    map(tuple((le_u8, le_u8, le_u8)), |(a, b, c)| {
+        EstablishedTimings([a, b, c])
+    })(input)
 }
 
-fn parse_standard_timing(input: &[u8]) -> IResult<&[u8], (), VerboseError<&[u8]>> {
-    map(take(16u8), |_bytes| ())(input)
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AspectRatio {
+    R1_1,
+    R16_10,
+    R4_3,
+    R5_4,
+    R16_9,
+    R15_9,
 }
 
-fn parse_descriptor_text(input: &[u8]) -> IResult<&[u8], String, VerboseError<&[u8]>> {
-    map(take(13u8), |b: &[u8]| {
+impl AspectRatio {
+    /// Decodes the aspect ratio bits of a standard timing. Their meaning
+    /// depends on the EDID version.
+    pub fn from_standard_timing_bits(bits: u8, version: u8, revision: u8) -> AspectRatio {
+        match bits & 0x3 {
+            0b00 if (version, revision) < (1, 3) => AspectRatio::R1_1,
+            0b00 => AspectRatio::R16_10,
+            0b01 => AspectRatio::R4_3,
+            0b10 => AspectRatio::R5_4,
+            _ => AspectRatio::R16_9,
+        }
+    }
+
+    /// Returns the (horizontal, vertical) ratio.
+    pub fn ratio(&self) -> (u16, u16) {
+        match self {
+            AspectRatio::R1_1 => (1, 1),
+            AspectRatio::R16_10 => (16, 10),
+            AspectRatio::R4_3 => (4, 3),
+            AspectRatio::R5_4 => (5, 4),
+            AspectRatio::R16_9 => (16, 9),
+            AspectRatio::R15_9 => (15, 9),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StandardTiming {
+    pub horizontal_active_pixels: u16,
+    pub aspect_ratio: AspectRatio,
+    /// Vertical rate in Hz.
+    pub refresh_rate: u8,
+}
+
+impl StandardTiming {
+    pub fn vertical_active_lines(&self) -> u16 {
+        let (h, v) = self.aspect_ratio.ratio();
+        (self.horizontal_active_pixels as u32 * v as u32 / h as u32) as u16
+    }
+}
+
+pub(crate) fn parse_standard_timing_entry(
+    input: &[u8],
+    version: u8,
+    revision: u8,
+) -> IResult<&[u8], Option<StandardTiming>, VerboseError<&[u8]>> {
+    map(tuple((le_u8, le_u8)), |(h, flags)| match (h, flags) {
+        // Unused entries are filled with 0x01 0x01
+        (0x01, 0x01) | (0x00, 0x00) => None,
+        _ => Some(StandardTiming {
+            horizontal_active_pixels: (h as u16 + 31) * 8,
+            aspect_ratio: AspectRatio::from_standard_timing_bits(flags >> 6, version, revision),
+            refresh_rate: (flags & 0x3f) + 60,
+        }),
+    })(input)
+}
+
+fn parse_standard_timing<'a>(
+    input: &'a [u8],
+    header: &Header,
+) -> IResult<&'a [u8], Vec<StandardTiming>, VerboseError<&'a [u8]>> {
+    map(
+        count(
+            |i| parse_standard_timing_entry(i, header.version, header.revision),
+            8,
+        ),
+        |timings| timings.into_iter().flatten().collect(),
+    )(input)
+}
+
+/// Decodes the text of a descriptor, borrowing it when it's printable ASCII.
+pub(crate) fn descriptor_text(b: &[u8]) -> Cow<'_, str> {
+    if b.iter().all(|c| (0x20..0x7F).contains(c) || *c == 0x0A) {
+        let s = std::str::from_utf8(b).unwrap().trim();
+        if !s.contains('\n') {
+            return Cow::Borrowed(s);
+        }
+    }
+    Cow::Owned(
         b.iter()
             .filter(|c| **c != 0x0A)
             .map(|b| cp437::forward(*b))
             .collect::<String>()
             .trim()
-            .to_string()
-    })(input)
+            .to_string(),
+    )
+}
+
+fn parse_descriptor_text(input: &[u8]) -> IResult<&[u8], String, VerboseError<&[u8]>> {
+    map(take(13u8), |b: &[u8]| descriptor_text(b).into_owned())(input)
 }
 
 #[derive(Debug, PartialEq, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct DetailedTiming {
     /// Pixel clock in kHz.
     pub pixel_clock: u32,
@@ -127,7 +405,9 @@ pub struct DetailedTiming {
     pub features: u8, /* TODO add enums etc. */
 }
 
-pub(crate) fn parse_detailed_timing(input: &[u8]) -> IResult<&[u8], DetailedTiming, VerboseError<&[u8]>> {
+pub(crate) fn parse_detailed_timing(
+    input: &[u8],
+) -> IResult<&[u8], DetailedTiming, VerboseError<&[u8]>> {
     map(
         tuple((
             le_u16, // pixel_clock_10khz
@@ -181,9 +461,9 @@ pub(crate) fn parse_detailed_timing(input: &[u8]) -> IResult<&[u8], DetailedTimi
             horizontal_sync_width: (horizontal_sync_width_lo as u16)
                 | ((((porch_sync_hi >> 4) & 0x3) as u16) << 8),
             vertical_front_porch: ((vertical_lo >> 4) as u16)
-                | ((((porch_sync_hi >> 2) & 0x3) as u16) << 8),
+                | ((((porch_sync_hi >> 2) & 0x3) as u16) << 4),
             vertical_sync_width: ((vertical_lo & 0xf) as u16)
-                | (((porch_sync_hi & 0x3) as u16) << 8),
+                | (((porch_sync_hi & 0x3) as u16) << 4),
             horizontal_size: (horizontal_size_lo as u16) | (((size_hi >> 4) as u16) << 8),
             vertical_size: (vertical_size_lo as u16) | (((size_hi & 0xf) as u16) << 8),
             horizontal_border_pixels: horizontal_border,
@@ -193,12 +473,87 @@ pub(crate) fn parse_detailed_timing(input: &[u8]) -> IResult<&[u8], DetailedTimi
     )(input)
 }
 
+impl DetailedTiming {
+    /// Encodes the 18-byte descriptor. Fields are truncated to their width
+    /// in the descriptor, e.g. 12 bits for the active pixels.
+    pub fn to_bytes(&self) -> [u8; 18] {
+        let clock = ((self.pixel_clock / 10) as u16).to_le_bytes();
+        let hi = |value: u16, shift: u16| ((value >> shift) & 0xf) as u8;
+        [
+            clock[0],
+            clock[1],
+            self.horizontal_active_pixels as u8,
+            self.horizontal_blanking_pixels as u8,
+            hi(self.horizontal_active_pixels, 8) << 4 | hi(self.horizontal_blanking_pixels, 8),
+            self.vertical_active_lines as u8,
+            self.vertical_blanking_lines as u8,
+            hi(self.vertical_active_lines, 8) << 4 | hi(self.vertical_blanking_lines, 8),
+            self.horizontal_front_porch as u8,
+            self.horizontal_sync_width as u8,
+            (self.vertical_front_porch as u8 & 0xf) << 4 | (self.vertical_sync_width as u8 & 0xf),
+            (hi(self.horizontal_front_porch, 8) & 0x3) << 6
+                | (hi(self.horizontal_sync_width, 8) & 0x3) << 4
+                | (hi(self.vertical_front_porch, 4) & 0x3) << 2
+                | (hi(self.vertical_sync_width, 4) & 0x3),
+            self.horizontal_size as u8,
+            self.vertical_size as u8,
+            hi(self.horizontal_size, 8) << 4 | hi(self.vertical_size, 8),
+            self.horizontal_border_pixels,
+            self.vertical_border_pixels,
+            self.features,
+        ]
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RangeLimits {
+    /// Vertical rate in Hz.
+    pub min_vertical_rate: u16,
+    pub max_vertical_rate: u16,
+    /// Horizontal rate in kHz.
+    pub min_horizontal_rate: u16,
+    pub max_horizontal_rate: u16,
+    /// Maximum pixel clock in kHz, rounded up to a multiple of 10 MHz.
+    pub max_pixel_clock: u32,
+    pub video_timing_support: u8,
+    /// Timing formula parameters, depending on `video_timing_support`.
+    pub video_timing_data: [u8; 7],
+}
+
+impl RangeLimits {
+    // video timing support flags
+    pub const DEFAULT_GTF: u8 = 0x00;
+    pub const RANGE_LIMITS_ONLY: u8 = 0x01;
+    pub const SECONDARY_GTF: u8 = 0x02;
+    pub const CVT: u8 = 0x04;
+}
+
+pub(crate) fn parse_range_limits(offsets: u8, data: &[u8]) -> RangeLimits {
+    // EDID 1.4 rate offsets: add 255 to the min/max value when the bit is set
+    let offset = |v: u8, set: bool| v as u16 + if set { 255 } else { 0 };
+    RangeLimits {
+        min_vertical_rate: offset(data[0], offsets & 0x03 == 0x03),
+        max_vertical_rate: offset(data[1], offsets & 0x02 != 0),
+        min_horizontal_rate: offset(data[2], offsets & 0x0c == 0x0c),
+        max_horizontal_rate: offset(data[3], offsets & 0x08 != 0),
+        max_pixel_clock: data[4] as u32 * 10_000,
+        video_timing_support: data[5],
+        video_timing_data: data[6..13].try_into().unwrap(),
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Descriptor {
     DetailedTiming(DetailedTiming),
     SerialNumber(String),
     UnspecifiedText(String),
-    RangeLimits,
+    RangeLimits(RangeLimits),
     ProductName(String),
     WhitePoint,
     StandardTiming,
@@ -209,19 +564,21 @@ pub enum Descriptor {
     Unknown([u8; 13]),
 }
 
-fn parse_descriptor(input: &[u8]) -> IResult<&[u8], Descriptor, VerboseError<&[u8]>> {
+pub(crate) fn parse_descriptor(input: &[u8]) -> IResult<&[u8], Descriptor, VerboseError<&[u8]>> {
     let (remaining, peeked) = peek(le_u16)(input)?;
     match peeked {
         0 => {
             let (remaining, _) = take(3u8)(remaining)?;
             let (remaining, discriminant) = le_u8(remaining)?;
-            let (remaining, _) = le_u8(remaining)?;
+            let (remaining, flags) = le_u8(remaining)?;
 
             match discriminant {
-                0xFF => map(parse_descriptor_text, |s| Descriptor::SerialNumber(s))(remaining),
-                0xFE => map(parse_descriptor_text, |s| Descriptor::UnspecifiedText(s))(remaining),
-                0xFD => map(take(13u8), |_discarded: &[u8]| Descriptor::RangeLimits)(remaining),
-                0xFC => map(parse_descriptor_text, |s| Descriptor::ProductName(s))(remaining),
+                0xFF => map(parse_descriptor_text, Descriptor::SerialNumber)(remaining),
+                0xFE => map(parse_descriptor_text, Descriptor::UnspecifiedText)(remaining),
+                0xFD => map(take(13u8), |data: &[u8]| {
+                    Descriptor::RangeLimits(parse_range_limits(flags, data))
+                })(remaining),
+                0xFC => map(parse_descriptor_text, Descriptor::ProductName)(remaining),
                 0xFB => map(take(13u8), |_discarded: &[u8]| Descriptor::WhitePoint)(remaining),
                 0xFA => map(take(13u8), |_discarded: &[u8]| Descriptor::StandardTiming)(remaining),
                 0xF9 => map(take(13u8), |_discarded: &[u8]| Descriptor::ColorManagement)(remaining),
@@ -243,55 +600,269 @@ fn parse_descriptor(input: &[u8]) -> IResult<&[u8], Descriptor, VerboseError<&[u
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EDID {
     pub header: Header,
     pub display: Display,
-    pub chromaticity: (),       
-    pub established_timing: (), 
-    pub standard_timing: (),    
+    pub chromaticity: (),
+    pub established_timing: EstablishedTimings,
+    pub standard_timing: Vec<StandardTiming>,
     pub descriptors: Vec<Descriptor>,
-    pub extensions: Option<CtaExtensions>,
+    pub extensions: Vec<Extension>,
+    /// The blocks whose checksum is wrong, which `parse` doesn't reject.
+    /// Ignored when encoding, which computes every checksum.
+    pub checksum_mismatches: Vec<ChecksumMismatch>,
+}
+
+/// Identification of a display, merged from the header and the text descriptors.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ProductInfo {
+    pub vendor: [char; 3],
+    pub product: u16,
+    pub serial: u32,
+    /// Contents of the serial number descriptor (0xFF), if any.
+    pub serial_number: Option<String>,
+    /// Contents of the product name descriptor (0xFC), if any.
+    pub product_name: Option<String>,
+    pub week: u8,
+    pub year: u8, // Starting at year 1990
+}
+
+impl ProductInfo {
+    /// See `Header::year_ad`.
+    pub fn year_ad(&self) -> u16 {
+        year_ad(self.year)
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ScreenSize {
+    /// Physical size in cm.
+    Size { width: u8, height: u8 },
+    /// Width divided by height, when only the aspect ratio is known (EDID 1.4).
+    AspectRatio(f32),
+    /// No size information, e.g. projectors.
+    Undefined,
+}
+
+/// Text split across consecutive unspecified text descriptors (0xFE).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct JoinedText {
+    pub text: String,
+    /// Text of each descriptor, in order.
+    pub pieces: Vec<String>,
+}
+
+impl EDID {
+    /// Joins each run of consecutive unspecified text descriptors.
+    pub fn unspecified_texts(&self) -> Vec<JoinedText> {
+        let mut texts: Vec<JoinedText> = Vec::new();
+        let mut previous_was_text = false;
+        for descriptor in &self.descriptors {
+            match descriptor {
+                Descriptor::UnspecifiedText(s) => {
+                    match texts.last_mut() {
+                        Some(joined) if previous_was_text => {
+                            joined.text.push_str(s);
+                            joined.pieces.push(s.clone());
+                        }
+                        _ => texts.push(JoinedText {
+                            text: s.clone(),
+                            pieces: vec![s.clone()],
+                        }),
+                    }
+                    previous_was_text = true;
+                }
+                _ => previous_was_text = false,
+            }
+        }
+        texts
+    }
+
+    pub fn screen_size(&self) -> ScreenSize {
+        let (width, height) = (self.display.width, self.display.height);
+        let v14 = (self.header.version, self.header.revision) >= (1, 4);
+        match (width, height) {
+            (0, 0) => ScreenSize::Undefined,
+            // One zero byte means the other one encodes the aspect ratio
+            (w, 0) if v14 => ScreenSize::AspectRatio((w as f32 + 99.0) / 100.0),
+            (0, h) if v14 => ScreenSize::AspectRatio(100.0 / (h as f32 + 99.0)),
+            (0, _) | (_, 0) => ScreenSize::Undefined,
+            _ => ScreenSize::Size { width, height },
+        }
+    }
+
+    /// The first detailed timing descriptor of the base block.
+    pub fn preferred_timing(&self) -> Option<&DetailedTiming> {
+        self.descriptors.iter().find_map(|d| match d {
+            Descriptor::DetailedTiming(timing) => Some(timing),
+            _ => None,
+        })
+    }
+
+    /// Physical size in millimeters, from the preferred timing if it has
+    /// one, else from the screen size.
+    pub fn physical_size_mm(&self) -> Option<(u16, u16)> {
+        if let Some(timing) = self.preferred_timing() {
+            if timing.horizontal_size != 0 && timing.vertical_size != 0 {
+                return Some((timing.horizontal_size, timing.vertical_size));
+            }
+        }
+        match self.screen_size() {
+            ScreenSize::Size { width, height } => Some((width as u16 * 10, height as u16 * 10)),
+            ScreenSize::AspectRatio(_) | ScreenSize::Undefined => None,
+        }
+    }
+
+    /// Horizontal and vertical pixel density of the preferred timing, in
+    /// dots per inch. `None` if the physical size is unknown.
+    pub fn dpi(&self) -> Option<(f32, f32)> {
+        let timing = self.preferred_timing()?;
+        let (width, height) = self.physical_size_mm()?;
+        let dpi = |pixels: u16, mm: u16| pixels as f32 * 25.4 / mm as f32;
+        Some((
+            dpi(timing.horizontal_active_pixels, width),
+            dpi(timing.vertical_active_lines, height),
+        ))
+    }
+
+    pub fn range_limits(&self) -> Option<&RangeLimits> {
+        self.descriptors.iter().find_map(|d| match d {
+            Descriptor::RangeLimits(limits) => Some(limits),
+            _ => None,
+        })
+    }
+
+    pub fn product_info(&self) -> ProductInfo {
+        let mut serial_number = None;
+        let mut product_name = None;
+        for descriptor in &self.descriptors {
+            match descriptor {
+                Descriptor::SerialNumber(s) if serial_number.is_none() => {
+                    serial_number = Some(s.clone())
+                }
+                Descriptor::ProductName(s) if product_name.is_none() => {
+                    product_name = Some(s.clone())
+                }
+                _ => {}
+            }
+        }
+
+        // A DisplayID product identification block fills in what the base
+        // block leaves empty
+        let (displayid_name, displayid_serial) = self.displayid_product().unwrap_or_default();
+        if product_name.is_none() && !displayid_name.is_empty() {
+            product_name = Some(displayid_name.to_string());
+        }
+        let serial = match self.header.serial {
+            0 => displayid_serial,
+            serial => serial,
+        };
+
+        ProductInfo {
+            vendor: self.header.vendor,
+            product: self.header.product,
+            serial,
+            serial_number,
+            product_name,
+            week: self.header.week,
+            year: self.header.year,
+        }
+    }
+}
 
+#[cfg(feature = "displayid")]
+impl EDID {
+    fn displayid_product(&self) -> Option<(&str, u32)> {
+        self.extensions.iter().find_map(|ext| match ext {
+            Extension::DisplayId(displayid) => displayid
+                .product_id()
+                .map(|product| (product.product_name.as_str(), product.serial_number)),
+            _ => None,
+        })
+    }
 }
 
+#[cfg(not(feature = "displayid"))]
+impl EDID {
+    fn displayid_product(&self) -> Option<(&str, u32)> {
+        None
+    }
+}
+
+/// HDMI 2.1 sinks may declare the real extension count in an EEODB of the
+/// first extension, in which case the base block says 1.
+#[cfg(feature = "cta")]
+fn parse_overridden_extensions(
+    input: &[u8],
+    mut extensions: Vec<Extension>,
+    parse_extension: ExtensionParser,
+) -> IResult<&[u8], Vec<Extension>, VerboseError<&[u8]>> {
+    let total = match extensions.first() {
+        Some(Extension::Cta(cta)) => cta.extension_override().unwrap_or(0) as usize,
+        _ => 0,
+    };
+    if total <= extensions.len() {
+        return Ok((input, extensions));
+    }
+    let (input, more) = count(parse_extension, total - extensions.len())(input)?;
+    extensions.extend(more);
+    Ok((input, extensions))
+}
+
+/// Parser of a single extension block.
+pub(crate) type ExtensionParser = fn(&[u8]) -> IResult<&[u8], Extension, VerboseError<&[u8]>>;
+
 fn parse_edid(input: &[u8]) -> IResult<&[u8], EDID, VerboseError<&[u8]>> {
-    let (input, (
-        header,
-        display,
-        chromaticity,
-        established_timing,
-        standard_timing,
-        descriptors,
-        number_of_extensions,
-        _checksum
-    )) = tuple((
-        parse_header,
+    parse_edid_with(input, parse_extension_block)
+}
+
+/// Parses an EDID, reading the extension blocks with `parse_extension`.
+pub(crate) fn parse_edid_with(
+    input: &[u8],
+    parse_extension: ExtensionParser,
+) -> IResult<&[u8], EDID, VerboseError<&[u8]>> {
+    let data = input;
+    let (input, header) = parse_header(input)?;
+    let (
+        input,
+        (
+            display,
+            chromaticity,
+            established_timing,
+            standard_timing,
+            descriptors,
+            number_of_extensions,
+            _checksum,
+        ),
+    ) = tuple((
         parse_display,
         parse_chromaticity,
         parse_established_timing,
-        parse_standard_timing,
+        |i| parse_standard_timing(i, &header),
         map(count(parse_descriptor, 4), Vec::from),
         le_u8,
         le_u8,
     ))(input)?;
 
-    if number_of_extensions == 0 {
-        return Ok((input, EDID {
-            header,
-            display,
-            chromaticity,
-            established_timing,
-            standard_timing,
-            descriptors,
-            extensions: None,
-        }));
-    }
-
-    // let (input, extensions) = map(
-    //     count(move |input| parse_extension(input), number_of_extensions as usize),
-    //     Vec::from,
-    // )(input)?;
-    let (input, extensions) = parse_extension(input)?;
+    let (input, extensions) = map(
+        count(parse_extension, number_of_extensions as usize),
+        Vec::from,
+    )(input)?;
+    #[cfg(feature = "cta")]
+    let (input, extensions) = parse_overridden_extensions(input, extensions, parse_extension)?;
+    let checksum_mismatches = checksum_mismatches(&data[..data.offset(input)]);
 
     Ok((
         input,
@@ -302,11 +873,40 @@ fn parse_edid(input: &[u8]) -> IResult<&[u8], EDID, VerboseError<&[u8]>> {
             established_timing,
             standard_timing,
             descriptors,
-            extensions: Some(extensions),
+            extensions,
+            checksum_mismatches,
         },
     ))
 }
 
-pub fn parse(data: &[u8]) -> nom::IResult<&[u8], EDID, VerboseError<&[u8]>> {
-    parse_edid(data)
+/// Like [`EDID::parse`], also returning the bytes after the last
+/// extension block.
+pub fn parse(data: &[u8]) -> Result<(&[u8], EDID), EdidError> {
+    parse_edid(data).map_err(|err| EdidError::from_nom(data, err))
+}
+
+impl EDID {
+    /// Parses an EDID and its extension blocks. Bytes after the last
+    /// extension block are ignored.
+    pub fn parse(data: &[u8]) -> Result<EDID, EdidError> {
+        parse(data).map(|(_, edid)| edid)
+    }
+}
+
+/// Same as [`EDID::parse`].
+impl TryFrom<&[u8]> for EDID {
+    type Error = EdidError;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        EDID::parse(data)
+    }
+}
+
+/// Same as [`EDID::parse`].
+impl TryFrom<Vec<u8>> for EDID {
+    type Error = EdidError;
+
+    fn try_from(data: Vec<u8>) -> Result<Self, Self::Error> {
+        EDID::parse(&data)
+    }
 }
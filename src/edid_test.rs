@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use crate::edid::*;
+    use crate::{edid::*, established::EstablishedTimings};
 
     fn test(d: &[u8], expected: &EDID) {
         match parse(d) {
@@ -35,9 +35,30 @@ mod tests {
                 gamma: 120,
                 features: 42,
             },
-            chromaticity: (()),
-            established_timing: (()),
-            standard_timing: (()),
+            chromaticity: (),
+            established_timing: EstablishedTimings([0xbf, 0xef, 0x80]),
+            standard_timing: vec![
+                StandardTiming {
+                    horizontal_active_pixels: 1680,
+                    aspect_ratio: AspectRatio::R16_10,
+                    refresh_rate: 60,
+                },
+                StandardTiming {
+                    horizontal_active_pixels: 1280,
+                    aspect_ratio: AspectRatio::R5_4,
+                    refresh_rate: 60,
+                },
+                StandardTiming {
+                    horizontal_active_pixels: 1280,
+                    aspect_ratio: AspectRatio::R4_3,
+                    refresh_rate: 60,
+                },
+                StandardTiming {
+                    horizontal_active_pixels: 1152,
+                    aspect_ratio: AspectRatio::R4_3,
+                    refresh_rate: 75,
+                },
+            ],
             descriptors: vec![
                 Descriptor::DetailedTiming(DetailedTiming {
                     pixel_clock: 146250,
@@ -55,11 +76,20 @@ mod tests {
                     vertical_border_pixels: 0,
                     features: 28,
                 }),
-                Descriptor::RangeLimits,
+                Descriptor::RangeLimits(RangeLimits {
+                    min_vertical_rate: 56,
+                    max_vertical_rate: 75,
+                    min_horizontal_rate: 30,
+                    max_horizontal_rate: 81,
+                    max_pixel_clock: 170000,
+                    video_timing_support: 0,
+                    video_timing_data: [10, 32, 32, 32, 32, 32, 32],
+                }),
                 Descriptor::ProductName("SyncMaster".to_string()),
                 Descriptor::SerialNumber("HS3P701105".to_string()),
             ],
-            extensions: None,
+            extensions: vec![],
+            checksum_mismatches: vec![],
         };
 
         test(d, &expected);
@@ -86,9 +116,9 @@ mod tests {
                 gamma: 120,
                 features: 14,
             },
-            chromaticity: (()),
-            established_timing: (()),
-            standard_timing: (()),
+            chromaticity: (),
+            established_timing: EstablishedTimings([0x00, 0x00, 0x00]),
+            standard_timing: vec![],
             descriptors: vec![
                 Descriptor::DetailedTiming(DetailedTiming {
                     pixel_clock: 138500,
@@ -110,9 +140,170 @@ mod tests {
                 Descriptor::UnspecifiedText("DJCP6ÇLQ133M1".to_string()),
                 Descriptor::Unknown([2, 65, 3, 40, 0, 18, 0, 0, 11, 1, 10, 32, 32]),
             ],
-            extensions: None,
+            extensions: vec![],
+            checksum_mismatches: vec![],
         };
 
         test(d, &expected);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_standard_timing_aspect_ratio_pre_1_3() {
+        let mut d = *include_bytes!("../testdata/card0-VGA-1.bin");
+        d[19] = 2; // EDID 1.2
+        let (_, edid) = parse(&d).unwrap();
+
+        let timing = edid.standard_timing[0];
+        assert_eq!(timing.aspect_ratio, AspectRatio::R1_1);
+        assert_eq!(timing.vertical_active_lines(), 1680);
+        assert_eq!(edid.standard_timing[3].vertical_active_lines(), 864);
+    }
+
+    #[test]
+    fn test_detailed_timing_vertical_high_bits() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let mut dtd = d[54..72].to_vec();
+        // Vertical front porch 0x23 and sync width 0x15: the low nibbles
+        // in byte 10, bits 4 and 5 in bits 0 to 3 of byte 11
+        dtd[10] = 0x35;
+        dtd[11] = (dtd[11] & 0xf0) | 0b1001;
+        let (_, timing) = parse_detailed_timing(&dtd).unwrap();
+        assert_eq!(timing.vertical_front_porch, 35);
+        assert_eq!(timing.vertical_sync_width, 21);
+        assert_eq!(timing.to_bytes()[..], dtd[..]);
+    }
+
+    #[test]
+    fn test_decoded_display_bytes() {
+        let d = include_bytes!("../testdata/card0-eDP-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(
+            edid.display.decoded_video_input(),
+            VideoInput::Digital {
+                color_bit_depth: Some(8),
+                interface: DigitalInterface::DisplayPort,
+            }
+        );
+
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(
+            edid.display.decoded_video_input(),
+            VideoInput::Analog {
+                signal_level: 0,
+                blank_to_black_setup: false,
+                separate_sync: true,
+                composite_sync: true,
+                sync_on_green: true,
+                vsync_serrated: false,
+            }
+        );
+        assert_eq!(
+            edid.display.decoded_features(),
+            FeatureSupport {
+                dpms_standby: false,
+                dpms_suspend: false,
+                dpms_active_off: true,
+                display_type: 1,
+                srgb_default: false,
+                preferred_timing_native: true,
+                continuous_frequency: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_unspecified_texts() {
+        let mut d = *include_bytes!("../testdata/card0-VGA-1.bin");
+        d[93] = 0xFE;
+        d[111] = 0xFE;
+        let (_, edid) = parse(&d).unwrap();
+
+        assert_eq!(
+            edid.unspecified_texts(),
+            vec![JoinedText {
+                text: "SyncMasterHS3P701105".to_string(),
+                pieces: vec!["SyncMaster".to_string(), "HS3P701105".to_string()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_screen_size() {
+        let mut d = *include_bytes!("../testdata/card0-eDP-1.bin");
+        let (_, edid) = parse(&d).unwrap();
+        assert_eq!(
+            edid.screen_size(),
+            ScreenSize::Size {
+                width: 29,
+                height: 17
+            }
+        );
+
+        // 16:9 landscape
+        d[21] = 79;
+        d[22] = 0;
+        let (_, edid) = parse(&d).unwrap();
+        assert_eq!(edid.screen_size(), ScreenSize::AspectRatio(1.78));
+
+        // 9:16 portrait
+        d[21] = 0;
+        d[22] = 79;
+        let (_, edid) = parse(&d).unwrap();
+        assert_eq!(edid.screen_size(), ScreenSize::AspectRatio(100.0 / 178.0));
+    }
+
+    #[test]
+    fn test_undefined_screen_size() {
+        let mut d = *include_bytes!("../testdata/card0-VGA-1.bin");
+        let (_, edid) = parse(&d).unwrap();
+        assert_eq!(edid.physical_size_mm(), Some((474, 296)));
+        let (h, v) = edid.dpi().unwrap();
+        assert_eq!((h.round(), v.round()), (90.0, 90.0));
+
+        // Projector: no size anywhere
+        d[21] = 0;
+        d[22] = 0;
+        d[66] = 0;
+        d[67] = 0;
+        d[68] = 0;
+        let (_, edid) = parse(&d).unwrap();
+        assert_eq!(edid.screen_size(), ScreenSize::Undefined);
+        assert_eq!(edid.physical_size_mm(), None);
+        assert_eq!(edid.dpi(), None);
+    }
+
+    #[test]
+    fn test_vendor_representations() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let (_, edid) = parse(d).unwrap();
+
+        assert_eq!(edid.header.vendor_string(), "SAM");
+        assert_eq!(edid.header.vendor_raw(), 0x4c2d);
+        assert_eq!(Header::vendor_from_raw(0x4c2d), ['S', 'A', 'M']);
+        assert_eq!(Header::vendor_from_str("SAM"), Some(['S', 'A', 'M']));
+        assert_eq!(Header::vendor_from_str("sam"), None);
+        assert_eq!(Header::vendor_from_str("SAMS"), None);
+    }
+
+    #[test]
+    fn test_product_info() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let (_, edid) = parse(d).unwrap();
+
+        assert_eq!(
+            edid.product_info(),
+            ProductInfo {
+                vendor: ['S', 'A', 'M'],
+                product: 596,
+                serial: 1146106418,
+                serial_number: Some("HS3P701105".to_string()),
+                product_name: Some("SyncMaster".to_string()),
+                week: 27,
+                year: 17,
+            }
+        );
+        assert_eq!(edid.header.year_ad(), 2007);
+        assert_eq!(edid.product_info().year_ad(), 2007);
+    }
+}
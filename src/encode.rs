@@ -0,0 +1,868 @@
+use std::fmt;
+
+#[cfg(feature = "di-ext")]
+use crate::di_ext::{ContentProtection, DiExt};
+#[cfg(feature = "displayid")]
+use crate::displayid::{DisplayId, DisplayIdDataBlock, DisplayIdTiming};
+#[cfg(feature = "vtb")]
+use crate::vtb::{CvtCode, Vtb};
+use crate::{
+    block_map::BlockMap,
+    cp437,
+    edid::{
+        AspectRatio, Descriptor, Display, Header, RangeLimits, StandardTiming, BLOCK_SIZE, EDID,
+        HEADER_MAGIC,
+    },
+    extension::Extension,
+    limits::{MAX_DATA_BLOCKS, MAX_EXTENSIONS},
+    ls_ext::{LsExt, StringEncoding},
+};
+#[cfg(feature = "cta")]
+use crate::{
+    cta::{CtaExtensions, DataBlock, ShortVideoDescriptor},
+    edid::DetailedTiming,
+    extended::{
+        ExtendedDataBlock, RoomPosition, ScanBehavior, ShortVideoReference,
+        VendorSpecificAudioPayload, VendorSpecificVideoPayload,
+    },
+    hdmi::HdmiForumVsdb,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum EncodeError {
+    /// More extension blocks than the parser accepts.
+    TooManyExtensions { count: usize },
+    /// Some content doesn't fit in its field or block, e.g. a data block
+    /// payload longer than 31 bytes.
+    Overflow {
+        /// The block being encoded, the base block being 0.
+        block: usize,
+        what: &'static str,
+        len: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::TooManyExtensions { count } => write!(
+                f,
+                "{} extension blocks, at most {} are allowed",
+                count, MAX_EXTENSIONS
+            ),
+            EncodeError::Overflow {
+                block,
+                what,
+                len,
+                max,
+            } => write!(
+                f,
+                "{} in block {} is {} long, at most {} fit",
+                what, block, len, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+fn check_len(block: usize, what: &'static str, len: usize, max: usize) -> Result<(), EncodeError> {
+    if len > max {
+        return Err(EncodeError::Overflow {
+            block,
+            what,
+            len,
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Pads the block to 127 bytes and appends its checksum.
+pub(crate) fn finish_block(mut block: Vec<u8>) -> Vec<u8> {
+    block.resize(BLOCK_SIZE - 1, 0);
+    let sum = block.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+    block.push(sum.wrapping_neg());
+    block
+}
+
+/// The header and basic display parameters, the first 25 bytes of the base
+/// block.
+pub(crate) fn header_bytes(header: &Header, display: &Display) -> Vec<u8> {
+    let mut block = HEADER_MAGIC.to_vec();
+    block.extend_from_slice(&header.vendor_raw().to_be_bytes());
+    block.extend_from_slice(&header.product.to_le_bytes());
+    block.extend_from_slice(&header.serial.to_le_bytes());
+    block.extend_from_slice(&[header.week, header.year, header.version, header.revision]);
+    block.extend_from_slice(&[
+        display.video_input,
+        display.width,
+        display.height,
+        display.gamma,
+        display.features,
+    ]);
+    block
+}
+
+fn standard_timing_bytes(timing: &StandardTiming) -> [u8; 2] {
+    let aspect_ratio = match timing.aspect_ratio {
+        AspectRatio::R1_1 | AspectRatio::R16_10 => 0b00,
+        AspectRatio::R4_3 => 0b01,
+        AspectRatio::R5_4 => 0b10,
+        AspectRatio::R16_9 | AspectRatio::R15_9 => 0b11,
+    };
+    [
+        (timing.horizontal_active_pixels / 8).saturating_sub(31) as u8,
+        aspect_ratio << 6 | (timing.refresh_rate.saturating_sub(60) & 0x3f),
+    ]
+}
+
+/// Encodes descriptor text in code page 437, terminated by a line feed and
+/// padded with spaces.
+fn text_bytes(text: &str) -> Result<[u8; 13], EncodeError> {
+    let encoded = cp437::encode(text);
+    check_len(0, "descriptor text", encoded.len(), 13)?;
+    Ok(text_payload(&encoded))
+}
+
+/// Pads already encoded text of at most 13 bytes.
+pub(crate) fn text_payload(text: &[u8]) -> [u8; 13] {
+    let mut bytes = [0x20; 13];
+    bytes[..text.len()].copy_from_slice(text);
+    if text.len() < 13 {
+        bytes[text.len()] = 0x0A;
+    }
+    bytes
+}
+
+/// Lays out a display descriptor.
+pub(crate) fn display_descriptor(tag: u8, flags: u8, payload: [u8; 13]) -> [u8; 18] {
+    let mut bytes = [0; 18];
+    bytes[3] = tag;
+    bytes[4] = flags;
+    bytes[5..].copy_from_slice(&payload);
+    bytes
+}
+
+/// The rate offset flags and the 13 bytes of a range limits descriptor.
+fn range_limits_bytes(limits: &RangeLimits) -> (u8, [u8; 13]) {
+    let mut flags = 0;
+    let mut rate = |value: u16, flag: u8| {
+        if value > 255 {
+            flags |= flag;
+            (value - 255) as u8
+        } else {
+            value as u8
+        }
+    };
+    let mut bytes = [0; 13];
+    bytes[0] = rate(limits.min_vertical_rate, 0x03);
+    bytes[1] = rate(limits.max_vertical_rate, 0x02);
+    bytes[2] = rate(limits.min_horizontal_rate, 0x0c);
+    bytes[3] = rate(limits.max_horizontal_rate, 0x08);
+    bytes[4] = limits.max_pixel_clock.div_ceil(10_000).min(255) as u8;
+    bytes[5] = limits.video_timing_support;
+    bytes[6..].copy_from_slice(&limits.video_timing_data);
+    (flags, bytes)
+}
+
+fn descriptor_bytes(descriptor: &Descriptor) -> Result<[u8; 18], EncodeError> {
+    let (tag, flags, payload) = match descriptor {
+        Descriptor::DetailedTiming(timing) => return Ok(timing.to_bytes()),
+        Descriptor::SerialNumber(text) => (0xFF, 0, text_bytes(text)?),
+        Descriptor::UnspecifiedText(text) => (0xFE, 0, text_bytes(text)?),
+        Descriptor::RangeLimits(limits) => {
+            let (flags, bytes) = range_limits_bytes(limits);
+            (0xFD, flags, bytes)
+        }
+        Descriptor::ProductName(text) => (0xFC, 0, text_bytes(text)?),
+        Descriptor::WhitePoint => (0xFB, 0, [0; 13]),
+        Descriptor::StandardTiming => (0xFA, 0, [0; 13]),
+        Descriptor::ColorManagement => (0xF9, 0, [0; 13]),
+        Descriptor::TimingCodes => (0xF8, 0, [0; 13]),
+        Descriptor::EstablishedTimings => (0xF7, 0, [0; 13]),
+        Descriptor::Dummy => (0x10, 0, [0; 13]),
+        // The tag isn't kept, 0x00 is the first manufacturer-specified one
+        Descriptor::Unknown(payload) => (0x00, 0, *payload),
+    };
+    Ok(display_descriptor(tag, flags, payload))
+}
+
+pub(crate) fn base_block(edid: &EDID) -> Result<Vec<u8>, EncodeError> {
+    check_len(0, "standard timings", edid.standard_timing.len(), 8)?;
+    check_len(0, "descriptors", edid.descriptors.len(), 4)?;
+
+    let mut block = header_bytes(&edid.header, &edid.display);
+    // Chromaticity isn't kept
+    block.resize(35, 0);
+    block.extend_from_slice(&edid.established_timing.0);
+    for timing in &edid.standard_timing {
+        block.extend_from_slice(&standard_timing_bytes(timing));
+    }
+    block.resize(54, 0x01);
+    for descriptor in &edid.descriptors {
+        block.extend_from_slice(&descriptor_bytes(descriptor)?);
+    }
+    for _ in edid.descriptors.len()..4 {
+        block.extend_from_slice(&descriptor_bytes(&Descriptor::Dummy)?);
+    }
+    let eeodb = edid.extensions.first().is_some_and(has_extension_override);
+    block.push(if eeodb {
+        1
+    } else {
+        edid.extensions.len() as u8
+    });
+    Ok(finish_block(block))
+}
+
+#[cfg(feature = "cta")]
+fn svd_byte(svd: &ShortVideoDescriptor) -> u8 {
+    if svd.is_native != 0 {
+        svd.cea861_index | 0x80
+    } else {
+        svd.cea861_index
+    }
+}
+
+#[cfg(feature = "cta")]
+fn svr_byte(svr: ShortVideoReference) -> u8 {
+    match svr {
+        ShortVideoReference::Vic(vic) => vic,
+        ShortVideoReference::Dtd(index) => 128u8.wrapping_add(index),
+        ShortVideoReference::DisplayIdTiming(index) => 144u8.wrapping_add(index),
+        ShortVideoReference::Reserved(svr) => svr,
+    }
+}
+
+#[cfg(feature = "cta")]
+fn scan_bits(behavior: ScanBehavior) -> u8 {
+    match behavior {
+        ScanBehavior::Unsupported => 0b00,
+        ScanBehavior::AlwaysOverscanned => 0b01,
+        ScanBehavior::AlwaysUnderscanned => 0b10,
+        ScanBehavior::Selectable => 0b11,
+    }
+}
+
+#[cfg(feature = "cta")]
+fn position_bytes(position: &RoomPosition) -> [u8; 3] {
+    [position.x as u8, position.y as u8, position.z as u8]
+}
+
+/// The HF-VSDB fields from the version byte on, the optional HDMI 2.1
+/// bytes only up to the last one in use.
+#[cfg(feature = "cta")]
+fn hdmi_forum_bytes(hf: &HdmiForumVsdb, out: &mut Vec<u8>) {
+    out.extend_from_slice(&[
+        hf.version,
+        (hf.max_tmds_char_rate.unwrap_or(0) / 5000) as u8,
+        hf.flags,
+        hf.max_frl_rate << 4 | (hf.uhd_vic as u8) << 3 | (hf.dc_420 & 0x07),
+    ]);
+    let vrr_max = hf.vrr_max.unwrap_or(0);
+    let has_vrr = hf.vrr_min.is_some() || hf.vrr_max.is_some();
+    if hf.features != 0 || has_vrr || hf.dsc.is_some() {
+        out.push(hf.features);
+    }
+    if has_vrr || hf.dsc.is_some() {
+        out.push(((vrr_max >> 2) as u8 & 0xc0) | (hf.vrr_min.unwrap_or(0) & 0x3f));
+        out.push(vrr_max as u8);
+    }
+    if let Some(dsc) = &hf.dsc {
+        out.extend_from_slice(&[
+            dsc.flags,
+            dsc.max_frl_rate << 4 | (dsc.max_slices & 0x0f),
+            dsc.total_chunk_kbytes & 0x3f,
+        ]);
+    }
+}
+
+/// The payload of an extended data block following the extended tag.
+#[cfg(feature = "cta")]
+fn extended_payload(block: &ExtendedDataBlock, extension_count: u8, out: &mut Vec<u8>) {
+    match block {
+        ExtendedDataBlock::VideoCapability(cap) => out.push(
+            (cap.ycc_quantization_selectable as u8) << 7
+                | (cap.rgb_quantization_selectable as u8) << 6
+                | scan_bits(cap.preferred) << 4
+                | scan_bits(cap.it) << 2
+                | scan_bits(cap.ce),
+        ),
+        ExtendedDataBlock::VendorSpecificVideo(vsvdb) => {
+            out.extend_from_slice(&vsvdb.identifier);
+            match &vsvdb.payload {
+                VendorSpecificVideoPayload::Hdr10Plus(hdr10) => {
+                    out.push(
+                        (hdr10.application_version & 0x3)
+                            | (hdr10.full_frame_peak_luminance_index & 0x3) << 2
+                            | hdr10.peak_luminance_index << 4,
+                    );
+                    out.extend_from_slice(&hdr10.extra);
+                }
+                VendorSpecificVideoPayload::Raw(payload) => out.extend_from_slice(payload),
+            }
+        }
+        ExtendedDataBlock::Colorimetry(colorimetry) => {
+            out.extend_from_slice(&[colorimetry.colorimetry, colorimetry.metadata])
+        }
+        ExtendedDataBlock::HdrStaticMetadata(hdr) => {
+            out.extend_from_slice(&[hdr.eotfs, hdr.static_metadata_descriptors]);
+            let luminance = [
+                hdr.max_luminance,
+                hdr.max_frame_avg_luminance,
+                hdr.min_luminance,
+            ];
+            let len = luminance
+                .iter()
+                .rposition(Option::is_some)
+                .map_or(0, |i| i + 1);
+            out.extend(luminance[..len].iter().map(|code| code.unwrap_or(0)));
+        }
+        ExtendedDataBlock::HdrDynamicMetadata(hdr) => {
+            for metadata in &hdr.metadata_types {
+                out.push((metadata.support_flags.len() as u8).wrapping_add(2));
+                out.extend_from_slice(&metadata.metadata_type.to_le_bytes());
+                out.extend_from_slice(&metadata.support_flags);
+            }
+        }
+        ExtendedDataBlock::NativeVideoResolution(nvrdb) => {
+            out.push(nvrdb.native.map_or(0, svr_byte));
+            if nvrdb.flags != 0 || nvrdb.resolution.is_some() || !nvrdb.extra.is_empty() {
+                out.push(nvrdb.flags);
+            }
+            if nvrdb.resolution.is_some() || !nvrdb.extra.is_empty() {
+                let (width, height) = nvrdb.resolution.unwrap_or((1, 1));
+                out.extend_from_slice(&width.wrapping_sub(1).to_le_bytes());
+                out.extend_from_slice(&height.wrapping_sub(1).to_le_bytes());
+            }
+            out.extend_from_slice(&nvrdb.extra);
+        }
+        ExtendedDataBlock::VideoFormatPreference(vfpdb) => {
+            out.extend(vfpdb.preferences.iter().copied().map(svr_byte))
+        }
+        ExtendedDataBlock::Ycbcr420Video(ycbcr420) => {
+            out.extend(ycbcr420.descriptors.iter().map(svd_byte))
+        }
+        ExtendedDataBlock::Ycbcr420CapabilityMap(map) => out.extend_from_slice(&map.bitmap),
+        ExtendedDataBlock::VendorSpecificAudio(vsadb) => {
+            out.extend_from_slice(&vsadb.identifier);
+            match &vsadb.payload {
+                VendorSpecificAudioPayload::Dolby(dolby) => {
+                    out.push(
+                        (dolby.headphone_only as u8) << 7
+                            | (dolby.height_speaker_zone as u8) << 6
+                            | (dolby.surround_speaker_zone as u8) << 5
+                            | (dolby.center_speaker_zone as u8) << 4
+                            | (dolby.version & 0x7),
+                    );
+                    out.push(dolby.mat_pcm_48khz_only as u8);
+                    out.extend_from_slice(&dolby.extra);
+                }
+                VendorSpecificAudioPayload::Raw(payload) => out.extend_from_slice(payload),
+            }
+        }
+        ExtendedDataBlock::RoomConfiguration(room) => {
+            out.push(
+                (room.display.is_some() as u8) << 7
+                    | (room.speaker_count.is_some() as u8) << 6
+                    | (room.has_speaker_locations as u8) << 5
+                    | (room.speaker_count.unwrap_or(1).saturating_sub(1) & 0x1f),
+            );
+            out.extend_from_slice(&room.speaker_presence.to_le_bytes()[..3]);
+            if room.max_dm.is_some() || room.display.is_some() {
+                out.extend_from_slice(&room.max_dm.unwrap_or_default());
+            }
+            if let Some(display) = &room.display {
+                out.extend_from_slice(&position_bytes(display));
+            }
+        }
+        ExtendedDataBlock::SpeakerLocations(locations) => {
+            for location in &locations.descriptors {
+                out.push(
+                    (location.position.is_some() as u8) << 6
+                        | (location.active as u8) << 5
+                        | (location.channel & 0x1f),
+                );
+                out.push(location.speaker & 0x1f);
+                if let Some(position) = &location.position {
+                    out.extend_from_slice(&position_bytes(position));
+                }
+            }
+        }
+        ExtendedDataBlock::InfoFrame(infoframe) => {
+            out.push((infoframe.processing.len() as u8) << 5);
+            out.push(infoframe.simultaneous_vsifs.saturating_sub(1) as u8);
+            out.extend_from_slice(&infoframe.processing);
+            for descriptor in &infoframe.descriptors {
+                out.push((descriptor.payload.len() as u8) << 5 | (descriptor.type_code & 0x1f));
+                if let Some(identifier) = &descriptor.identifier {
+                    out.extend_from_slice(identifier);
+                }
+                out.extend_from_slice(&descriptor.payload);
+            }
+        }
+        ExtendedDataBlock::HdmiForumSinkCapability(hf) => {
+            out.extend_from_slice(&[0, 0]);
+            hdmi_forum_bytes(hf, out);
+        }
+        ExtendedDataBlock::ExtensionOverride(_) => out.push(extension_count),
+        ExtendedDataBlock::Reserved(reserved) => out.extend_from_slice(&reserved.payload),
+    }
+}
+
+/// Appends a data block with its header. An EEODB gets `extension_count`.
+#[cfg(feature = "cta")]
+fn data_block_bytes(
+    block: &DataBlock,
+    index: usize,
+    extension_count: u8,
+    out: &mut Vec<u8>,
+) -> Result<(), EncodeError> {
+    let mut payload = Vec::new();
+    let tag = match block {
+        DataBlock::Reserved(reserved) => {
+            payload.extend_from_slice(&reserved.payload);
+            reserved.header.type_tag
+        }
+        DataBlock::AudioBlock(audio) => {
+            for sad in &audio.descriptors {
+                let third = if sad.audio_format == 15 {
+                    sad.audio_format_extended_code << 3 | (sad.format_dependent_value & 0x7)
+                } else {
+                    sad.format_dependent_value
+                };
+                payload.extend_from_slice(&[
+                    (sad.audio_format & 0xf) << 3
+                        | (sad.number_of_channels.saturating_sub(1) & 0x7),
+                    sad.sampling_frequences,
+                    third,
+                ]);
+            }
+            1
+        }
+        DataBlock::VideoBlock(video) => {
+            payload.extend(video.descriptors.iter().map(svd_byte));
+            2
+        }
+        DataBlock::VendorSpecific(vsdb) => {
+            payload.extend_from_slice(&vsdb.identifier);
+            payload.extend_from_slice(&vsdb.payload);
+            3
+        }
+        DataBlock::TruncatedVendorSpecific(reserved) => {
+            payload.extend_from_slice(&reserved.payload);
+            3
+        }
+        DataBlock::SpeakerAllocation(speakers) => {
+            payload.extend_from_slice(&speakers.speakers.0.to_le_bytes()[..3]);
+            4
+        }
+        DataBlock::Extended(extended) => {
+            payload.push(extended.extended_tag());
+            extended_payload(extended, extension_count, &mut payload);
+            7
+        }
+    };
+    check_len(index, "data block payload", payload.len(), 31)?;
+    push_data_block(tag, &payload, out);
+    Ok(())
+}
+
+/// Appends a CTA data block header and its payload of at most 31 bytes.
+#[cfg(feature = "cta")]
+pub(crate) fn push_data_block(tag: u8, payload: &[u8], out: &mut Vec<u8>) {
+    out.push((tag & 0x7) << 5 | payload.len() as u8);
+    out.extend_from_slice(payload);
+}
+
+/// Lays out a CTA extension block around an encoded data block collection.
+#[cfg(feature = "cta")]
+pub(crate) fn cta_layout(revision: u8, flags: u8, collection: &[u8], dtds: &[[u8; 18]]) -> Vec<u8> {
+    // Without data blocks and DTDs the offset is zero
+    let offset = if collection.is_empty() && dtds.is_empty() {
+        0
+    } else {
+        4 + collection.len()
+    };
+    let mut block = vec![Extension::CTA, revision, offset as u8, flags];
+    block.extend_from_slice(collection);
+    for dtd in dtds {
+        block.extend_from_slice(dtd);
+    }
+    finish_block(block)
+}
+
+#[cfg(feature = "cta")]
+fn cta_block(
+    cta: &CtaExtensions,
+    index: usize,
+    extension_count: u8,
+) -> Result<Vec<u8>, EncodeError> {
+    let mut collection = Vec::new();
+    check_len(index, "data blocks", cta.blocks.len(), MAX_DATA_BLOCKS)?;
+    for block in &cta.blocks {
+        data_block_bytes(block, index, extension_count, &mut collection)?;
+    }
+    let len = collection.len() + cta.descriptors.len() * 18;
+    check_len(index, "CTA data blocks and DTDs", len, 123)?;
+
+    let native = &cta.native_dtd;
+    let flags = (native.underscan & 1) << 7
+        | (native.basic_audio & 1) << 6
+        | (native.ycbcr444 & 1) << 5
+        | (native.ycbcr422 & 1) << 4
+        | (native.number_of_native_dtd & 0xf);
+    let dtds: Vec<_> = cta
+        .descriptors
+        .iter()
+        .map(DetailedTiming::to_bytes)
+        .collect();
+    Ok(cta_layout(cta.reserved, flags, &collection, &dtds))
+}
+
+#[cfg(feature = "vtb")]
+fn cvt_code_bytes(code: &CvtCode) -> [u8; 3] {
+    let lines = (code.vertical_active_lines / 2).saturating_sub(1);
+    let aspect_ratio = match code.aspect_ratio {
+        AspectRatio::R16_9 => 0b01,
+        AspectRatio::R16_10 => 0b10,
+        AspectRatio::R15_9 => 0b11,
+        _ => 0b00,
+    };
+    let preferred = match code.preferred_refresh_rate {
+        60 => 0b01,
+        75 => 0b10,
+        85 => 0b11,
+        _ => 0b00,
+    };
+    [
+        lines as u8,
+        ((lines >> 8) as u8) << 4 | aspect_ratio << 2,
+        preferred << 5 | (code.supported_refresh_rates & 0x1f),
+    ]
+}
+
+#[cfg(feature = "vtb")]
+fn vtb_block(vtb: &Vtb, index: usize) -> Result<Vec<u8>, EncodeError> {
+    let len = 4
+        + vtb.detailed_timings.len() * 18
+        + vtb.cvt_codes.len() * 3
+        + vtb.standard_timings.len() * 2;
+    check_len(index, "VTB timings", len, 126)?;
+    let mut block = vec![
+        Extension::VTB,
+        vtb.version,
+        vtb.detailed_timings.len() as u8,
+        vtb.cvt_codes.len() as u8,
+        vtb.standard_timings.len() as u8,
+    ];
+    for timing in &vtb.detailed_timings {
+        block.extend_from_slice(&timing.to_bytes());
+    }
+    for code in &vtb.cvt_codes {
+        block.extend_from_slice(&cvt_code_bytes(code));
+    }
+    for timing in &vtb.standard_timings {
+        block.extend_from_slice(&standard_timing_bytes(timing));
+    }
+    Ok(finish_block(block))
+}
+
+/// Bytes 0x37-0x50 aren't kept and are written as zeros.
+#[cfg(feature = "di-ext")]
+fn di_ext_block(di_ext: &DiExt, index: usize) -> Result<Vec<u8>, EncodeError> {
+    check_len(
+        index,
+        "DI-EXT capabilities",
+        di_ext.capabilities.len(),
+        0x37 - 0x14,
+    )?;
+    let transfer = &di_ext.transfer_characteristics;
+    check_len(
+        index,
+        "DI-EXT transfer characteristics",
+        transfer.len(),
+        0x7F - 0x51,
+    )?;
+
+    let interface = &di_ext.interface;
+    let mut raw = interface.raw;
+    raw[0] = interface.interface_type;
+    raw[1] = interface.interface_version << 4 | (interface.interface_release & 0xf);
+    raw[2] = match interface.content_protection {
+        ContentProtection::None => 0x00,
+        ContentProtection::Hdcp => 0x01,
+        ContentProtection::Dtcp => 0x02,
+        ContentProtection::Dpa => 0x03,
+        ContentProtection::Reserved(code) => code,
+    };
+    // Bits per color and pixels per clock follow from the color encoding
+    raw[4] = interface.color_encoding.code();
+    let device = &di_ext.display_device;
+    let mut device_raw = device.raw;
+    device_raw[0] = device.technology << 4 | (device.technology_subtype & 0xf);
+
+    let mut block = vec![Extension::DI_EXT, di_ext.version];
+    block.extend_from_slice(&raw);
+    block.extend_from_slice(&device_raw);
+    block.extend_from_slice(&di_ext.capabilities);
+    block.resize(0x51, 0);
+    block.extend_from_slice(transfer);
+    Ok(finish_block(block))
+}
+
+fn ls_ext_block(ls_ext: &LsExt, index: usize) -> Result<Vec<u8>, EncodeError> {
+    let mut block = vec![Extension::LS_EXT, ls_ext.version, ls_ext.revision];
+    check_len(index, "string tables", ls_ext.tables.len(), MAX_DATA_BLOCKS)?;
+    for table in &ls_ext.tables {
+        let encoding = match table.encoding {
+            StringEncoding::Utf8 => 0,
+            StringEncoding::Utf16Be => 1,
+            StringEncoding::Utf32Be => 2,
+            StringEncoding::Reserved(code) => code,
+        };
+        let letter =
+            |c: char, shift: u16| ((c as u32).wrapping_sub('@' as u32) as u16 & 0x1f) << shift;
+        let language = letter(table.language[0], 10)
+            | letter(table.language[1], 5)
+            | letter(table.language[2], 0);
+        block.extend_from_slice(&[5, encoding]);
+        block.extend_from_slice(&(table.country_code & 0x3fff).to_be_bytes());
+        block.extend_from_slice(&language.to_be_bytes());
+        for string in [
+            &table.manufacturer_name,
+            &table.model_name,
+            &table.serial_number,
+        ] {
+            check_len(index, "localized string", string.raw.len(), 255)?;
+            block.push(string.raw.len() as u8);
+            block.extend_from_slice(&string.raw);
+        }
+    }
+    check_len(index, "string tables", block.len() - 1, 126)?;
+    Ok(finish_block(block))
+}
+
+#[cfg(feature = "displayid")]
+fn displayid_timing_bytes(timing: &DisplayIdTiming, clock_unit: u32, out: &mut Vec<u8>) {
+    let t = &timing.timing;
+    let minus_one = |v: u16| v.saturating_sub(1);
+    let clock = (t.pixel_clock / clock_unit).saturating_sub(1);
+    out.extend_from_slice(&clock.to_le_bytes()[..3]);
+    out.push(
+        (timing.preferred as u8) << 7
+            | (timing.stereo & 0b11) << 5
+            | (t.is_interlaced() as u8) << 4
+            | (timing.aspect_ratio & 0xf),
+    );
+    let h_polarity = if t.features & 0x02 != 0 { 0x8000 } else { 0 };
+    let v_polarity = if t.features & 0x04 != 0 { 0x8000 } else { 0 };
+    for value in [
+        minus_one(t.horizontal_active_pixels),
+        minus_one(t.horizontal_blanking_pixels),
+        minus_one(t.horizontal_front_porch) | h_polarity,
+        minus_one(t.horizontal_sync_width),
+        minus_one(t.vertical_active_lines),
+        minus_one(t.vertical_blanking_lines),
+        minus_one(t.vertical_front_porch) | v_polarity,
+        minus_one(t.vertical_sync_width),
+    ] {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+/// Appends a DisplayID data block. Typed blocks get the tag of the
+/// section's DisplayID version and revision 0.
+#[cfg(feature = "displayid")]
+fn displayid_data_block(
+    block: &DisplayIdDataBlock,
+    v2: bool,
+    index: usize,
+    out: &mut Vec<u8>,
+) -> Result<(), EncodeError> {
+    let mut payload = Vec::new();
+    let (tag, revision) = match block {
+        DisplayIdDataBlock::TypeITimings(timings) => {
+            for timing in timings {
+                displayid_timing_bytes(timing, 10, &mut payload);
+            }
+            (DisplayIdDataBlock::TYPE_I_TIMING, 0)
+        }
+        DisplayIdDataBlock::TypeViiTimings(timings) => {
+            for timing in timings {
+                displayid_timing_bytes(timing, 1, &mut payload);
+            }
+            (DisplayIdDataBlock::TYPE_VII_TIMING, 0)
+        }
+        DisplayIdDataBlock::TiledTopology(tiled) => {
+            let h_tiles = tiled.horizontal_tiles.saturating_sub(1);
+            let v_tiles = tiled.vertical_tiles.saturating_sub(1);
+            let (h_location, v_location) = (tiled.horizontal_location, tiled.vertical_location);
+            payload.extend_from_slice(&[
+                (tiled.single_enclosure as u8) << 7
+                    | (tiled.bezel.is_some() as u8) << 6
+                    | (tiled.multi_tile_behavior & 0b11) << 3
+                    | (tiled.single_tile_behavior & 0b111),
+                (h_tiles & 0xf) << 4 | (v_tiles & 0xf),
+                (h_location & 0xf) << 4 | (v_location & 0xf),
+                (h_tiles & 0x30) << 2
+                    | (v_tiles & 0x30)
+                    | (h_location & 0x30) >> 2
+                    | (v_location & 0x30) >> 4,
+            ]);
+            payload.extend_from_slice(&tiled.tile_width.saturating_sub(1).to_le_bytes());
+            payload.extend_from_slice(&tiled.tile_height.saturating_sub(1).to_le_bytes());
+            payload.extend_from_slice(&match &tiled.bezel {
+                Some(bezel) => [
+                    bezel.pixel_multiplier,
+                    bezel.top,
+                    bezel.bottom,
+                    bezel.right,
+                    bezel.left,
+                ],
+                None => [0; 5],
+            });
+            payload.extend_from_slice(&tiled.vendor_id);
+            payload.extend_from_slice(&tiled.product_code.to_le_bytes());
+            payload.extend_from_slice(&tiled.serial_number.to_le_bytes());
+            let tag = if v2 {
+                DisplayIdDataBlock::TILED_TOPOLOGY_V2
+            } else {
+                DisplayIdDataBlock::TILED_TOPOLOGY
+            };
+            (tag, 0)
+        }
+        DisplayIdDataBlock::ProductId(product) => {
+            payload.extend_from_slice(&product.vendor_id);
+            payload.extend_from_slice(&product.product_code.to_le_bytes());
+            payload.extend_from_slice(&product.serial_number.to_le_bytes());
+            let name = product.product_name.as_bytes();
+            payload.extend_from_slice(&[product.week, product.year, name.len() as u8]);
+            payload.extend_from_slice(name);
+            let tag = if v2 {
+                DisplayIdDataBlock::PRODUCT_ID_V2
+            } else {
+                DisplayIdDataBlock::PRODUCT_ID
+            };
+            (tag, 0)
+        }
+        #[cfg(feature = "cta")]
+        DisplayIdDataBlock::Cta(blocks) => {
+            check_len(index, "data blocks", blocks.len(), MAX_DATA_BLOCKS)?;
+            for block in blocks {
+                data_block_bytes(block, index, 0, &mut payload)?;
+            }
+            (DisplayIdDataBlock::CTA, 0)
+        }
+        DisplayIdDataBlock::Reserved(reserved) => {
+            payload.extend_from_slice(&reserved.payload);
+            (reserved.header.tag, reserved.header.revision)
+        }
+    };
+    check_len(index, "DisplayID data block payload", payload.len(), 248)?;
+    out.extend_from_slice(&[tag, revision, payload.len() as u8]);
+    out.extend_from_slice(&payload);
+    Ok(())
+}
+
+/// The section checksum is computed, `checksum` and `checksum_valid` are
+/// ignored.
+#[cfg(feature = "displayid")]
+fn displayid_block(displayid: &DisplayId, index: usize) -> Result<Vec<u8>, EncodeError> {
+    let mut payload = Vec::new();
+    check_len(
+        index,
+        "data blocks",
+        displayid.blocks.len(),
+        MAX_DATA_BLOCKS,
+    )?;
+    for block in &displayid.blocks {
+        displayid_data_block(block, displayid.version >= 2, index, &mut payload)?;
+    }
+    check_len(index, "DisplayID section", payload.len(), 121)?;
+
+    let mut section = vec![
+        displayid.version << 4 | (displayid.revision & 0xf),
+        payload.len() as u8,
+        displayid.product_type,
+        displayid.extension_count,
+    ];
+    section.extend_from_slice(&payload);
+    let sum = section.iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+    section.push(sum.wrapping_neg());
+
+    let mut block = vec![Extension::DISPLAY_ID];
+    block.extend_from_slice(&section);
+    Ok(finish_block(block))
+}
+
+fn block_map_block(map: &BlockMap, index: usize) -> Result<Vec<u8>, EncodeError> {
+    check_len(index, "block map tags", map.tags.len(), 126)?;
+    let mut block = vec![Extension::BLOCK_MAP];
+    block.extend_from_slice(&map.tags);
+    Ok(finish_block(block))
+}
+
+/// Encodes extension block `index`, the base block being 0, out of
+/// `count` extensions.
+pub(crate) fn extension_block(
+    extension: &Extension,
+    index: usize,
+    count: usize,
+) -> Result<Vec<u8>, EncodeError> {
+    #[cfg(not(feature = "cta"))]
+    let _ = count;
+    Ok(match extension {
+        #[cfg(feature = "cta")]
+        Extension::Cta(cta) => cta_block(cta, index, count as u8)?,
+        #[cfg(feature = "vtb")]
+        Extension::Vtb(vtb) => vtb_block(vtb, index)?,
+        #[cfg(feature = "di-ext")]
+        Extension::DiExt(di_ext) => di_ext_block(di_ext, index)?,
+        Extension::LsExt(ls_ext) => ls_ext_block(ls_ext, index)?,
+        #[cfg(feature = "displayid")]
+        Extension::DisplayId(displayid) => displayid_block(displayid, index)?,
+        Extension::BlockMap(map) => block_map_block(map, index)?,
+        Extension::Unknown { tag, payload } => {
+            check_len(index, "extension payload", payload.len(), 126)?;
+            let mut block = vec![*tag];
+            block.extend_from_slice(payload);
+            finish_block(block)
+        }
+    })
+}
+
+/// Whether the extension holds an EEODB, overriding the extension count.
+pub(crate) fn has_extension_override(extension: &Extension) -> bool {
+    #[cfg(feature = "cta")]
+    if let Extension::Cta(cta) = extension {
+        return cta.extension_override().is_some();
+    }
+    let _ = extension;
+    false
+}
+
+impl EDID {
+    /// Encodes the base block and the extension blocks, the inverse of
+    /// `parse`.
+    ///
+    /// The extension count and all checksums are computed, as is the count
+    /// of an EEODB, in which case the base block announces a single
+    /// extension, so `checksum_mismatches` is ignored. Bytes the model
+    /// doesn't keep are written as zeros: the chromaticity coordinates and
+    /// the payload of untyped descriptors. Parsing the result gives back an
+    /// equal `EDID` for the fields the model keeps; use
+    /// `PreservedEdid::to_bytes` to keep the other bytes of parsed data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        let count = self.extensions.len();
+        if count > MAX_EXTENSIONS {
+            return Err(EncodeError::TooManyExtensions { count });
+        }
+        let mut data = base_block(self)?;
+        for (i, extension) in self.extensions.iter().enumerate() {
+            data.extend_from_slice(&extension_block(extension, i + 1, count)?);
+        }
+        Ok(data)
+    }
+}
@@ -0,0 +1,182 @@
+use std::fmt;
+
+use nom::{
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    Offset,
+};
+
+use crate::edid::{BLOCK_SIZE, HEADER_MAGIC};
+
+/// Error returned by [`EDID::parse`](crate::EDID::parse) and the other
+/// parse functions.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EdidError {
+    pub kind: EdidErrorKind,
+    /// Byte offset of the failure from the start of the data.
+    pub offset: usize,
+}
+
+/// What an EDID failed on.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EdidErrorKind {
+    /// The data ended before the base block and the extension blocks it
+    /// announces, `expected` and `found` being lengths in bytes.
+    Truncated { expected: usize, found: usize },
+    /// The base block doesn't start with `00 FF FF FF FF FF FF 00`.
+    HeaderMagic { found: [u8; 8] },
+    /// Descriptor `index` of the base block, from 0 to 3.
+    Descriptor { index: usize },
+    /// Extension block `index`, from 0, with its tag byte.
+    Extension { index: usize, tag: u8 },
+    /// The bytes of block `block` don't sum to zero. Only returned by
+    /// [`EDID::parse_strict`](crate::EDID::parse_strict), see also
+    /// [`checksum_mismatches`](crate::checksum_mismatches).
+    Checksum {
+        block: usize,
+        expected: u8,
+        found: u8,
+    },
+    /// More items than the parser accepts, see [`MAX_DATA_BLOCKS`](crate::MAX_DATA_BLOCKS).
+    TooMany { what: &'static str },
+    /// A byte holds a reserved value or sets reserved bits. Only returned
+    /// by `EDID::parse_strict`.
+    InvalidField { field: &'static str, found: u8 },
+    /// Display descriptor `index` of the base block has nonzero reserved
+    /// bytes or a reserved tag. Only returned by `EDID::parse_strict`.
+    DescriptorFlags { index: usize },
+    /// Bytes after the last extension block. Only returned by
+    /// `EDID::parse_strict`.
+    TrailingData { len: usize },
+    /// Any other failure, with what was being parsed when known.
+    Invalid { context: Option<&'static str> },
+}
+
+impl EdidError {
+    /// The block holding the failing byte, the base block being 0.
+    pub fn block(&self) -> usize {
+        self.offset / BLOCK_SIZE
+    }
+
+    /// Converts a nom error for `data` into an owned error.
+    pub(crate) fn from_nom(data: &[u8], err: nom::Err<VerboseError<&[u8]>>) -> Self {
+        let truncated = EdidError {
+            kind: EdidErrorKind::Truncated {
+                expected: expected_len(data),
+                found: data.len(),
+            },
+            offset: data.len(),
+        };
+        // Only the limits in `limits.rs` fail without backtracking
+        let (err, limit) = match err {
+            nom::Err::Incomplete(_) => return truncated,
+            nom::Err::Error(err) => (err, false),
+            nom::Err::Failure(err) => (err, true),
+        };
+
+        if data.len() >= HEADER_MAGIC.len() && data[..8] != HEADER_MAGIC {
+            let mut found = [0; 8];
+            found.copy_from_slice(&data[..8]);
+            return EdidError {
+                kind: EdidErrorKind::HeaderMagic { found },
+                offset: 0,
+            };
+        }
+
+        // The first entry is the innermost failure
+        let (offset, kind) = match err.errors.first() {
+            Some((input, kind)) => (offset_in(data, input), kind),
+            None => (0, &VerboseErrorKind::Nom(ErrorKind::Fail)),
+        };
+        if offset >= data.len() || *kind == VerboseErrorKind::Nom(ErrorKind::Eof) {
+            return truncated;
+        }
+
+        let context = err.errors.iter().find_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(*context),
+            _ => None,
+        });
+        let kind = match (context, offset) {
+            (Some(what), _) if limit => EdidErrorKind::TooMany { what },
+            (_, 54..=125) => EdidErrorKind::Descriptor {
+                index: (offset - 54) / 18,
+            },
+            (_, BLOCK_SIZE..) => EdidErrorKind::Extension {
+                index: offset / BLOCK_SIZE - 1,
+                tag: data[offset / BLOCK_SIZE * BLOCK_SIZE],
+            },
+            (context, _) => EdidErrorKind::Invalid { context },
+        };
+        EdidError { kind, offset }
+    }
+}
+
+/// The offset of `input` in `data`. Extensions parse sub-slices of their
+/// block, so the remaining length doesn't give the offset.
+fn offset_in(data: &[u8], input: &[u8]) -> usize {
+    let range = data.as_ptr_range();
+    if range.contains(&input.as_ptr()) || input.as_ptr() == range.end {
+        data.offset(input)
+    } else {
+        data.len().saturating_sub(input.len())
+    }
+}
+
+/// The size of the base block and the extension blocks it announces.
+fn expected_len(data: &[u8]) -> usize {
+    match data.get(126) {
+        Some(count) => BLOCK_SIZE * (1 + *count as usize),
+        None => BLOCK_SIZE,
+    }
+}
+
+impl fmt::Display for EdidErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EdidErrorKind::Truncated { expected, found } => write!(
+                f,
+                "EDID data is truncated, expected {} bytes, found {}",
+                expected, found
+            ),
+            EdidErrorKind::HeaderMagic { found } => write!(
+                f,
+                "invalid header, expected 00 FF FF FF FF FF FF 00, found {:02X?}",
+                found
+            ),
+            EdidErrorKind::Descriptor { index } => write!(f, "invalid descriptor {}", index),
+            EdidErrorKind::Extension { index, tag } => {
+                write!(f, "invalid extension block {} with tag {:#04x}", index, tag)
+            }
+            EdidErrorKind::Checksum {
+                block,
+                expected,
+                found,
+            } => write!(
+                f,
+                "invalid checksum in block {}, expected {:#04x}, found {:#04x}",
+                block, expected, found
+            ),
+            EdidErrorKind::TooMany { what } => write!(f, "too many {}", what),
+            EdidErrorKind::InvalidField { field, found } => {
+                write!(f, "invalid {} {:#04x}", field, found)
+            }
+            EdidErrorKind::DescriptorFlags { index } => {
+                write!(f, "reserved bytes set in descriptor {}", index)
+            }
+            EdidErrorKind::TrailingData { len } => {
+                write!(f, "{} bytes after the last block", len)
+            }
+            EdidErrorKind::Invalid {
+                context: Some(context),
+            } => write!(f, "invalid {}", context),
+            EdidErrorKind::Invalid { context: None } => write!(f, "invalid EDID data"),
+        }
+    }
+}
+
+impl fmt::Display for EdidError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for EdidError {}
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::EDID, error::*};
+
+    #[test]
+    fn test_parse() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let edid = EDID::parse(d).unwrap();
+        assert_eq!(edid.header.vendor, ['S', 'A', 'M']);
+
+        // Trailing bytes are ignored
+        let mut data = d.to_vec();
+        data.extend_from_slice(&[0; 16]);
+        assert_eq!(EDID::parse(&data), Ok(edid));
+    }
+
+    #[test]
+    fn test_try_from() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let edid: EDID = d[..].try_into().unwrap();
+        assert_eq!(edid, EDID::parse(d).unwrap());
+        assert_eq!(EDID::try_from(d.to_vec()), Ok(edid));
+
+        let err = EDID::try_from(d[..16].to_vec()).unwrap_err();
+        assert!(matches!(err.kind, EdidErrorKind::Truncated { .. }));
+    }
+
+    #[test]
+    fn test_sub_slice_offset() {
+        use nom::error::{ErrorKind, VerboseError, VerboseErrorKind};
+
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.push(0x10);
+        data.resize(256, 0);
+
+        // A failure in the payload of the extension block, short of its end
+        let payload = &data[129..255];
+        let err = nom::Err::Error(VerboseError {
+            errors: vec![(&payload[10..20], VerboseErrorKind::Nom(ErrorKind::Verify))],
+        });
+        assert_eq!(
+            EdidError::from_nom(&data, err),
+            EdidError {
+                kind: EdidErrorKind::Extension {
+                    index: 0,
+                    tag: 0x10
+                },
+                offset: 139,
+            }
+        );
+    }
+
+    #[test]
+    fn test_truncated() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        for len in [0, 100, 200] {
+            let err = EDID::parse(&d[..len]).unwrap_err();
+            let expected = if len > 126 { 256 } else { 128 };
+            assert_eq!(
+                err,
+                EdidError {
+                    kind: EdidErrorKind::Truncated {
+                        expected,
+                        found: len,
+                    },
+                    offset: len,
+                }
+            );
+        }
+        assert_eq!(
+            EDID::parse(&d[..200]).unwrap_err().to_string(),
+            "EDID data is truncated, expected 256 bytes, found 200 at byte 200"
+        );
+    }
+
+    #[test]
+    fn test_header_magic() {
+        let mut data = include_bytes!("../testdata/card0-HDMI-1.bin").to_vec();
+        data[1] = 0x00;
+        let err = EDID::parse(&data).unwrap_err();
+        assert_eq!(
+            err,
+            EdidError {
+                kind: EdidErrorKind::HeaderMagic {
+                    found: [0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00],
+                },
+                offset: 0,
+            }
+        );
+        assert_eq!(err.block(), 0);
+    }
+
+    #[test]
+    fn test_checksum_error() {
+        let mut data = include_bytes!("../testdata/card0-HDMI-1.bin").to_vec();
+        data[128 + 20] ^= 0x01;
+        let check = |data: &[u8]| -> Result<EDID, EdidError> {
+            if let Some(mismatch) = crate::checksum::checksum_mismatches(data).pop() {
+                return Err(mismatch.into());
+            }
+            EDID::parse(data)
+        };
+
+        let err = check(&data).unwrap_err();
+        assert_eq!(err.block(), 1);
+        assert_eq!(err.offset, 255);
+        assert!(matches!(err.kind, EdidErrorKind::Checksum { block: 1, .. }));
+    }
+}
@@ -0,0 +1,72 @@
+use self::Established::{Dmt, Other};
+use crate::{
+    dmt::{dmt_timing, timing, Mode, INTERLACED, NN, NP, PP},
+    edid::DetailedTiming,
+};
+
+/// The raw established timings I and II, bytes 35 to 37 of the base block.
+/// Bits 0 to 6 of the last byte are manufacturer's timings.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EstablishedTimings(pub [u8; 3]);
+
+/// An established timing, either a DMT mode or one of the IBM and Apple
+/// modes DMT doesn't define.
+enum Established {
+    Dmt(u16, u16, u8),
+    Other(Mode),
+}
+
+/// The established timings in bit order, from bit 7 of byte 35.
+const MODES: [Established; 17] = [
+    // 720x400@70
+    Other((28320, [720, 180, 18, 108, 400, 49, 12, 2], NP)),
+    // 720x400@88
+    Other((35500, [720, 180, 18, 108, 400, 49, 12, 2], NP)),
+    Dmt(640, 480, 60),
+    // 640x480@67
+    Other((30240, [640, 224, 64, 64, 480, 45, 3, 3], NN)),
+    Dmt(640, 480, 72),
+    Dmt(640, 480, 75),
+    Dmt(800, 600, 56),
+    Dmt(800, 600, 60),
+    Dmt(800, 600, 72),
+    Dmt(800, 600, 75),
+    // 832x624@75
+    Other((57284, [832, 320, 32, 64, 624, 43, 1, 3], NN)),
+    // 1024x768@87 interlaced, vertical values per field
+    Other((44900, [1024, 240, 8, 176, 384, 24, 0, 4], PP | INTERLACED)),
+    Dmt(1024, 768, 60),
+    Dmt(1024, 768, 70),
+    Dmt(1024, 768, 75),
+    Dmt(1280, 1024, 75),
+    // 1152x870@75
+    Other((100000, [1152, 304, 32, 128, 870, 45, 3, 3], NN)),
+];
+
+impl EstablishedTimings {
+    /// Whether bit `bit` of byte `byte`, counted from byte 35, is set.
+    pub fn contains(&self, byte: usize, bit: u8) -> bool {
+        self.0[byte] & (1 << bit) != 0
+    }
+
+    /// Bits 0 to 6 of byte 37.
+    pub fn manufacturer_timings(&self) -> u8 {
+        self.0[2] & 0x7f
+    }
+
+    /// The timings of the set bits, in bit order. Manufacturer's timings
+    /// aren't known, so they aren't listed.
+    pub fn timings(&self) -> impl Iterator<Item = DetailedTiming> + '_ {
+        MODES
+            .iter()
+            .enumerate()
+            .filter(move |(i, _)| self.contains(i / 8, 7 - (i % 8) as u8))
+            .filter_map(|(_, mode)| match mode {
+                Dmt(width, height, refresh) => dmt_timing(*width, *height, *refresh),
+                Other(mode) => Some(timing(mode)),
+            })
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::parse, established::*};
+
+    #[test]
+    fn test_established_timings() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        let established = edid.established_timing;
+        assert_eq!(established, EstablishedTimings([0xa5, 0x4b, 0x00]));
+        let modes: Vec<_> = established
+            .timings()
+            .map(|timing| {
+                (
+                    timing.horizontal_active_pixels,
+                    timing.vertical_active_lines,
+                    timing.refresh_rate().round(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            modes,
+            [
+                (720, 400, 70.0),
+                (640, 480, 60.0),
+                (640, 480, 75.0),
+                (800, 600, 60.0),
+                (800, 600, 75.0),
+                (1024, 768, 60.0),
+                (1024, 768, 75.0),
+                (1280, 1024, 75.0),
+            ]
+        );
+        let timing = established.timings().nth(5).unwrap();
+        assert_eq!(timing.pixel_clock, 65000);
+        assert_eq!(
+            timing.to_modeline(),
+            "Modeline \"1024x768_60.00\" 65.00 1024 1048 1184 1344 768 771 777 806 -hsync -vsync"
+        );
+
+        // 1024x768 interlaced, manufacturer's timings aren't listed
+        let established = EstablishedTimings([0x00, 0x10, 0x7f]);
+        assert_eq!(established.manufacturer_timings(), 0x7f);
+        let timings: Vec<_> = established.timings().collect();
+        assert_eq!(timings.len(), 1);
+        assert!(timings[0].is_interlaced());
+        assert_eq!(timings[0].refresh_rate().round(), 87.0);
+
+        // Every established timing has a known timing
+        let established = EstablishedTimings([0xff, 0xff, 0x80]);
+        assert_eq!(established.timings().count(), 17);
+    }
+}
@@ -0,0 +1,959 @@
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::take,
+    combinator::{fail, map, rest, verify},
+    error::VerboseError,
+    multi::{length_data, many0},
+    number::complete::{le_u16, le_u8},
+    sequence::{preceded, tuple},
+    IResult,
+};
+
+use crate::{
+    cta::{
+        parse_data_block_header, parse_short_video_descriptor, DataBlockHeader,
+        ShortVideoDescriptor,
+    },
+    extension::{from_name, ParseNameError},
+    hdmi::{parse_hdmi_forum, HdmiForumVsdb},
+};
+
+/// Data block with tag 7, dispatched on its extended tag byte.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ExtendedDataBlock {
+    VideoCapability(VideoCapability),
+    VendorSpecificVideo(VendorSpecificVideo),
+    Colorimetry(Colorimetry),
+    HdrStaticMetadata(HdrStaticMetadata),
+    HdrDynamicMetadata(HdrDynamicMetadata),
+    NativeVideoResolution(NativeVideoResolution),
+    VideoFormatPreference(VideoFormatPreference),
+    Ycbcr420Video(Ycbcr420Video),
+    Ycbcr420CapabilityMap(Ycbcr420CapabilityMap),
+    VendorSpecificAudio(VendorSpecificAudio),
+    RoomConfiguration(RoomConfiguration),
+    SpeakerLocations(SpeakerLocations),
+    InfoFrame(InfoFrame),
+    /// HF-SCDB, the HF-VSDB fields in an extended block.
+    HdmiForumSinkCapability(HdmiForumVsdb),
+    ExtensionOverride(ExtensionOverride),
+    Reserved(ExtendedDataBlockReserved),
+}
+
+impl ExtendedDataBlock {
+    pub const VIDEO_CAPABILITY: u8 = 0;
+    pub const VENDOR_SPECIFIC_VIDEO: u8 = 1;
+    pub const COLORIMETRY: u8 = 5;
+    pub const HDR_STATIC_METADATA: u8 = 6;
+    pub const HDR_DYNAMIC_METADATA: u8 = 7;
+    pub const NATIVE_VIDEO_RESOLUTION: u8 = 8;
+    pub const VIDEO_FORMAT_PREFERENCE: u8 = 13;
+    pub const YCBCR420_VIDEO: u8 = 14;
+    pub const YCBCR420_CAPABILITY_MAP: u8 = 15;
+    pub const VENDOR_SPECIFIC_AUDIO: u8 = 17;
+    pub const ROOM_CONFIGURATION: u8 = 19;
+    pub const SPEAKER_LOCATION: u8 = 20;
+    pub const INFOFRAME: u8 = 32;
+    pub const HDMI_FORUM_EEODB: u8 = 0x78;
+    pub const HDMI_FORUM_SCDB: u8 = 0x79;
+
+    pub fn extended_tag(&self) -> u8 {
+        match self {
+            ExtendedDataBlock::VideoCapability(_) => ExtendedDataBlock::VIDEO_CAPABILITY,
+            ExtendedDataBlock::VendorSpecificVideo(_) => ExtendedDataBlock::VENDOR_SPECIFIC_VIDEO,
+            ExtendedDataBlock::Colorimetry(_) => ExtendedDataBlock::COLORIMETRY,
+            ExtendedDataBlock::HdrStaticMetadata(_) => ExtendedDataBlock::HDR_STATIC_METADATA,
+            ExtendedDataBlock::HdrDynamicMetadata(_) => ExtendedDataBlock::HDR_DYNAMIC_METADATA,
+            ExtendedDataBlock::NativeVideoResolution(_) => {
+                ExtendedDataBlock::NATIVE_VIDEO_RESOLUTION
+            }
+            ExtendedDataBlock::VideoFormatPreference(_) => {
+                ExtendedDataBlock::VIDEO_FORMAT_PREFERENCE
+            }
+            ExtendedDataBlock::Ycbcr420Video(_) => ExtendedDataBlock::YCBCR420_VIDEO,
+            ExtendedDataBlock::Ycbcr420CapabilityMap(_) => {
+                ExtendedDataBlock::YCBCR420_CAPABILITY_MAP
+            }
+            ExtendedDataBlock::VendorSpecificAudio(_) => ExtendedDataBlock::VENDOR_SPECIFIC_AUDIO,
+            ExtendedDataBlock::RoomConfiguration(_) => ExtendedDataBlock::ROOM_CONFIGURATION,
+            ExtendedDataBlock::SpeakerLocations(_) => ExtendedDataBlock::SPEAKER_LOCATION,
+            ExtendedDataBlock::InfoFrame(_) => ExtendedDataBlock::INFOFRAME,
+            ExtendedDataBlock::HdmiForumSinkCapability(_) => ExtendedDataBlock::HDMI_FORUM_SCDB,
+            ExtendedDataBlock::ExtensionOverride(_) => ExtendedDataBlock::HDMI_FORUM_EEODB,
+            ExtendedDataBlock::Reserved(block) => block.extended_tag,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExtendedDataBlockReserved {
+    pub header: DataBlockHeader,
+    pub extended_tag: u8,
+    /// Payload following the extended tag byte.
+    pub payload: Vec<u8>,
+}
+
+/// Over/underscan behavior of a class of video formats.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ScanBehavior {
+    /// Formats not supported. For preferred formats: no data, refer to the
+    /// IT and CE behavior instead.
+    Unsupported,
+    AlwaysOverscanned,
+    AlwaysUnderscanned,
+    /// Supports both over- and underscan.
+    Selectable,
+}
+
+impl ScanBehavior {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b00 => ScanBehavior::Unsupported,
+            0b01 => ScanBehavior::AlwaysOverscanned,
+            0b10 => ScanBehavior::AlwaysUnderscanned,
+            _ => ScanBehavior::Selectable,
+        }
+    }
+}
+
+/// Video Capability Data Block (extended tag 0).
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VideoCapability {
+    /// QY: the YCC quantization range can be selected via the AVI InfoFrame.
+    pub ycc_quantization_selectable: bool,
+    /// QS: the RGB quantization range can be selected via the AVI InfoFrame.
+    pub rgb_quantization_selectable: bool,
+    /// PT: behavior for the preferred video format.
+    pub preferred: ScanBehavior,
+    /// IT: behavior for IT video formats.
+    pub it: ScanBehavior,
+    /// CE: behavior for CE video formats.
+    pub ce: ScanBehavior,
+}
+
+fn parse_video_capability(payload: &[u8]) -> IResult<&[u8], VideoCapability, VerboseError<&[u8]>> {
+    let (payload, flags) = le_u8(payload)?;
+    Ok((
+        payload,
+        VideoCapability {
+            ycc_quantization_selectable: flags & 0x80 != 0,
+            rgb_quantization_selectable: flags & 0x40 != 0,
+            preferred: ScanBehavior::from_bits(flags >> 4),
+            it: ScanBehavior::from_bits(flags >> 2),
+            ce: ScanBehavior::from_bits(flags),
+        },
+    ))
+}
+
+/// Vendor-Specific Video Data Block (extended tag 1).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VendorSpecificVideo {
+    /// IEEE OUI, least significant byte first as stored in the block.
+    pub identifier: [u8; 3],
+    pub payload: VendorSpecificVideoPayload,
+}
+
+impl VendorSpecificVideo {
+    pub const HDR10_PLUS_OUI: u32 = 0x90848b;
+
+    pub fn oui(&self) -> u32 {
+        u32::from_le_bytes([
+            self.identifier[0],
+            self.identifier[1],
+            self.identifier[2],
+            0,
+        ])
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VendorSpecificVideoPayload {
+    Hdr10Plus(Hdr10Plus),
+    /// Payload of vendors without a typed decode, following the OUI.
+    Raw(Vec<u8>),
+}
+
+/// HDR10+ (SMPTE ST 2094-40) vendor payload.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Hdr10Plus {
+    pub application_version: u8,
+    pub full_frame_peak_luminance_index: u8,
+    pub peak_luminance_index: u8,
+    /// Bytes following the first payload byte.
+    pub extra: Vec<u8>,
+}
+
+fn parse_vendor_specific_video(
+    payload: &[u8],
+) -> IResult<&[u8], VendorSpecificVideo, VerboseError<&[u8]>> {
+    let (payload, identifier) = take(3u8)(payload)?;
+    let identifier: [u8; 3] = identifier.try_into().unwrap();
+    let mut block = VendorSpecificVideo {
+        identifier,
+        payload: VendorSpecificVideoPayload::Raw(payload.to_vec()),
+    };
+    if block.oui() == VendorSpecificVideo::HDR10_PLUS_OUI {
+        if let Some((flags, extra)) = payload.split_first() {
+            block.payload = VendorSpecificVideoPayload::Hdr10Plus(Hdr10Plus {
+                application_version: flags & 0x3,
+                full_frame_peak_luminance_index: (flags >> 2) & 0x3,
+                peak_luminance_index: flags >> 4,
+                extra: extra.to_vec(),
+            });
+        }
+    }
+    Ok((&payload[payload.len()..], block))
+}
+
+/// Colorimetry Data Block (extended tag 5).
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Colorimetry {
+    /// Supported colorimetry standards.
+    pub colorimetry: u8,
+    /// DCI-P3 support and the supported gamut metadata profiles (MD0-MD3).
+    pub metadata: u8,
+}
+
+impl Colorimetry {
+    // colorimetry bits
+    pub const BT2020_RGB: u8 = (1u8 << 7);
+    pub const BT2020_YCC: u8 = (1u8 << 6);
+    pub const BT2020_CYCC: u8 = (1u8 << 5);
+    pub const OP_RGB: u8 = (1u8 << 4);
+    pub const OP_YCC_601: u8 = (1u8 << 3);
+    pub const SYCC_601: u8 = (1u8 << 2);
+    pub const XVYCC_709: u8 = (1u8 << 1);
+    pub const XVYCC_601: u8 = (1u8 << 0);
+
+    // metadata bits
+    pub const DCI_P3: u8 = (1u8 << 7);
+    pub const MD3: u8 = (1u8 << 3);
+    pub const MD2: u8 = (1u8 << 2);
+    pub const MD1: u8 = (1u8 << 1);
+    pub const MD0: u8 = (1u8 << 0);
+
+    /// Whether any of the BT.2020 colorimetries is supported.
+    pub fn supports_bt2020(&self) -> bool {
+        self.colorimetry & (Self::BT2020_RGB | Self::BT2020_YCC | Self::BT2020_CYCC) != 0
+    }
+
+    pub fn supports_dci_p3(&self) -> bool {
+        self.metadata & Self::DCI_P3 != 0
+    }
+}
+
+fn parse_colorimetry(payload: &[u8]) -> IResult<&[u8], Colorimetry, VerboseError<&[u8]>> {
+    let (payload, colorimetry) = le_u8(payload)?;
+    // The metadata byte is missing in some older blocks
+    let metadata = payload.first().copied().unwrap_or(0);
+    Ok((
+        payload,
+        Colorimetry {
+            colorimetry,
+            metadata,
+        },
+    ))
+}
+
+/// HDR Static Metadata Data Block (extended tag 6).
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HdrStaticMetadata {
+    /// Supported electro-optical transfer functions.
+    pub eotfs: u8,
+    /// Supported static metadata descriptor types.
+    pub static_metadata_descriptors: u8,
+    /// Desired content max luminance code.
+    pub max_luminance: Option<u8>,
+    /// Desired content max frame-average luminance code.
+    pub max_frame_avg_luminance: Option<u8>,
+    /// Desired content min luminance code.
+    pub min_luminance: Option<u8>,
+}
+
+impl HdrStaticMetadata {
+    // EOTF bits
+    pub const EOTF_SDR: u8 = (1u8 << 0); // traditional gamma, SDR luminance range
+    pub const EOTF_HDR: u8 = (1u8 << 1); // traditional gamma, HDR luminance range
+    pub const EOTF_PQ: u8 = (1u8 << 2); // SMPTE ST 2084, used by HDR10
+    pub const EOTF_HLG: u8 = (1u8 << 3); // hybrid log-gamma
+
+    // static metadata descriptor bits
+    pub const STATIC_METADATA_TYPE1: u8 = (1u8 << 0);
+
+    /// Converts a max or max frame-average luminance code to cd/m².
+    pub fn luminance(code: u8) -> f64 {
+        50.0 * 2f64.powf(code as f64 / 32.0)
+    }
+
+    /// Converts a min luminance code to cd/m², which is relative to the max
+    /// luminance.
+    pub fn min_luminance_from(max_code: u8, min_code: u8) -> f64 {
+        Self::luminance(max_code) * (min_code as f64 / 255.0).powi(2) / 100.0
+    }
+
+    pub fn max_luminance_cd_m2(&self) -> Option<f64> {
+        self.max_luminance.map(Self::luminance)
+    }
+
+    pub fn max_frame_avg_luminance_cd_m2(&self) -> Option<f64> {
+        self.max_frame_avg_luminance.map(Self::luminance)
+    }
+
+    pub fn min_luminance_cd_m2(&self) -> Option<f64> {
+        Some(Self::min_luminance_from(
+            self.max_luminance?,
+            self.min_luminance?,
+        ))
+    }
+}
+
+/// Electro-optical transfer function of the HDR Static Metadata Data Block.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Eotf {
+    TraditionalSdr,
+    TraditionalHdr,
+    Pq,
+    Hlg,
+}
+
+impl Eotf {
+    pub const ALL: [Eotf; 4] = [
+        Eotf::TraditionalSdr,
+        Eotf::TraditionalHdr,
+        Eotf::Pq,
+        Eotf::Hlg,
+    ];
+
+    /// The bit in `HdrStaticMetadata::eotfs`.
+    pub fn bit(&self) -> u8 {
+        match self {
+            Eotf::TraditionalSdr => HdrStaticMetadata::EOTF_SDR,
+            Eotf::TraditionalHdr => HdrStaticMetadata::EOTF_HDR,
+            Eotf::Pq => HdrStaticMetadata::EOTF_PQ,
+            Eotf::Hlg => HdrStaticMetadata::EOTF_HLG,
+        }
+    }
+
+    /// Stable snake_case identifier, e.g. for config files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Eotf::TraditionalSdr => "traditional_sdr",
+            Eotf::TraditionalHdr => "traditional_hdr",
+            Eotf::Pq => "pq",
+            Eotf::Hlg => "hlg",
+        }
+    }
+}
+
+impl FromStr for Eotf {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_name(&Eotf::ALL, Eotf::as_str, s)
+    }
+}
+
+impl HdrStaticMetadata {
+    pub fn supported_eotfs(&self) -> impl Iterator<Item = Eotf> + '_ {
+        Eotf::ALL
+            .into_iter()
+            .filter(|eotf| self.eotfs & eotf.bit() != 0)
+    }
+}
+
+fn parse_hdr_static_metadata(
+    payload: &[u8],
+) -> IResult<&[u8], HdrStaticMetadata, VerboseError<&[u8]>> {
+    let (rest, eotfs) = le_u8(payload)?;
+    let (rest, static_metadata_descriptors) = le_u8(rest)?;
+    // The luminance values are optional, a zero code means unknown
+    let luminance = |index: usize| rest.get(index).copied().filter(|code| *code != 0);
+    Ok((
+        &rest[rest.len().min(3)..],
+        HdrStaticMetadata {
+            eotfs,
+            static_metadata_descriptors,
+            max_luminance: luminance(0),
+            max_frame_avg_luminance: luminance(1),
+            min_luminance: rest.get(2).copied(),
+        },
+    ))
+}
+
+/// One supported dynamic metadata type.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HdrDynamicMetadataType {
+    pub metadata_type: u16,
+    /// Support flags, the lower 4 bits of the first byte are the version.
+    pub support_flags: Vec<u8>,
+}
+
+impl HdrDynamicMetadataType {
+    pub const SMPTE_ST_2094_10: u16 = 0x0001;
+    pub const ETSI_TS_103_433: u16 = 0x0002;
+    pub const ITU_T_H265: u16 = 0x0003;
+    pub const SMPTE_ST_2094_40: u16 = 0x0004;
+
+    pub fn version(&self) -> u8 {
+        self.support_flags.first().map_or(0, |flags| flags & 0xf)
+    }
+}
+
+/// HDR Dynamic Metadata Data Block (extended tag 7).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HdrDynamicMetadata {
+    pub metadata_types: Vec<HdrDynamicMetadataType>,
+}
+
+fn parse_hdr_dynamic_metadata_type(
+    input: &[u8],
+) -> IResult<&[u8], HdrDynamicMetadataType, VerboseError<&[u8]>> {
+    let (input, data) = length_data(verify(le_u8, |len| *len >= 2))(input)?;
+    let (support_flags, metadata_type) = le_u16(data)?;
+    Ok((
+        input,
+        HdrDynamicMetadataType {
+            metadata_type,
+            support_flags: support_flags.to_vec(),
+        },
+    ))
+}
+
+fn parse_hdr_dynamic_metadata(
+    payload: &[u8],
+) -> IResult<&[u8], HdrDynamicMetadata, VerboseError<&[u8]>> {
+    map(many0(parse_hdr_dynamic_metadata_type), |metadata_types| {
+        HdrDynamicMetadata { metadata_types }
+    })(payload)
+}
+
+/// Short Video Reference, an entry of the Video Format Preference Data Block.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ShortVideoReference {
+    Vic(u8),
+    /// 1-based index of a DTD, counting over the whole EDID.
+    Dtd(u8),
+    /// 1-based index of a DisplayID Type VII or Type X timing.
+    DisplayIdTiming(u8),
+    Reserved(u8),
+}
+
+impl ShortVideoReference {
+    pub fn from_byte(svr: u8) -> Self {
+        match svr {
+            1..=127 | 193..=253 => ShortVideoReference::Vic(svr),
+            129..=144 => ShortVideoReference::Dtd(svr - 128),
+            145..=160 => ShortVideoReference::DisplayIdTiming(svr - 144),
+            _ => ShortVideoReference::Reserved(svr),
+        }
+    }
+}
+
+/// Native Video Resolution Data Block (extended tag 8).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NativeVideoResolution {
+    /// The native video format, `None` if the SVR is 0.
+    pub native: Option<ShortVideoReference>,
+    /// Image size and native resolution flags.
+    pub flags: u8,
+    /// Native horizontal and vertical pixel counts, if given.
+    pub resolution: Option<(u16, u16)>,
+    /// Bytes following the ones decoded above.
+    pub extra: Vec<u8>,
+}
+
+fn parse_native_video_resolution(
+    payload: &[u8],
+) -> IResult<&[u8], NativeVideoResolution, VerboseError<&[u8]>> {
+    let (rest, svr) = le_u8(payload)?;
+    let flags = rest.first().copied().unwrap_or(0);
+    // The resolution fields store the pixel counts minus one
+    let resolution = rest.get(1..5).map(|b| {
+        (
+            u16::from_le_bytes([b[0], b[1]]).wrapping_add(1),
+            u16::from_le_bytes([b[2], b[3]]).wrapping_add(1),
+        )
+    });
+    Ok((
+        &rest[rest.len()..],
+        NativeVideoResolution {
+            native: (svr != 0).then(|| ShortVideoReference::from_byte(svr)),
+            flags,
+            resolution,
+            extra: rest.get(5..).unwrap_or_default().to_vec(),
+        },
+    ))
+}
+
+/// Video Format Preference Data Block (extended tag 13).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VideoFormatPreference {
+    /// Most preferred format first.
+    pub preferences: Vec<ShortVideoReference>,
+}
+
+/// YCbCr 4:2:0 Video Data Block (extended tag 14), listing the formats
+/// only supported with 4:2:0 sampling.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Ycbcr420Video {
+    pub descriptors: Vec<ShortVideoDescriptor>,
+}
+
+impl Ycbcr420Video {
+    pub fn vics(&self) -> impl Iterator<Item = u8> + '_ {
+        self.descriptors.iter().map(|svd| svd.cea861_index)
+    }
+}
+
+/// YCbCr 4:2:0 Capability Map Data Block (extended tag 15), flagging the
+/// SVDs of the Video Data Blocks that also support 4:2:0 sampling.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Ycbcr420CapabilityMap {
+    /// Bit `i` of byte `j` is set if SVD number `8 * j + i` supports 4:2:0.
+    pub bitmap: Vec<u8>,
+}
+
+impl Ycbcr420CapabilityMap {
+    /// Whether the SVD at `index`, counting over all Video Data Blocks of
+    /// the CTA block, supports 4:2:0. An empty map covers all SVDs.
+    pub fn supports(&self, index: usize) -> bool {
+        if self.bitmap.is_empty() {
+            return true;
+        }
+        self.bitmap
+            .get(index / 8)
+            .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+    }
+
+    /// Filters the SVDs, in Video Data Block order, down to the ones
+    /// supporting 4:2:0.
+    pub fn resolve<'a, I>(&self, svds: I) -> Vec<&'a ShortVideoDescriptor>
+    where
+        I: IntoIterator<Item = &'a ShortVideoDescriptor>,
+    {
+        svds.into_iter()
+            .enumerate()
+            .filter(|(i, _)| self.supports(*i))
+            .map(|(_, svd)| svd)
+            .collect()
+    }
+}
+
+/// Vendor-Specific Audio Data Block (extended tag 17).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VendorSpecificAudio {
+    /// IEEE OUI, least significant byte first as stored in the block.
+    pub identifier: [u8; 3],
+    pub payload: VendorSpecificAudioPayload,
+}
+
+impl VendorSpecificAudio {
+    pub const DOLBY_OUI: u32 = 0x00d046;
+
+    pub fn oui(&self) -> u32 {
+        u32::from_le_bytes([
+            self.identifier[0],
+            self.identifier[1],
+            self.identifier[2],
+            0,
+        ])
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum VendorSpecificAudioPayload {
+    Dolby(DolbyAudio),
+    /// Payload of vendors without a typed decode, following the OUI.
+    Raw(Vec<u8>),
+}
+
+/// Dolby vendor payload, advertising Dolby MAT and Atmos capabilities.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DolbyAudio {
+    /// Version field, bits 2:0 of the first byte.
+    pub version: u8,
+    /// The sink only plays back over headphones.
+    pub headphone_only: bool,
+    pub height_speaker_zone: bool,
+    pub surround_speaker_zone: bool,
+    pub center_speaker_zone: bool,
+    /// Decodes Dolby MAT PCM at 48 kHz only, without TrueHD support.
+    pub mat_pcm_48khz_only: bool,
+    /// Bytes following the ones decoded above.
+    pub extra: Vec<u8>,
+}
+
+fn parse_dolby_audio(payload: &[u8]) -> IResult<&[u8], DolbyAudio, VerboseError<&[u8]>> {
+    let (payload, (flags, mat)) = tuple((le_u8, le_u8))(payload)?;
+    Ok((
+        &payload[payload.len()..],
+        DolbyAudio {
+            version: flags & 0x7,
+            headphone_only: flags & 0x80 != 0,
+            height_speaker_zone: flags & 0x40 != 0,
+            surround_speaker_zone: flags & 0x20 != 0,
+            center_speaker_zone: flags & 0x10 != 0,
+            mat_pcm_48khz_only: mat & 0x01 != 0,
+            extra: payload.to_vec(),
+        },
+    ))
+}
+
+fn parse_vendor_specific_audio(
+    payload: &[u8],
+) -> IResult<&[u8], VendorSpecificAudio, VerboseError<&[u8]>> {
+    let (payload, identifier) = take(3u8)(payload)?;
+    let mut block = VendorSpecificAudio {
+        identifier: identifier.try_into().unwrap(),
+        payload: VendorSpecificAudioPayload::Raw(payload.to_vec()),
+    };
+    if block.oui() == VendorSpecificAudio::DOLBY_OUI {
+        if let Ok((_, dolby)) = parse_dolby_audio(payload) {
+            block.payload = VendorSpecificAudioPayload::Dolby(dolby);
+        }
+    }
+    Ok((&payload[payload.len()..], block))
+}
+
+/// A position in the room, each coordinate in units of 1/64 of the room's
+/// maximum along that axis.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RoomPosition {
+    pub x: i8,
+    pub y: i8,
+    pub z: i8,
+}
+
+impl RoomPosition {
+    fn from_bytes(b: &[u8]) -> Self {
+        RoomPosition {
+            x: b[0] as i8,
+            y: b[1] as i8,
+            z: b[2] as i8,
+        }
+    }
+
+    /// The coordinates as fractions of the room maximums.
+    pub fn relative(&self) -> (f32, f32, f32) {
+        (
+            self.x as f32 / 64.0,
+            self.y as f32 / 64.0,
+            self.z as f32 / 64.0,
+        )
+    }
+}
+
+/// Room Configuration Data Block (extended tag 19).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RoomConfiguration {
+    /// Number of speakers, if given.
+    pub speaker_count: Option<u8>,
+    /// Whether a Speaker Location Data Block describes the speakers.
+    pub has_speaker_locations: bool,
+    /// Speaker presence mask, 24 bits.
+    pub speaker_presence: u32,
+    /// Room maximums along X, Y and Z in decimeters.
+    pub max_dm: Option<[u8; 3]>,
+    /// Display position, if the display flag is set.
+    pub display: Option<RoomPosition>,
+}
+
+fn parse_room_configuration(
+    payload: &[u8],
+) -> IResult<&[u8], RoomConfiguration, VerboseError<&[u8]>> {
+    let (rest, (flags, mask)) = tuple((le_u8, take(3u8)))(payload)?;
+    Ok((
+        &rest[rest.len()..],
+        RoomConfiguration {
+            speaker_count: (flags & 0x40 != 0).then_some((flags & 0x1f) + 1),
+            has_speaker_locations: flags & 0x20 != 0,
+            speaker_presence: u32::from_le_bytes([mask[0], mask[1], mask[2], 0]),
+            max_dm: rest.get(..3).map(|max| [max[0], max[1], max[2]]),
+            display: rest
+                .get(3..6)
+                .filter(|_| flags & 0x80 != 0)
+                .map(RoomPosition::from_bytes),
+        },
+    ))
+}
+
+/// Speaker Location Descriptor.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpeakerLocation {
+    /// Channel index, 0-31.
+    pub channel: u8,
+    pub active: bool,
+    /// Speaker ID, as in the speaker presence mask.
+    pub speaker: u8,
+    pub position: Option<RoomPosition>,
+}
+
+/// Speaker Location Data Block (extended tag 20).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpeakerLocations {
+    pub descriptors: Vec<SpeakerLocation>,
+}
+
+fn parse_speaker_location(input: &[u8]) -> IResult<&[u8], SpeakerLocation, VerboseError<&[u8]>> {
+    let (input, (flags, speaker)) = tuple((le_u8, le_u8))(input)?;
+    // Coordinates follow when the COORD flag is set
+    let (input, position) = if flags & 0x40 != 0 {
+        map(take(3u8), |b: &[u8]| Some(RoomPosition::from_bytes(b)))(input)?
+    } else {
+        (input, None)
+    };
+    Ok((
+        input,
+        SpeakerLocation {
+            channel: flags & 0x1f,
+            active: flags & 0x20 != 0,
+            speaker: speaker & 0x1f,
+            position,
+        },
+    ))
+}
+
+/// Short InfoFrame Descriptor.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ShortInfoFrame {
+    /// InfoFrame type code, see the associated consts.
+    pub type_code: u8,
+    /// IEEE OUI of vendor-specific InfoFrames, least significant byte first.
+    pub identifier: Option<[u8; 3]>,
+    pub payload: Vec<u8>,
+}
+
+impl ShortInfoFrame {
+    pub const VENDOR_SPECIFIC: u8 = 0x01;
+    pub const AVI: u8 = 0x02;
+    pub const SOURCE_PRODUCT: u8 = 0x03;
+    pub const AUDIO: u8 = 0x04;
+    pub const MPEG_SOURCE: u8 = 0x05;
+    pub const NTSC_VBI: u8 = 0x06;
+    pub const DYNAMIC_RANGE_MASTERING: u8 = 0x07;
+}
+
+/// InfoFrame Data Block (extended tag 32).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct InfoFrame {
+    /// Number of Vendor-Specific InfoFrames the sink can receive
+    /// simultaneously.
+    pub simultaneous_vsifs: u16,
+    /// Payload of the InfoFrame Processing Descriptor.
+    pub processing: Vec<u8>,
+    /// InfoFrames the sink can process, besides the mandatory ones.
+    pub descriptors: Vec<ShortInfoFrame>,
+}
+
+impl InfoFrame {
+    pub fn supports(&self, type_code: u8) -> bool {
+        self.descriptors.iter().any(|d| d.type_code == type_code)
+    }
+}
+
+fn parse_short_infoframe(input: &[u8]) -> IResult<&[u8], ShortInfoFrame, VerboseError<&[u8]>> {
+    let (input, header) = le_u8(input)?;
+    let type_code = header & 0x1f;
+    let (input, identifier) = if type_code == ShortInfoFrame::VENDOR_SPECIFIC {
+        map(take(3u8), |oui: &[u8]| Some([oui[0], oui[1], oui[2]]))(input)?
+    } else {
+        (input, None)
+    };
+    let (input, payload) = take(header >> 5)(input)?;
+    Ok((
+        input,
+        ShortInfoFrame {
+            type_code,
+            identifier,
+            payload: payload.to_vec(),
+        },
+    ))
+}
+
+fn parse_infoframe(payload: &[u8]) -> IResult<&[u8], InfoFrame, VerboseError<&[u8]>> {
+    let (payload, (header, vsifs)) = tuple((le_u8, le_u8))(payload)?;
+    let (payload, processing) = take(header >> 5)(payload)?;
+    let (payload, descriptors) = many0(parse_short_infoframe)(payload)?;
+    Ok((
+        payload,
+        InfoFrame {
+            simultaneous_vsifs: vsifs as u16 + 1,
+            processing: processing.to_vec(),
+            descriptors,
+        },
+    ))
+}
+
+/// HDMI Forum EDID Extension Override Data Block (extended tag 0x78).
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ExtensionOverride {
+    /// Number of extension blocks, overriding the count of the base block.
+    pub extension_count: u8,
+}
+
+pub(crate) fn parse_extended_data_block(
+    input: &[u8],
+) -> IResult<&[u8], ExtendedDataBlock, VerboseError<&[u8]>> {
+    let (input, header) = parse_data_block_header(input)?;
+    let (input, payload) = take(header.len)(input)?;
+    let (payload, extended_tag) = le_u8(payload)?;
+
+    let typed = match extended_tag {
+        ExtendedDataBlock::VIDEO_CAPABILITY => {
+            map(parse_video_capability, ExtendedDataBlock::VideoCapability)(payload)
+        }
+        ExtendedDataBlock::VENDOR_SPECIFIC_VIDEO => map(
+            parse_vendor_specific_video,
+            ExtendedDataBlock::VendorSpecificVideo,
+        )(payload),
+        ExtendedDataBlock::COLORIMETRY => {
+            map(parse_colorimetry, ExtendedDataBlock::Colorimetry)(payload)
+        }
+        ExtendedDataBlock::HDR_STATIC_METADATA => map(
+            parse_hdr_static_metadata,
+            ExtendedDataBlock::HdrStaticMetadata,
+        )(payload),
+        ExtendedDataBlock::HDR_DYNAMIC_METADATA => map(
+            parse_hdr_dynamic_metadata,
+            ExtendedDataBlock::HdrDynamicMetadata,
+        )(payload),
+        ExtendedDataBlock::NATIVE_VIDEO_RESOLUTION => map(
+            parse_native_video_resolution,
+            ExtendedDataBlock::NativeVideoResolution,
+        )(payload),
+        ExtendedDataBlock::VIDEO_FORMAT_PREFERENCE => map(rest, |svrs: &[u8]| {
+            ExtendedDataBlock::VideoFormatPreference(VideoFormatPreference {
+                preferences: svrs
+                    .iter()
+                    .copied()
+                    .map(ShortVideoReference::from_byte)
+                    .collect(),
+            })
+        })(payload),
+        ExtendedDataBlock::YCBCR420_VIDEO => {
+            map(many0(parse_short_video_descriptor), |descriptors| {
+                ExtendedDataBlock::Ycbcr420Video(Ycbcr420Video { descriptors })
+            })(payload)
+        }
+        ExtendedDataBlock::YCBCR420_CAPABILITY_MAP => map(rest, |bitmap: &[u8]| {
+            ExtendedDataBlock::Ycbcr420CapabilityMap(Ycbcr420CapabilityMap {
+                bitmap: bitmap.to_vec(),
+            })
+        })(payload),
+        ExtendedDataBlock::VENDOR_SPECIFIC_AUDIO => map(
+            parse_vendor_specific_audio,
+            ExtendedDataBlock::VendorSpecificAudio,
+        )(payload),
+        ExtendedDataBlock::ROOM_CONFIGURATION => map(
+            parse_room_configuration,
+            ExtendedDataBlock::RoomConfiguration,
+        )(payload),
+        ExtendedDataBlock::SPEAKER_LOCATION => map(many0(parse_speaker_location), |descriptors| {
+            ExtendedDataBlock::SpeakerLocations(SpeakerLocations { descriptors })
+        })(payload),
+        ExtendedDataBlock::INFOFRAME => map(parse_infoframe, ExtendedDataBlock::InfoFrame)(payload),
+        ExtendedDataBlock::HDMI_FORUM_EEODB => map(le_u8, |extension_count| {
+            ExtendedDataBlock::ExtensionOverride(ExtensionOverride { extension_count })
+        })(payload),
+        // Two reserved bytes take the place of the HF-VSDB OUI
+        ExtendedDataBlock::HDMI_FORUM_SCDB => map(
+            preceded(take(2u8), parse_hdmi_forum),
+            ExtendedDataBlock::HdmiForumSinkCapability,
+        )(payload),
+        _ => fail(payload),
+    };
+
+    // Unknown blocks and blocks too short for their extended tag are kept
+    // as reserved
+    let block = match typed {
+        Ok((_, block)) => block,
+        Err(_) => ExtendedDataBlock::Reserved(ExtendedDataBlockReserved {
+            header,
+            extended_tag,
+            payload: payload.to_vec(),
+        }),
+    };
+    Ok((input, block))
+}
@@ -1,332 +1,230 @@
+use std::{fmt, str::FromStr};
+
 use nom::{
-    bytes::complete::{tag, take},
-    combinator::{map, not, peek},
-    error::{context, VerboseError},
-    multi::many0,
+    bytes::complete::take,
+    combinator::{map, peek},
+    error::VerboseError,
     number::complete::le_u8,
-    sequence::tuple,
     IResult,
 };
 
-use crate::edid::{parse_detailed_timing, DetailedTiming};
-
-#[derive(Debug, PartialEq, Copy, Clone, Default)]
-pub struct NativeDTDs {
-    pub underscan: u8,
-    pub basic_audio: u8,
-    pub ycbcr444: u8,
-    pub ycbcr422: u8,
-    pub number_of_native_dtd: u8,
-}
-
-fn parse_native_dtds(input: &[u8]) -> IResult<&[u8], NativeDTDs, VerboseError<&[u8]>> {
-    let (input, v) = le_u8(input)?;
-    Ok((
-        input,
-        NativeDTDs {
-            underscan: (v & 0x80u8) >> 7,
-            basic_audio: (v & 0x40u8) >> 6,
-            ycbcr444: (v & 0x20u8) >> 5,
-            ycbcr422: (v & 0x10u8) >> 4,
-            number_of_native_dtd: v & 0xfu8,
-        },
-    ))
-}
-
-#[derive(Debug, PartialEq, Clone)]
-pub struct DataBlockHeader {
-    pub type_tag: u8,
-    pub len: u8,
-}
-
-// impl fmt::Display for DataBlockHeader {
-//     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-//         write!(f, "{:03b}{:05b}", self.type_tag, self.len)
-//     }
-// }
-
-fn parse_data_block_header(input: &[u8]) -> IResult<&[u8], DataBlockHeader, VerboseError<&[u8]>> {
-    map(le_u8, |v| DataBlockHeader {
-        type_tag: (v & 0xe0u8) >> 5,
-        len: v & 0x1fu8,
-    })(input)
-}
-
-#[derive(Debug, PartialEq, Clone)]
-pub enum DataBlock {
-    Reserved(DataBlockReserved),
-    AudioBlock(AudioBlock),
-    VideoBlock(VideoBlock),
-    VendorSpecific(VendorSpecific),
-    SpeakerAllocation(SpeakerAllocation),
-}
-
-fn parse_blocks(input: &[u8]) -> IResult<&[u8], Vec<DataBlock>, VerboseError<&[u8]>> {
-    many0(parse_data_block)(input)
-}
+#[cfg(feature = "cta")]
+use crate::cta::{parse_extension, CtaExtensions};
+#[cfg(feature = "di-ext")]
+use crate::di_ext::{parse_di_ext, DiExt};
+#[cfg(feature = "displayid")]
+use crate::displayid::{parse_displayid, DisplayId};
+#[cfg(feature = "vtb")]
+use crate::vtb::{parse_vtb, Vtb};
+use crate::{
+    block_map::{parse_block_map, BlockMap},
+    ls_ext::{parse_ls_ext, LsExt},
+};
 
+/// An extension block, dispatched on its tag byte.
+///
+/// Unknown blocks keep their payload, i.e. the block without the tag and
+/// checksum bytes. So do the blocks of extension families whose feature is
+/// disabled.
 #[derive(Debug, PartialEq, Clone)]
-pub struct AudioBlock {
-    pub header: DataBlockHeader,
-    pub descriptors: Vec<ShortAudioDescriptor>,
-}
-
-#[derive(Debug, PartialEq, Clone, Default)]
-pub struct ShortAudioDescriptor {
-    pub audio_format: u8,
-    pub number_of_channels: u8,
-    pub sampling_frequences: u8,
-    pub format_dependent_value: u8,
-    pub audio_format_extended_code: u8,
-}
-
-// pub enum AudioFormatCode {
-//     RESERVED,
-//     LPCM,
-//     AC3,
-//     MPEG1,
-//     MP3,
-//     MPEG2,
-//     AAC,
-//     DTS,
-//     ATRAC,
-//     DSD,
-//     DDPLUS,
-//     DTSHD,
-//     TRUEHD,
-//     DSTAUDIO,
-//     WMAPRO,
-//     EXTENSION,
-// }
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Extension {
+    #[cfg(feature = "cta")]
+    Cta(CtaExtensions),
+    #[cfg(feature = "vtb")]
+    Vtb(Vtb),
+    #[cfg(feature = "di-ext")]
+    DiExt(DiExt),
+    LsExt(LsExt),
+    #[cfg(feature = "displayid")]
+    DisplayId(DisplayId),
+    BlockMap(BlockMap),
+    Unknown {
+        #[cfg_attr(feature = "arbitrary", arbitrary(with = arbitrary_unknown_tag))]
+        tag: u8,
+        payload: Vec<u8>,
+    },
+}
+
+/// A tag that doesn't parse as one of the typed extensions.
+#[cfg(feature = "arbitrary")]
+fn arbitrary_unknown_tag(u: &mut arbitrary::Unstructured) -> arbitrary::Result<u8> {
+    let tag = u.arbitrary()?;
+    Ok(match ExtensionTag::from_tag(tag) {
+        Some(_) => 0xFF,
+        None => tag,
+    })
+}
+
+impl Extension {
+    pub const CTA: u8 = 0x02;
+    pub const VTB: u8 = 0x10;
+    pub const DI_EXT: u8 = 0x40;
+    pub const LS_EXT: u8 = 0x50;
+    pub const DISPLAY_ID: u8 = 0x70;
+    pub const BLOCK_MAP: u8 = 0xF0;
+
+    pub fn tag(&self) -> u8 {
+        match self {
+            #[cfg(feature = "cta")]
+            Extension::Cta(_) => Extension::CTA,
+            #[cfg(feature = "vtb")]
+            Extension::Vtb(_) => Extension::VTB,
+            #[cfg(feature = "di-ext")]
+            Extension::DiExt(_) => Extension::DI_EXT,
+            Extension::LsExt(_) => Extension::LS_EXT,
+            #[cfg(feature = "displayid")]
+            Extension::DisplayId(_) => Extension::DISPLAY_ID,
+            Extension::BlockMap(_) => Extension::BLOCK_MAP,
+            Extension::Unknown { tag, .. } => *tag,
+        }
+    }
 
-fn parse_audio_block(input: &[u8]) -> IResult<&[u8], AudioBlock, VerboseError<&[u8]>> {
-    context("audio data blocks", |i| {
-        let (i, header) = parse_data_block_header(i)?;
-        let (i, payload) = take(header.len)(i)?;
-        let (_i, descriptors) = many0(map(
-            tuple((le_u8, le_u8, le_u8)),
-            |(format_and_channels, sampling_frequences, bitrate_or_bitdepth)| {
-                ShortAudioDescriptor {
-                    audio_format: (format_and_channels & 0x78u8) >> 3,
-                    number_of_channels: (format_and_channels & 0x7u8) + 1u8,
-                    sampling_frequences,
-                    audio_format_extended_code: (bitrate_or_bitdepth & 0xf8u8) >> 3,
-                    format_dependent_value: bitrate_or_bitdepth & 0x7u8,
-                }
-            },
-        ))(payload)?;
-        Ok((
-            i,
-            AudioBlock {
-                header,
-                descriptors,
-            },
-        ))
-    })(input)
+    /// The extension family, `None` for unknown tags.
+    pub fn extension_tag(&self) -> Option<ExtensionTag> {
+        ExtensionTag::from_tag(self.tag())
+    }
 }
 
+/// Returned by the `FromStr` impls of name-mapped enums, e.g. `ExtensionTag`.
 #[derive(Debug, PartialEq, Clone)]
-pub struct ShortVideoDescriptor {
-    pub is_native: u8,
-    pub cea861_index: u8,
+pub struct ParseNameError {
+    pub name: String,
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct VideoBlock {
-    pub header: DataBlockHeader,
-    pub descriptors: Vec<ShortVideoDescriptor>,
+impl fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown name `{}`", self.name)
+    }
 }
 
-fn parse_video_block(input: &[u8]) -> IResult<&[u8], VideoBlock, VerboseError<&[u8]>> {
-    context("video data blocks", |i| {
-        let (i, header) = parse_data_block_header(i)?;
-        let (i, payload) = take(header.len)(i)?;
-        let (_i, descriptors) = many0(map(le_u8, |payload| ShortVideoDescriptor {
-            is_native: (payload & 0x80u8) >> 7,
-            cea861_index: payload & 0x7fu8,
-        }))(payload)?;
-        Ok((
-            i,
-            VideoBlock {
-                header,
-                descriptors,
-            },
-        ))
-    })(input)
-}
+impl std::error::Error for ParseNameError {}
+
+/// Finds the variant whose `as_str()` is `name`.
+pub(crate) fn from_name<T: Copy>(
+    all: &[T],
+    as_str: fn(&T) -> &'static str,
+    name: &str,
+) -> Result<T, ParseNameError> {
+    all.iter()
+        .find(|v| as_str(v) == name)
+        .copied()
+        .ok_or_else(|| ParseNameError {
+            name: name.to_string(),
+        })
+}
+
+/// Known extension block families, also when their feature is disabled.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum ExtensionTag {
+    Cta,
+    Vtb,
+    DiExt,
+    LsExt,
+    DisplayId,
+    BlockMap,
+}
+
+impl ExtensionTag {
+    pub const ALL: [ExtensionTag; 6] = [
+        ExtensionTag::Cta,
+        ExtensionTag::Vtb,
+        ExtensionTag::DiExt,
+        ExtensionTag::LsExt,
+        ExtensionTag::DisplayId,
+        ExtensionTag::BlockMap,
+    ];
+
+    pub fn from_tag(tag: u8) -> Option<Self> {
+        ExtensionTag::ALL.into_iter().find(|t| t.tag() == tag)
+    }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct VendorSpecific {
-    pub header: DataBlockHeader,
-    pub identifier: [u8; 3],
-    pub payload: Vec<u8>,
-}
+    pub fn tag(&self) -> u8 {
+        match self {
+            ExtensionTag::Cta => Extension::CTA,
+            ExtensionTag::Vtb => Extension::VTB,
+            ExtensionTag::DiExt => Extension::DI_EXT,
+            ExtensionTag::LsExt => Extension::LS_EXT,
+            ExtensionTag::DisplayId => Extension::DISPLAY_ID,
+            ExtensionTag::BlockMap => Extension::BLOCK_MAP,
+        }
+    }
 
-fn parse_vendor_specific(input: &[u8]) -> IResult<&[u8], VendorSpecific, VerboseError<&[u8]>> {
-    context("vendor specific data block", |i| {
-        let (i, header) = parse_data_block_header(i)?;
-        let (i, payload) = take(header.len)(i)?;
-        let (payload, identifier) = take(3u8)(payload)?;
-        let (_i, payload) = take(header.len - 3)(payload)?;
-        Ok((
-            i,
-            VendorSpecific {
-                header,
-                identifier: identifier.try_into().unwrap(),
-                payload: Vec::from(payload), // payload 类型由编译器推断
-            },
-        ))
-    })(input)
+    /// Stable snake_case identifier, e.g. for config files.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExtensionTag::Cta => "cta",
+            ExtensionTag::Vtb => "vtb",
+            ExtensionTag::DiExt => "di_ext",
+            ExtensionTag::LsExt => "ls_ext",
+            ExtensionTag::DisplayId => "displayid",
+            ExtensionTag::BlockMap => "block_map",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct SpeakerAllocation {
-    pub header: DataBlockHeader,
-    pub speakers: u8,
-    pub reserved: [u8; 2],
-}
+impl FromStr for ExtensionTag {
+    type Err = ParseNameError;
 
-impl SpeakerAllocation {
-    pub const REAR_LEFT_RIGHT_CENTER: u8 = (1u8 << 6);
-    pub const FRONT_LEFT_RIGHT_CENTER: u8 = (1u8 << 5);
-    pub const REAR_CENTER: u8 = (1u8 << 4);
-    pub const REAR_LEFT_RIGHT: u8 = (1u8 << 3);
-    pub const FRONT_CENTER: u8 = (1u8 << 2);
-    pub const LFE: u8 = (1u8 << 1);
-    pub const FRONT_LEFT_RIGHT: u8 = (1u8 << 0);
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_name(&ExtensionTag::ALL, ExtensionTag::as_str, s)
+    }
 }
 
-fn parse_speaker_allocation(
+pub(crate) fn parse_extension_block(
     input: &[u8],
-) -> IResult<&[u8], SpeakerAllocation, VerboseError<&[u8]>> {
-    context("speaker allocation data block", |i| {
-        let (i, header) = parse_data_block_header(i)?;
-        let (i, payload) = take(header.len)(i)?;
-        let (payload, speakers) = take(1u8)(payload)?;
-        let (_i, reserved) = take(2u8)(payload)?;
-        Ok((
-            i,
-            SpeakerAllocation {
-                header,
-                speakers: speakers[0],
-                reserved: [reserved[0], reserved[1]],
-            },
-        ))
-    })(input)
+) -> IResult<&[u8], Extension, VerboseError<&[u8]>> {
+    match input.first() {
+        // A malformed section doesn't fail the whole EDID
+        #[cfg(feature = "displayid")]
+        Some(&Extension::DISPLAY_ID) => or_unknown(parse_typed_extension)(input),
+        _ => parse_typed_extension(input),
+    }
 }
 
-fn parse_data_block_reserved(
+/// Parses an extension block by its tag, failing if a block with a known
+/// tag is malformed.
+pub(crate) fn parse_typed_extension(
     input: &[u8],
-) -> IResult<&[u8], DataBlockReserved, VerboseError<&[u8]>> {
-    let (input, header) = parse_data_block_header(input)?;
-    let (input, payload) = take(header.len)(input)?;
-
-    Ok((
-        input,
-        DataBlockReserved {
-            header,
-            payload: payload.to_vec(),
-        },
-    ))
-}
-
-fn parse_data_block(input: &[u8]) -> IResult<&[u8], DataBlock, VerboseError<&[u8]>> {
-    let (remaining, header) = peek(parse_data_block_header)(input)?;
-    // println!("data block type: {:?}", header.type_tag);
-    // println!("data block len: {:?}", header.len);
-    match header.type_tag {
-        0b001 => map(parse_audio_block, |v| DataBlock::AudioBlock(v))(remaining),
-        0b010 => map(parse_video_block, |v| DataBlock::VideoBlock(v))(remaining),
-        0b011 => map(parse_vendor_specific, |v| DataBlock::VendorSpecific(v))(remaining),
-        0b100 => map(parse_speaker_allocation, |v| {
-            DataBlock::SpeakerAllocation(v)
-        })(remaining),
-        // 0b101 => map(parse_audio_block, |v| DataBlock::AudioBlock(v))(input),
-        // 0b110 => map(parse_audio_block, |v| DataBlock::AudioBlock(v))(input),
-        // 0b111 => map(parse_audio_block, |v| DataBlock::AudioBlock(v))(input),
-        // _ => Ok((
-        //     remaining,
-        //     DataBlock::Reserved(DataBlockReserved {
-        //         header,
-        //         payload: Vec::from(block_data),
-        //     }),
-        // )),
-        _ => map(parse_data_block_reserved, |v| DataBlock::Reserved(v))(remaining),
+) -> IResult<&[u8], Extension, VerboseError<&[u8]>> {
+    let (_, tag) = peek(le_u8)(input)?;
+    match tag {
+        #[cfg(feature = "cta")]
+        Extension::CTA => map(parse_extension, Extension::Cta)(input),
+        #[cfg(feature = "vtb")]
+        Extension::VTB => map(parse_vtb, Extension::Vtb)(input),
+        #[cfg(feature = "di-ext")]
+        Extension::DI_EXT => map(parse_di_ext, Extension::DiExt)(input),
+        Extension::LS_EXT => map(parse_ls_ext, Extension::LsExt)(input),
+        #[cfg(feature = "displayid")]
+        Extension::DISPLAY_ID => map(parse_displayid, Extension::DisplayId)(input),
+        Extension::BLOCK_MAP => map(parse_block_map, Extension::BlockMap)(input),
+        _ => parse_unknown_block(input),
     }
 }
 
-#[derive(Debug, PartialEq, Clone)]
-pub struct DataBlockReserved {
-    pub header: DataBlockHeader,
-    pub payload: Vec<u8>,
-}
-
-
-#[derive(Debug, PartialEq, Clone, Default)]
-pub struct CtaExtensions {
-    pub extension_tag: u8,
-    pub reserved: u8,
-    pub native_dtd: NativeDTDs,
-    pub blocks: Vec<DataBlock>,
-    pub descriptors: Vec<DetailedTiming>,
-}
-
-impl CtaExtensions {
-    // native DTD information bits
-    pub const DTD_UNDERSCAN: u8 = (1u8 << 7); // display supports underscan
-    pub const DTD_BASIC_AUDIO: u8 = (1u8 << 6); // display supports basic audio
-    pub const DTD_YUV444: u8 = (1u8 << 5); // display supports YCbCr 4∶4∶4
-    pub const DTD_YUV422: u8 = (1u8 << 4); // display supports YCbCr 4∶2∶2
-}
-
-fn parse_descriptors(input: &[u8]) -> IResult<&[u8], Vec<DetailedTiming>, VerboseError<&[u8]>> {    
-    many0(map(
-        tuple((
-            peek(not(tag(&[0, 0]))),
-            take(18u8),
-        )),
-        | (_, data)| {
-            let (_, detailed_timing) =  parse_detailed_timing(data).unwrap();
-            detailed_timing
-        },
-    ))(input)
+fn parse_unknown_block(input: &[u8]) -> IResult<&[u8], Extension, VerboseError<&[u8]>> {
+    map(take(128u8), |block: &[u8]| Extension::Unknown {
+        tag: block[0],
+        payload: block[1..127].to_vec(),
+    })(input)
 }
 
-pub(crate) fn parse_extension(input: &[u8]) -> IResult<&[u8], CtaExtensions, VerboseError<&[u8]>> {
-    let (input, (extension_tag, reserved, dtd_flag)) = tuple((le_u8, le_u8, le_u8))(input)?;
-    if dtd_flag == 0 {
-        return Ok((
-            &input[128..],
-            CtaExtensions {
-                extension_tag,
-                reserved,
-                blocks: Vec::new(),
-                descriptors: Vec::new(),
-                ..Default::default()
-            },
-        ));
+/// Runs the parser of a typed extension, keeping a complete block whose
+/// content doesn't parse as `Extension::Unknown`.
+pub(crate) fn or_unknown<'a, F>(
+    mut f: F,
+) -> impl FnMut(&'a [u8]) -> IResult<&'a [u8], Extension, VerboseError<&'a [u8]>>
+where
+    F: FnMut(&'a [u8]) -> IResult<&'a [u8], Extension, VerboseError<&'a [u8]>>,
+{
+    move |input| match f(input) {
+        Err(_) if input.len() >= 128 => parse_unknown_block(input),
+        result => result,
     }
-    println!("dtd_flag: {:?}", dtd_flag);
-
-    let (input, native_dtd) = parse_native_dtds(input)?;
-    let (input, extension_data) = take(dtd_flag - 4)(input)?;
-    let (_, data_block) = parse_blocks(extension_data)?;
-    let (input, detailed_timing_data) = take(input.len() as u8 -1 )(input)?;
-    let (_, detailed_timing) = parse_descriptors(detailed_timing_data)?;
-
-    let (input, _checksum) = le_u8(input)?;
-
-    println!("input[{:b}]", _checksum);
-
-    Ok((
-        input,
-        CtaExtensions {
-            extension_tag,
-            reserved,
-            native_dtd,
-            blocks: data_block,
-            descriptors: detailed_timing,
-            ..Default::default()
-        },
-    ))
 }
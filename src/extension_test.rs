@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use crate::{edid::*, extension::*};
+    use crate::{
+        block_map::*, builder::*, cta::*, di_ext::*, edid::*, established::*, extended::*,
+        extension::*, ls_ext::*, vtb::*,
+    };
 
     fn test(d: &[u8], expected: &EDID) {
         match parse(d) {
@@ -36,8 +39,24 @@ mod tests {
                 features: 234,
             },
             chromaticity: (),
-            established_timing: (),
-            standard_timing: (),
+            established_timing: EstablishedTimings([0xa5, 0x4b, 0x00]),
+            standard_timing: vec![
+                StandardTiming {
+                    horizontal_active_pixels: 1152,
+                    aspect_ratio: AspectRatio::R4_3,
+                    refresh_rate: 75,
+                },
+                StandardTiming {
+                    horizontal_active_pixels: 1280,
+                    aspect_ratio: AspectRatio::R5_4,
+                    refresh_rate: 60,
+                },
+                StandardTiming {
+                    horizontal_active_pixels: 1920,
+                    aspect_ratio: AspectRatio::R16_9,
+                    refresh_rate: 60,
+                },
+            ],
             descriptors: vec![
                 Descriptor::DetailedTiming(DetailedTiming {
                     pixel_clock: 148500,
@@ -57,9 +76,17 @@ mod tests {
                 }),
                 Descriptor::SerialNumber("67Y4J34A0EYQ".to_string()),
                 Descriptor::ProductName("DELL S2440L".to_string()),
-                Descriptor::RangeLimits,
+                Descriptor::RangeLimits(RangeLimits {
+                    min_vertical_rate: 56,
+                    max_vertical_rate: 76,
+                    min_horizontal_rate: 30,
+                    max_horizontal_rate: 83,
+                    max_pixel_clock: 170000,
+                    video_timing_support: 0,
+                    video_timing_data: [10, 32, 32, 32, 32, 32, 32],
+                }),
             ],
-            extensions: Some(CtaExtensions {
+            extensions: vec![Extension::Cta(CtaExtensions {
                 extension_tag: 2,
                 reserved: 3,
                 native_dtd: NativeDTDs {
@@ -152,8 +179,7 @@ mod tests {
                             type_tag: 4,
                             len: 3,
                         },
-                        speakers: 1,
-                        reserved: [0, 0],
+                        speakers: Speakers::FL_FR,
                     }),
                 ],
                 descriptors: vec![
@@ -222,9 +248,872 @@ mod tests {
                         features: 24,
                     },
                 ],
-            }),
+            })],
+            checksum_mismatches: vec![],
         };
 
         test(d, &expected);
     }
+
+    #[test]
+    fn test_multiple_extensions() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut data = d.to_vec();
+        data.extend_from_slice(&d[128..]);
+        data[126] = 2;
+
+        let (remaining, parsed) = parse(&data).unwrap();
+        assert_eq!(remaining.len(), 0);
+        assert_eq!(parsed.extensions.len(), 2);
+        assert_eq!(parsed.extensions[0], parsed.extensions[1]);
+    }
+
+    #[test]
+    fn test_unknown_extension() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut data = d.to_vec();
+        data[128] = 0x42;
+
+        let (remaining, parsed) = parse(&data).unwrap();
+        assert_eq!(remaining.len(), 0);
+        assert_eq!(
+            parsed.extensions,
+            vec![Extension::Unknown {
+                tag: 0x42,
+                payload: d[129..255].to_vec(),
+            }]
+        );
+        assert_eq!(parsed.cta_extensions().count(), 0);
+    }
+
+    #[test]
+    fn test_block_map() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut block_map = [0u8; 128];
+        block_map[0] = Extension::BLOCK_MAP;
+        block_map[1] = Extension::CTA;
+        let mut data = d[..128].to_vec();
+        data.extend_from_slice(&block_map);
+        data.extend_from_slice(&d[128..]);
+        data[126] = 2;
+
+        let (remaining, parsed) = parse(&data).unwrap();
+        assert_eq!(remaining.len(), 0);
+        assert_eq!(
+            parsed.extensions[0],
+            Extension::BlockMap(BlockMap {
+                tags: vec![Extension::CTA],
+            })
+        );
+        assert_eq!(parsed.block_map_mismatches(), vec![]);
+
+        data[129] = Extension::DISPLAY_ID;
+        let (_, parsed) = parse(&data).unwrap();
+        assert_eq!(
+            parsed.block_map_mismatches(),
+            vec![BlockMapMismatch {
+                block: 2,
+                expected: Some(Extension::DISPLAY_ID),
+                found: Some(Extension::CTA),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_insert_remove_extensions() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, mut parsed) = parse(d).unwrap();
+        let unknown = Extension::Unknown {
+            tag: 0x20,
+            payload: vec![0; 126],
+        };
+
+        parsed.insert_extension(0, Extension::BlockMap(BlockMap { tags: vec![] }));
+        parsed.push_extension(unknown.clone());
+        assert_eq!(
+            parsed.extensions[0],
+            Extension::BlockMap(BlockMap {
+                tags: vec![Extension::CTA, 0x20],
+            })
+        );
+
+        let cta = parsed.remove_extension(1);
+        assert_eq!(cta.tag(), Extension::CTA);
+        assert_eq!(
+            parsed.extensions,
+            vec![Extension::BlockMap(BlockMap { tags: vec![0x20] }), unknown]
+        );
+        assert_eq!(parsed.block_map_mismatches(), vec![]);
+    }
+
+    #[test]
+    fn test_di_ext() {
+        let mut block = [0u8; 128];
+        block[0] = Extension::DI_EXT;
+        block[1] = 0x01;
+        block[2] = 0x01;
+        block[3] = 0x10;
+        block[4] = 0x01;
+        block[6] = 0x48;
+        block[0x0E] = 0x21;
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let di_ext = match &parsed.extensions[0] {
+            Extension::DiExt(di_ext) => di_ext,
+            other => panic!("unexpected extension {:?}", other),
+        };
+        assert_eq!(di_ext.version, 1);
+        assert_eq!(di_ext.interface.interface_type, 1);
+        assert_eq!(di_ext.interface.interface_version, 1);
+        assert_eq!(di_ext.interface.interface_release, 0);
+        assert_eq!(di_ext.interface.content_protection, ContentProtection::Hdcp);
+        assert_eq!(
+            di_ext.interface.color_encoding,
+            ColorEncoding::Rgb48HighResolution
+        );
+        assert_eq!(di_ext.interface.bits_per_color, Some(8));
+        assert_eq!(di_ext.interface.pixels_per_clock, Some(2));
+        assert_eq!(di_ext.display_device.technology, 2);
+        assert_eq!(di_ext.display_device.technology_subtype, 1);
+        assert_eq!(di_ext.capabilities.len(), 0x37 - 0x14);
+    }
+
+    #[test]
+    fn test_cta_revision_1() {
+        let hdmi = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut block = vec![Extension::CTA, 0x01, 4, 0];
+        block.extend_from_slice(&hdmi[54..72]);
+        block.resize(128, 0);
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        assert_eq!(cta.reserved, 1);
+        assert!(cta.blocks.is_empty());
+        assert_eq!(cta.native_dtd, NativeDTDs::default());
+        assert_eq!(cta.descriptors.len(), 1);
+        assert_eq!(cta.descriptors[0].horizontal_active_pixels, 1920);
+    }
+
+    #[test]
+    fn test_vtb() {
+        let hdmi = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut block = vec![Extension::VTB, 0x01, 1, 1, 2];
+        block.extend_from_slice(&hdmi[54..72]);
+        // 1920x1200, 16:10, preferring 60 Hz, 60 Hz and 60 Hz RB supported
+        block.extend_from_slice(&[0x57, 0x28, 0x29]);
+        block.extend_from_slice(&[0xd1, 0xc0, 0x01, 0x01]);
+        block.resize(128, 0);
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let vtb = match &parsed.extensions[0] {
+            Extension::Vtb(vtb) => vtb,
+            other => panic!("unexpected extension {:?}", other),
+        };
+        assert_eq!(vtb.version, 1);
+        assert_eq!(vtb.detailed_timings.len(), 1);
+        assert_eq!(vtb.detailed_timings[0].horizontal_active_pixels, 1920);
+        assert_eq!(
+            vtb.cvt_codes,
+            vec![CvtCode {
+                vertical_active_lines: 1200,
+                aspect_ratio: AspectRatio::R16_10,
+                preferred_refresh_rate: 60,
+                supported_refresh_rates: CvtCode::RATE_60HZ | CvtCode::RATE_60HZ_REDUCED_BLANKING,
+            }]
+        );
+        assert_eq!(vtb.cvt_codes[0].horizontal_active_pixels(), 1920);
+        assert_eq!(
+            vtb.standard_timings,
+            vec![StandardTiming {
+                horizontal_active_pixels: 1920,
+                aspect_ratio: AspectRatio::R16_9,
+                refresh_rate: 60,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_vtb_counts_overflow() {
+        let hdmi = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut block = vec![Extension::VTB, 0x01, 255, 255, 255];
+        for _ in 0..6 {
+            block.extend_from_slice(&hdmi[54..72]);
+        }
+        block.resize(128, 0);
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let vtb = match &parsed.extensions[0] {
+            Extension::Vtb(vtb) => vtb,
+            other => panic!("unexpected extension {:?}", other),
+        };
+        // 122 bytes hold six DTDs and the remaining 14 bytes four CVT codes
+        assert_eq!(vtb.detailed_timings.len(), 6);
+        assert_eq!(vtb.cvt_codes.len(), 4);
+        assert_eq!(vtb.standard_timings.len(), 0);
+    }
+
+    #[test]
+    fn test_ls_ext() {
+        let mut block = vec![Extension::LS_EXT, 0x01, 0x00];
+        // UTF-8, country 0, language "DEU"
+        block.extend_from_slice(&[5, 0x00, 0x00, 0x00, 0x10, 0xb5]);
+        block.push(4);
+        block.extend_from_slice(b"Dell");
+        block.push(7);
+        block.extend_from_slice("Größe".as_bytes());
+        block.extend_from_slice(&[3, 0x41, 0xff, 0x42]);
+        block.resize(128, 0);
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let ls_ext = match &parsed.extensions[0] {
+            Extension::LsExt(ls_ext) => ls_ext,
+            other => panic!("unexpected extension {:?}", other),
+        };
+        assert_eq!(ls_ext.version, 1);
+        assert_eq!(ls_ext.tables.len(), 1);
+        let table = &ls_ext.tables[0];
+        assert_eq!(table.encoding, StringEncoding::Utf8);
+        assert_eq!(table.language, ['D', 'E', 'U']);
+        assert_eq!(table.manufacturer_name.raw, b"Dell".to_vec());
+        assert_eq!(table.model_name.to_string_lossy(table.encoding), "Größe");
+        assert_eq!(
+            table.serial_number.to_string_lossy(table.encoding),
+            "A\u{fffd}B"
+        );
+    }
+
+    #[test]
+    fn test_extended_data_blocks() {
+        let d = include_bytes!("../testdata/card0-HDMI-2.bin");
+        let (remaining, parsed) = parse(d).unwrap();
+        assert_eq!(remaining.len(), 0);
+
+        let cta = parsed.cta_extensions().next().unwrap();
+        let extended: Vec<_> = cta
+            .blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::Extended(extended) => Some(extended),
+                _ => None,
+            })
+            .collect();
+        let tags: Vec<_> = extended.iter().map(|b| b.extended_tag()).collect();
+        assert_eq!(tags, vec![0, 5, 15, 6]);
+        assert_eq!(
+            extended[0],
+            &ExtendedDataBlock::VideoCapability(VideoCapability {
+                ycc_quantization_selectable: true,
+                rgb_quantization_selectable: true,
+                preferred: ScanBehavior::AlwaysOverscanned,
+                it: ScanBehavior::AlwaysOverscanned,
+                ce: ScanBehavior::AlwaysOverscanned,
+            })
+        );
+        assert_eq!(
+            extended[1],
+            &ExtendedDataBlock::Colorimetry(Colorimetry {
+                colorimetry: Colorimetry::BT2020_RGB | Colorimetry::BT2020_YCC,
+                metadata: 0,
+            })
+        );
+        if let ExtendedDataBlock::Colorimetry(colorimetry) = extended[1] {
+            assert!(colorimetry.supports_bt2020());
+            assert!(!colorimetry.supports_dci_p3());
+        }
+
+        assert_eq!(
+            extended[2],
+            &ExtendedDataBlock::Ycbcr420CapabilityMap(Ycbcr420CapabilityMap {
+                bitmap: vec![0x00, 0x00, 0x0c, 0x00],
+            })
+        );
+        let vics: Vec<_> = cta
+            .ycbcr420_capable_svds()
+            .iter()
+            .map(|svd| svd.cea861_index)
+            .collect();
+        assert_eq!(vics, vec![96, 97]);
+
+        let all = Ycbcr420CapabilityMap { bitmap: vec![] };
+        assert_eq!(all.resolve(cta.short_video_descriptors()).len(), 30);
+
+        let hdr = match extended[3] {
+            ExtendedDataBlock::HdrStaticMetadata(hdr) => hdr,
+            other => panic!("unexpected block {:?}", other),
+        };
+        assert_eq!(
+            hdr.eotfs,
+            HdrStaticMetadata::EOTF_SDR | HdrStaticMetadata::EOTF_PQ
+        );
+        assert_eq!(
+            hdr.static_metadata_descriptors,
+            HdrStaticMetadata::STATIC_METADATA_TYPE1
+        );
+        assert_eq!(hdr.max_luminance, Some(0x69));
+        assert_eq!(hdr.max_frame_avg_luminance, Some(0x69));
+        assert_eq!(hdr.min_luminance, Some(0x4f));
+        assert_eq!(hdr.max_luminance_cd_m2().unwrap().round(), 486.0);
+        assert_eq!((hdr.min_luminance_cd_m2().unwrap() * 1000.0).round(), 467.0);
+        assert_eq!(HdrStaticMetadata::luminance(0), 50.0);
+    }
+
+    #[test]
+    fn test_cta_revision_2() {
+        let mut data = include_bytes!("../testdata/card0-HDMI-2.bin").to_vec();
+        data[129] = 2;
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        assert_eq!(cta.revision(), 2);
+        assert!(!cta.is_v3());
+
+        // Extended tags are kept as reserved blocks
+        assert!(cta
+            .blocks
+            .iter()
+            .all(|block| !matches!(block, DataBlock::Extended(_))));
+        assert!(cta.blocks.iter().any(|block| matches!(
+            block,
+            DataBlock::Reserved(DataBlockReserved {
+                header: DataBlockHeader { type_tag: 7, .. },
+                ..
+            })
+        )));
+    }
+
+    #[test]
+    fn test_hdr_dynamic_metadata() {
+        // HDR10+ version 1 and ST 2094-10 version 0
+        let block = CtaBuilder::new()
+            .raw_data_block(7, &[7, 3, 0x04, 0x00, 0x01, 2, 0x01, 0x00])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        assert_eq!(
+            cta.blocks,
+            vec![DataBlock::Extended(ExtendedDataBlock::HdrDynamicMetadata(
+                HdrDynamicMetadata {
+                    metadata_types: vec![
+                        HdrDynamicMetadataType {
+                            metadata_type: HdrDynamicMetadataType::SMPTE_ST_2094_40,
+                            support_flags: vec![0x01],
+                        },
+                        HdrDynamicMetadataType {
+                            metadata_type: HdrDynamicMetadataType::SMPTE_ST_2094_10,
+                            support_flags: vec![],
+                        },
+                    ],
+                }
+            ))]
+        );
+        if let DataBlock::Extended(ExtendedDataBlock::HdrDynamicMetadata(hdr)) = &cta.blocks[0] {
+            assert_eq!(hdr.metadata_types[0].version(), 1);
+            assert_eq!(hdr.metadata_types[1].version(), 0);
+        }
+    }
+
+    #[test]
+    fn test_ycbcr420_video() {
+        let block = CtaBuilder::new()
+            .raw_data_block(7, &[14, 0x60, 0x61])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        let vdb = match &cta.blocks[0] {
+            DataBlock::Extended(ExtendedDataBlock::Ycbcr420Video(vdb)) => vdb,
+            other => panic!("unexpected block {:?}", other),
+        };
+        assert_eq!(vdb.vics().collect::<Vec<_>>(), vec![96, 97]);
+        assert_eq!(vdb.descriptors[0].is_native, 0);
+    }
+
+    #[test]
+    fn test_short_video_descriptors() {
+        // Native 1080p60, VICs 65 and 193 without a native flag, reserved 128
+        let block = CtaBuilder::new()
+            .raw_data_block(2, &[0x90, 0x41, 0xc1, 0x80])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        let svds = match &cta.blocks[0] {
+            DataBlock::VideoBlock(video) => &video.descriptors,
+            other => panic!("unexpected block {:?}", other),
+        };
+        let decoded: Vec<_> = svds
+            .iter()
+            .map(|svd| (svd.is_native, svd.cea861_index))
+            .collect();
+        assert_eq!(decoded, vec![(1, 16), (0, 65), (0, 193), (0, 128)]);
+        assert_eq!(svds[2].video_format().unwrap().width, 5120);
+        assert_eq!(svds[3].video_format(), None);
+    }
+
+    #[test]
+    fn test_video_format_preference() {
+        let block = CtaBuilder::new()
+            .raw_data_block(7, &[13, 0x61, 0x81, 0x10, 0x91, 0xc8, 0x00])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        assert_eq!(
+            cta.blocks[0],
+            DataBlock::Extended(ExtendedDataBlock::VideoFormatPreference(
+                VideoFormatPreference {
+                    preferences: vec![
+                        ShortVideoReference::Vic(97),
+                        ShortVideoReference::Dtd(1),
+                        ShortVideoReference::Vic(16),
+                        ShortVideoReference::DisplayIdTiming(1),
+                        ShortVideoReference::Vic(200),
+                        ShortVideoReference::Reserved(0),
+                    ],
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_native_video_resolution() {
+        let block = CtaBuilder::new()
+            .raw_data_block(7, &[8, 0x10, 0x40, 0x7f, 0x07, 0x37, 0x04])
+            .raw_data_block(7, &[8, 0x00])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        assert_eq!(
+            cta.native_video_resolution(),
+            Some(&NativeVideoResolution {
+                native: Some(ShortVideoReference::Vic(16)),
+                flags: 0x40,
+                resolution: Some((1920, 1080)),
+                extra: vec![],
+            })
+        );
+        assert_eq!(
+            cta.blocks[1],
+            DataBlock::Extended(ExtendedDataBlock::NativeVideoResolution(
+                NativeVideoResolution {
+                    native: None,
+                    flags: 0,
+                    resolution: None,
+                    extra: vec![],
+                }
+            ))
+        );
+        assert_eq!(parsed.to_bytes().unwrap()[128..], data[128..]);
+    }
+
+    #[test]
+    fn test_vendor_specific_video() {
+        let block = CtaBuilder::new()
+            // HDR10+ application version 1, peak luminance index 5
+            .raw_data_block(7, &[1, 0x8b, 0x84, 0x90, 0x51])
+            .raw_data_block(7, &[1, 0x46, 0xd0, 0x00, 0x12, 0x34])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        assert_eq!(
+            cta.blocks,
+            vec![
+                DataBlock::Extended(ExtendedDataBlock::VendorSpecificVideo(
+                    VendorSpecificVideo {
+                        identifier: [0x8b, 0x84, 0x90],
+                        payload: VendorSpecificVideoPayload::Hdr10Plus(Hdr10Plus {
+                            application_version: 1,
+                            full_frame_peak_luminance_index: 0,
+                            peak_luminance_index: 5,
+                            extra: vec![],
+                        }),
+                    }
+                )),
+                DataBlock::Extended(ExtendedDataBlock::VendorSpecificVideo(
+                    VendorSpecificVideo {
+                        identifier: [0x46, 0xd0, 0x00],
+                        payload: VendorSpecificVideoPayload::Raw(vec![0x12, 0x34]),
+                    }
+                )),
+            ]
+        );
+        if let DataBlock::Extended(ExtendedDataBlock::VendorSpecificVideo(vsvdb)) = &cta.blocks[1] {
+            assert_eq!(vsvdb.oui(), 0x00d046);
+        }
+    }
+
+    #[test]
+    fn test_vendor_specific_audio() {
+        let block = CtaBuilder::new()
+            // Dolby version 1 with height and surround zones, MAT PCM at 48 kHz only
+            .raw_data_block(7, &[17, 0x46, 0xd0, 0x00, 0x61, 0x01])
+            .raw_data_block(7, &[17, 0x12, 0x34, 0x56, 0x78])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        assert_eq!(
+            cta.blocks,
+            vec![
+                DataBlock::Extended(ExtendedDataBlock::VendorSpecificAudio(
+                    VendorSpecificAudio {
+                        identifier: [0x46, 0xd0, 0x00],
+                        payload: VendorSpecificAudioPayload::Dolby(DolbyAudio {
+                            version: 1,
+                            headphone_only: false,
+                            height_speaker_zone: true,
+                            surround_speaker_zone: true,
+                            center_speaker_zone: false,
+                            mat_pcm_48khz_only: true,
+                            extra: vec![],
+                        }),
+                    }
+                )),
+                DataBlock::Extended(ExtendedDataBlock::VendorSpecificAudio(
+                    VendorSpecificAudio {
+                        identifier: [0x12, 0x34, 0x56],
+                        payload: VendorSpecificAudioPayload::Raw(vec![0x78]),
+                    }
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_room_configuration() {
+        let block = CtaBuilder::new()
+            // Display, 2 speakers with locations, FL/FR, 5x4x3 m room
+            .raw_data_block(7, &[19, 0xe1, 0x03, 0x00, 0x00, 50, 40, 30, 32, 64, 0xe0])
+            // FL at the front left, FR without coordinates
+            .raw_data_block(7, &[20, 0x60, 0x00, 0x00, 0x40, 0x00, 0x21, 0x01])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        let display = RoomPosition {
+            x: 32,
+            y: 64,
+            z: -32,
+        };
+        assert_eq!(
+            cta.blocks,
+            vec![
+                DataBlock::Extended(ExtendedDataBlock::RoomConfiguration(RoomConfiguration {
+                    speaker_count: Some(2),
+                    has_speaker_locations: true,
+                    speaker_presence: 0x3,
+                    max_dm: Some([50, 40, 30]),
+                    display: Some(display),
+                })),
+                DataBlock::Extended(ExtendedDataBlock::SpeakerLocations(SpeakerLocations {
+                    descriptors: vec![
+                        SpeakerLocation {
+                            channel: 0,
+                            active: true,
+                            speaker: 0,
+                            position: Some(RoomPosition { x: 0, y: 64, z: 0 }),
+                        },
+                        SpeakerLocation {
+                            channel: 1,
+                            active: true,
+                            speaker: 1,
+                            position: None,
+                        },
+                    ],
+                })),
+            ]
+        );
+        assert_eq!(display.relative(), (0.5, 1.0, -0.5));
+    }
+
+    #[test]
+    fn test_infoframe() {
+        let block = CtaBuilder::new()
+            // One extra VSIF, no processing payload, HDR10+ VSIF and DRM
+            .raw_data_block(7, &[32, 0x00, 0x01, 0x21, 0x8b, 0x84, 0x90, 0x01, 0x07])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, parsed) = parse(&data).unwrap();
+        let cta = parsed.cta_extensions().next().unwrap();
+        let infoframe = InfoFrame {
+            simultaneous_vsifs: 2,
+            processing: vec![],
+            descriptors: vec![
+                ShortInfoFrame {
+                    type_code: ShortInfoFrame::VENDOR_SPECIFIC,
+                    identifier: Some([0x8b, 0x84, 0x90]),
+                    payload: vec![0x01],
+                },
+                ShortInfoFrame {
+                    type_code: ShortInfoFrame::DYNAMIC_RANGE_MASTERING,
+                    identifier: None,
+                    payload: vec![],
+                },
+            ],
+        };
+        assert!(infoframe.supports(ShortInfoFrame::DYNAMIC_RANGE_MASTERING));
+        assert!(!infoframe.supports(ShortInfoFrame::MPEG_SOURCE));
+        assert_eq!(
+            cta.blocks,
+            vec![DataBlock::Extended(ExtendedDataBlock::InfoFrame(infoframe))]
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        for tag in ExtensionTag::ALL {
+            assert_eq!(tag.as_str().parse(), Ok(tag));
+            assert_eq!(ExtensionTag::from_tag(tag.tag()), Some(tag));
+        }
+        for format in AudioFormat::ALL {
+            assert_eq!(format.as_str().parse(), Ok(format));
+        }
+        for format in ExtendedAudioFormat::ALL {
+            let format = AudioFormat::Extended(Some(format));
+            assert_eq!(format.as_str().parse(), Ok(format));
+        }
+        for eotf in Eotf::ALL {
+            assert_eq!(eotf.as_str().parse(), Ok(eotf));
+        }
+        assert_eq!(
+            "hdr10".parse::<Eotf>(),
+            Err(ParseNameError {
+                name: "hdr10".to_string()
+            })
+        );
+
+        let d = include_bytes!("../testdata/card0-HDMI-2.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(edid.extensions[0].extension_tag(), Some(ExtensionTag::Cta));
+        let cta = edid.cta_extensions().next().unwrap();
+        let hdr = cta
+            .blocks
+            .iter()
+            .find_map(|block| match block {
+                DataBlock::Extended(ExtendedDataBlock::HdrStaticMetadata(hdr)) => Some(hdr),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            hdr.supported_eotfs().collect::<Vec<_>>(),
+            vec![Eotf::TraditionalSdr, Eotf::Pq]
+        );
+
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        let audio = cta
+            .blocks
+            .iter()
+            .find_map(|block| match block {
+                DataBlock::AudioBlock(audio) => Some(audio),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(audio.descriptors[0].format(), AudioFormat::Lpcm);
+        assert_eq!(AudioFormat::Lpcm.as_str(), "lpcm");
+        let speakers = cta
+            .blocks
+            .iter()
+            .find_map(|block| match block {
+                DataBlock::SpeakerAllocation(alloc) => Some(alloc.speakers),
+                _ => None,
+            })
+            .unwrap();
+        assert!(speakers.contains(Speakers::FL_FR));
+        assert_eq!(speakers.iter().next().unwrap().name(), Some("FL/FR"));
+        assert_eq!(AudioFormat::Lpcm.to_string(), "LPCM");
+        assert_eq!(AudioFormat::EnhancedAc3.to_string(), "E-AC-3");
+        assert_eq!(
+            audio.descriptors[0].sampling_rates(),
+            vec![32000, 44100, 48000]
+        );
+        assert_eq!(
+            audio.descriptors[0].format_detail(),
+            FormatDetail::Lpcm {
+                bit_depths: vec![16, 20, 24]
+            }
+        );
+        let ac3 = ShortAudioDescriptor {
+            audio_format: 2,
+            format_dependent_value: 80,
+            ..Default::default()
+        };
+        assert_eq!(ac3.format_detail(), FormatDetail::MaxBitrate(640));
+
+        // AC-4 and a reserved extension type code
+        let block = CtaBuilder::new()
+            .raw_data_block(1, &[0x7d, 0x07, 0x60, 0x7d, 0x07, 0x48])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+        let (_, edid) = parse(&data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        let audio = match &cta.blocks[0] {
+            DataBlock::AudioBlock(audio) => audio,
+            block => panic!("unexpected block {:?}", block),
+        };
+        let ac4 = audio.descriptors[0].format();
+        assert_eq!(ac4, AudioFormat::Extended(Some(ExtendedAudioFormat::Ac4)));
+        assert_eq!(ac4.to_string(), "AC-4");
+        assert_eq!(ac4.as_str(), "ac4");
+        assert_eq!(audio.descriptors[1].format(), AudioFormat::Extended(None));
+        assert_eq!(audio.descriptors[1].format().to_string(), "Extension");
+
+        // Extended positions in the second and third bytes
+        let block = CtaBuilder::new()
+            .raw_data_block(4, &[0x8b, 0x09, 0x02])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+        let (_, edid) = parse(&data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        let speakers = match &cta.blocks[0] {
+            DataBlock::SpeakerAllocation(alloc) => alloc.speakers,
+            block => panic!("unexpected block {:?}", block),
+        };
+        assert!(speakers.contains(Speakers::TPFL_TPFR | Speakers::BTFC));
+        assert!(!speakers.contains(Speakers::FC));
+        let names: Vec<_> = speakers.iter().filter_map(|s| s.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "FL/FR",
+                "LFE1",
+                "BL/BR",
+                "FLw/FRw",
+                "TpFL/TpFR",
+                "LS/RS",
+                "BtFC"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cta_dtd_offsets() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let cta_block = |offset: u8, flags: u8, body: &[u8]| {
+            let mut data = d[..128].to_vec();
+            let mut block = vec![0x02, 0x03, offset, flags];
+            block.extend_from_slice(body);
+            block.resize(128, 0);
+            data.extend_from_slice(&block);
+            let (_, edid) = parse(&data).unwrap();
+            let cta = edid.cta_extensions().next().unwrap().clone();
+            cta
+        };
+
+        // No data blocks nor DTDs, the capability flags still apply
+        let cta = cta_block(0, 0xc0, &[]);
+        assert_eq!(cta.native_dtd.underscan, 1);
+        assert_eq!(cta.native_dtd.basic_audio, 1);
+        assert!(cta.blocks.is_empty() && cta.descriptors.is_empty());
+
+        // DTDs right after the header
+        let dtd = &d[128 + 49..128 + 67];
+        let cta = cta_block(4, 0x40, dtd);
+        assert_eq!(cta.native_dtd.basic_audio, 1);
+        assert!(cta.blocks.is_empty());
+        assert_eq!(cta.descriptors.len(), 1);
+
+        // Invalid offsets don't panic
+        for offset in [1, 3, 128, 255] {
+            cta_block(offset, 0, &[]);
+        }
+    }
+
+    #[test]
+    fn test_extension_override() {
+        let first = CtaBuilder::new()
+            .raw_data_block(7, &[0x78, 2])
+            .build()
+            .unwrap();
+        let second = CtaBuilder::new()
+            .raw_data_block(2, &[0x10])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&first);
+        data.extend_from_slice(&second);
+
+        let (rest, parsed) = parse(&data).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(parsed.extensions.len(), 2);
+        let cta: Vec<_> = parsed.cta_extensions().collect();
+        assert_eq!(cta[0].extension_override(), Some(2));
+        assert_eq!(
+            cta[0].blocks,
+            vec![DataBlock::Extended(ExtendedDataBlock::ExtensionOverride(
+                ExtensionOverride { extension_count: 2 }
+            ))]
+        );
+        assert_eq!(cta[1].extension_override(), None);
+
+        // The overridden count is capped like the base block's
+        let first = CtaBuilder::new()
+            .raw_data_block(7, &[0x78, 200])
+            .build()
+            .unwrap();
+        data.truncate(128);
+        data.extend_from_slice(&first);
+        assert!(parse(&data).is_err());
+    }
 }
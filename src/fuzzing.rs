@@ -0,0 +1,18 @@
+//! Entry points for the fuzz targets in `fuzz/`, not a stable API.
+
+#[cfg(feature = "cta")]
+use crate::cta::{parse_extension, CtaExtensions};
+use crate::edid::{parse_descriptor, Descriptor};
+
+/// Parses a single 128-byte CTA extension block.
+#[cfg(feature = "cta")]
+pub fn parse_cta_extension(data: &[u8]) -> Option<CtaExtensions> {
+    parse_extension(data).ok().map(|(_, cta)| cta)
+}
+
+/// Parses a single 18-byte base block descriptor.
+pub fn parse_base_descriptor(data: &[u8]) -> Option<Descriptor> {
+    parse_descriptor(data)
+        .ok()
+        .map(|(_, descriptor)| descriptor)
+}
@@ -0,0 +1,372 @@
+use nom::{
+    bytes::complete::take,
+    combinator::{all_consuming, cond, map, opt},
+    error::VerboseError,
+    multi::many0,
+    number::complete::{be_u16, le_u8},
+    sequence::tuple,
+    IResult,
+};
+
+use crate::{
+    cta::{CtaExtensions, DataBlock, VendorSpecific},
+    extended::ExtendedDataBlock,
+};
+
+/// HDMI 1.4 Vendor-Specific Data Block, the payload following the HDMI
+/// Licensing OUI.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HdmiVsdb {
+    /// CEC physical address A.B.C.D.
+    pub physical_address: [u8; 4],
+    /// Support flags, see the associated consts.
+    pub flags: u8,
+    /// Maximum TMDS clock in kHz, if indicated.
+    pub max_tmds_clock: Option<u32>,
+    /// Supported content types (CNC0-CNC3), see the `CNC_*` consts.
+    pub content_types: u8,
+    /// Latencies for progressive video formats.
+    pub latency: Option<Latency>,
+    /// Latencies for interlaced video formats.
+    pub interlaced_latency: Option<Latency>,
+    /// Present when the HDMI_Video_present flag is set.
+    pub video: Option<HdmiVideo>,
+}
+
+/// Video and audio latency in milliseconds, `None` if unknown or if the
+/// sink doesn't output video or audio.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Latency {
+    pub video: Option<u16>,
+    pub audio: Option<u16>,
+}
+
+impl Latency {
+    fn from_bytes(video: u8, audio: u8) -> Self {
+        // 0 is unknown and 255 unsupported, the others encode 2 ms steps
+        let ms = |raw: u8| match raw {
+            0 | 255 => None,
+            raw => Some((raw as u16 - 1) * 2),
+        };
+        Latency {
+            video: ms(video),
+            audio: ms(audio),
+        }
+    }
+}
+
+impl HdmiVsdb {
+    pub const SUPPORTS_AI: u8 = (1u8 << 7);
+    pub const DC_48BIT: u8 = (1u8 << 6);
+    pub const DC_36BIT: u8 = (1u8 << 5);
+    pub const DC_30BIT: u8 = (1u8 << 4);
+    pub const DC_Y444: u8 = (1u8 << 3);
+    pub const DVI_DUAL: u8 = (1u8 << 0);
+
+    // content type bits
+    pub const CNC_GRAPHICS: u8 = (1u8 << 0);
+    pub const CNC_PHOTO: u8 = (1u8 << 1);
+    pub const CNC_CINEMA: u8 = (1u8 << 2);
+    pub const CNC_GAME: u8 = (1u8 << 3);
+}
+
+/// HDMI VICs and 3D support of an HDMI 1.4 VSDB.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HdmiVideo {
+    /// Image_Size: how the image size fields are to be interpreted.
+    pub image_size: u8,
+    /// Extended resolution formats, 1 to 4.
+    pub vics: Vec<u8>,
+    /// Whether the mandatory 3D formats are supported.
+    pub s3d_present: bool,
+    /// 3D formats supported by the listed SVDs, see the `S3D_*` consts.
+    pub s3d_structure_all: Option<u16>,
+    /// Bit i selects the i-th SVD for `s3d_structure_all`. All of the first
+    /// 16 SVDs when absent but `s3d_structure_all` isn't.
+    pub s3d_mask: Option<u16>,
+    pub s3d_vics: Vec<Hdmi3dVic>,
+}
+
+impl HdmiVideo {
+    // 3D_Structure_ALL bits
+    pub const S3D_FRAME_PACKING: u16 = (1u16 << 0);
+    pub const S3D_TOP_AND_BOTTOM: u16 = (1u16 << 6);
+    pub const S3D_SIDE_BY_SIDE_HALF: u16 = (1u16 << 8);
+
+    /// The CTA-861 VIC equivalent of an HDMI VIC.
+    pub fn cta_vic(hdmi_vic: u8) -> Option<u8> {
+        match hdmi_vic {
+            1 => Some(95),
+            2 => Some(94),
+            3 => Some(93),
+            4 => Some(98),
+            _ => None,
+        }
+    }
+}
+
+/// 3D format supported by a single SVD.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Hdmi3dVic {
+    /// Index of the SVD, in the order of the video data blocks.
+    pub svd_index: u8,
+    /// 3D_Structure_X, 0 frame packing, 6 top-and-bottom, 8 side-by-side
+    /// (half).
+    pub structure: u8,
+    /// 3D_Detail_X, only present for side-by-side formats.
+    pub detail: Option<u8>,
+}
+
+fn parse_3d_vic(input: &[u8]) -> IResult<&[u8], Hdmi3dVic, VerboseError<&[u8]>> {
+    let (input, vic) = le_u8(input)?;
+    let structure = vic & 0xf;
+    let (input, detail) = cond(structure >= 8, map(le_u8, |detail| detail >> 4))(input)?;
+    Ok((
+        input,
+        Hdmi3dVic {
+            svd_index: vic >> 4,
+            structure,
+            detail,
+        },
+    ))
+}
+
+fn parse_hdmi_video(input: &[u8]) -> IResult<&[u8], HdmiVideo, VerboseError<&[u8]>> {
+    let (input, (flags, lengths)) = tuple((le_u8, le_u8))(input)?;
+    let (input, vics) = take(lengths >> 5)(input)?;
+    let (input, s3d) = take(lengths & 0x1f)(input)?;
+
+    // 3D_Multi_present selects which of the all/mask fields are present
+    let multi = (flags >> 5) & 0b11;
+    let (s3d, s3d_structure_all) = cond(multi == 0b01 || multi == 0b10, be_u16)(s3d)?;
+    let (s3d, s3d_mask) = cond(multi == 0b10, be_u16)(s3d)?;
+    let (_, s3d_vics) = all_consuming(many0(parse_3d_vic))(s3d)?;
+    Ok((
+        input,
+        HdmiVideo {
+            image_size: (flags >> 3) & 0b11,
+            vics: vics.to_vec(),
+            s3d_present: flags & 0x80 != 0,
+            s3d_structure_all,
+            s3d_mask,
+            s3d_vics,
+        },
+    ))
+}
+
+fn parse_hdmi_vsdb(payload: &[u8]) -> IResult<&[u8], HdmiVsdb, VerboseError<&[u8]>> {
+    let (payload, (ab, cd)) = tuple((le_u8, le_u8))(payload)?;
+    // Both bytes are optional, a short block supports none of the features
+    let (payload, flags) = opt(le_u8)(payload)?;
+    let (payload, max_tmds_clock) = opt(map(le_u8, |clock| clock as u32 * 5000))(payload)?;
+    let (payload, latency_flags) = opt(le_u8)(payload)?;
+    let latency_flags = latency_flags.unwrap_or(0);
+    let (payload, latency) = cond(
+        latency_flags & 0x80 != 0,
+        map(tuple((le_u8, le_u8)), |(video, audio)| {
+            Latency::from_bytes(video, audio)
+        }),
+    )(payload)?;
+    // Interlaced latencies are only present along with the progressive ones
+    let (payload, interlaced_latency) = cond(
+        latency.is_some() && latency_flags & 0x40 != 0,
+        map(tuple((le_u8, le_u8)), |(video, audio)| {
+            Latency::from_bytes(video, audio)
+        }),
+    )(payload)?;
+    let (payload, video) = cond(latency_flags & 0x20 != 0, parse_hdmi_video)(payload)?;
+    Ok((
+        payload,
+        HdmiVsdb {
+            physical_address: [ab >> 4, ab & 0xf, cd >> 4, cd & 0xf],
+            flags: flags.unwrap_or(0),
+            max_tmds_clock: max_tmds_clock.filter(|clock| *clock != 0),
+            content_types: latency_flags & 0x0f,
+            latency,
+            interlaced_latency,
+            video,
+        },
+    ))
+}
+
+/// HDMI Forum Vendor-Specific Data Block (HDMI 2.x), the payload following
+/// the HDMI Forum OUI.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HdmiForumVsdb {
+    pub version: u8,
+    /// Maximum TMDS character rate in kHz, if above 340 MHz.
+    pub max_tmds_char_rate: Option<u32>,
+    /// SCDC and 3D flags, see the associated consts.
+    pub flags: u8,
+    /// Max_FRL_Rate code, see `frl_rate`.
+    pub max_frl_rate: u8,
+    /// UHD VICs are supported with the 4:3 and 64:27 aspect ratios.
+    pub uhd_vic: bool,
+    /// Deep color modes in 4:2:0, see the `DC_*_420` consts.
+    pub dc_420: u8,
+    /// HDMI 2.1 features, see the `FEATURE_*` consts.
+    pub features: u8,
+    /// Minimum VRR refresh rate in Hz, if VRR is supported.
+    pub vrr_min: Option<u8>,
+    /// Maximum VRR refresh rate in Hz, if indicated.
+    pub vrr_max: Option<u16>,
+    pub dsc: Option<HdmiDsc>,
+}
+
+impl HdmiForumVsdb {
+    pub const SCDC_PRESENT: u8 = (1u8 << 7);
+    pub const RR_CAPABLE: u8 = (1u8 << 6);
+    pub const CABLE_STATUS: u8 = (1u8 << 5);
+    pub const CCBPCI: u8 = (1u8 << 4);
+    pub const LTE_340MCSC_SCRAMBLE: u8 = (1u8 << 3);
+    pub const INDEPENDENT_VIEW: u8 = (1u8 << 2);
+    pub const DUAL_VIEW: u8 = (1u8 << 1);
+    pub const OSD_DISPARITY: u8 = (1u8 << 0);
+
+    // dc_420 bits
+    pub const DC_48BIT_420: u8 = (1u8 << 2);
+    pub const DC_36BIT_420: u8 = (1u8 << 1);
+    pub const DC_30BIT_420: u8 = (1u8 << 0);
+
+    // features bits
+    pub const FEATURE_FAPA_END_EXTENDED: u8 = (1u8 << 7);
+    pub const FEATURE_QMS: u8 = (1u8 << 6);
+    pub const FEATURE_M_DELTA: u8 = (1u8 << 5);
+    pub const FEATURE_CINEMA_VRR: u8 = (1u8 << 4);
+    pub const FEATURE_NEG_MVRR: u8 = (1u8 << 3);
+    pub const FEATURE_FVA: u8 = (1u8 << 2);
+    pub const FEATURE_ALLM: u8 = (1u8 << 1);
+    pub const FEATURE_FAPA_START_LOCATION: u8 = (1u8 << 0);
+
+    /// Lanes and Gbit/s per lane of a Max_FRL_Rate code, `None` if FRL is
+    /// unsupported or the code reserved.
+    pub fn frl_rate(code: u8) -> Option<(u8, u8)> {
+        match code {
+            1 => Some((3, 3)),
+            2 => Some((3, 6)),
+            3 => Some((4, 6)),
+            4 => Some((4, 8)),
+            5 => Some((4, 10)),
+            6 => Some((4, 12)),
+            _ => None,
+        }
+    }
+}
+
+/// Display Stream Compression capabilities of an HF-VSDB.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct HdmiDsc {
+    /// DSC 1.2a support and supported formats, see the associated consts.
+    pub flags: u8,
+    /// Max_FRL_Rate code when compressed, see `HdmiForumVsdb::frl_rate`.
+    pub max_frl_rate: u8,
+    /// DSC_MaxSlices code.
+    pub max_slices: u8,
+    /// Total chunk buffer size in KiB, minus one.
+    pub total_chunk_kbytes: u8,
+}
+
+impl HdmiDsc {
+    pub const DSC_1P2: u8 = (1u8 << 7);
+    pub const NATIVE_420: u8 = (1u8 << 6);
+    pub const ALL_BPP: u8 = (1u8 << 3);
+    pub const BPC_16: u8 = (1u8 << 2);
+    pub const BPC_12: u8 = (1u8 << 1);
+    pub const BPC_10: u8 = (1u8 << 0);
+}
+
+/// Parses the HF-VSDB fields from the version byte on.
+pub(crate) fn parse_hdmi_forum(
+    payload: &[u8],
+) -> IResult<&[u8], HdmiForumVsdb, VerboseError<&[u8]>> {
+    let (payload, (version, tmds, flags, frl)) = tuple((le_u8, le_u8, le_u8, le_u8))(payload)?;
+    // The HDMI 2.1 bytes are optional
+    let (payload, features) = opt(le_u8)(payload)?;
+    let (payload, vrr) = opt(tuple((le_u8, le_u8)))(payload)?;
+    let (payload, dsc) = opt(tuple((le_u8, le_u8, le_u8)))(payload)?;
+    let (vrr_min, vrr_max) = match vrr {
+        Some((high, low)) => (high & 0x3f, ((high as u16 & 0xc0) << 2) | low as u16),
+        None => (0, 0),
+    };
+    Ok((
+        payload,
+        HdmiForumVsdb {
+            version,
+            max_tmds_char_rate: Some(tmds as u32 * 5000).filter(|rate| *rate != 0),
+            flags,
+            max_frl_rate: frl >> 4,
+            uhd_vic: frl & 0x08 != 0,
+            dc_420: frl & 0x07,
+            features: features.unwrap_or(0),
+            vrr_min: Some(vrr_min).filter(|min| *min != 0),
+            vrr_max: Some(vrr_max).filter(|max| *max != 0),
+            dsc: dsc
+                .filter(|(flags, _, _)| flags & HdmiDsc::DSC_1P2 != 0)
+                .map(|(flags, rates, chunk)| HdmiDsc {
+                    flags,
+                    max_frl_rate: rates >> 4,
+                    max_slices: rates & 0x0f,
+                    total_chunk_kbytes: chunk & 0x3f,
+                }),
+        },
+    ))
+}
+
+impl VendorSpecific {
+    pub const HDMI_OUI: u32 = 0x000c03;
+    pub const HDMI_FORUM_OUI: u32 = 0xc45dd8;
+
+    /// Decodes the payload of HDMI 1.4 blocks, `None` for other vendors or
+    /// if the payload is too short.
+    pub fn hdmi(&self) -> Option<HdmiVsdb> {
+        if self.oui() != VendorSpecific::HDMI_OUI {
+            return None;
+        }
+        parse_hdmi_vsdb(&self.payload).ok().map(|(_, hdmi)| hdmi)
+    }
+
+    /// Decodes the payload of HDMI Forum blocks, `None` for other vendors or
+    /// if the payload is too short.
+    pub fn hdmi_forum(&self) -> Option<HdmiForumVsdb> {
+        if self.oui() != VendorSpecific::HDMI_FORUM_OUI {
+            return None;
+        }
+        parse_hdmi_forum(&self.payload).ok().map(|(_, hf)| hf)
+    }
+}
+
+impl CtaExtensions {
+    /// The HDMI Forum capabilities, from the HF-VSDB or else the HF-SCDB.
+    pub fn hdmi_forum(&self) -> Option<HdmiForumVsdb> {
+        let vsdb = self.blocks.iter().find_map(|block| match block {
+            DataBlock::VendorSpecific(vsdb) => vsdb.hdmi_forum(),
+            _ => None,
+        });
+        vsdb.or_else(|| {
+            self.blocks.iter().find_map(|block| match block {
+                DataBlock::Extended(ExtendedDataBlock::HdmiForumSinkCapability(scdb)) => {
+                    Some(scdb.clone())
+                }
+                _ => None,
+            })
+        })
+    }
+}
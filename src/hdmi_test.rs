@@ -0,0 +1,241 @@
+#[cfg(test)]
+mod tests {
+    use crate::{builder::*, cta::*, edid::*, extended::*, hdmi::*};
+
+    fn vendor_blocks(data: &[u8]) -> Vec<VendorSpecific> {
+        let (_, edid) = parse(data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        cta.blocks
+            .iter()
+            .filter_map(|block| match block {
+                DataBlock::VendorSpecific(vsdb) => Some(vsdb.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_hdmi_vsdb() {
+        let blocks = vendor_blocks(include_bytes!("../testdata/card0-HDMI-2.bin"));
+        assert_eq!(blocks[0].oui(), VendorSpecific::HDMI_OUI);
+        assert_eq!(
+            blocks[0].hdmi(),
+            Some(HdmiVsdb {
+                physical_address: [1, 0, 0, 0],
+                flags: HdmiVsdb::DC_36BIT | HdmiVsdb::DC_30BIT | HdmiVsdb::DC_Y444,
+                max_tmds_clock: Some(600_000),
+                content_types: 0,
+                latency: None,
+                interlaced_latency: None,
+                video: Some(HdmiVideo {
+                    image_size: 0,
+                    vics: vec![1, 2, 3, 4],
+                    s3d_present: false,
+                    s3d_structure_all: None,
+                    s3d_mask: None,
+                    s3d_vics: vec![],
+                }),
+            })
+        );
+        // The HDMI Forum block has another OUI
+        assert_eq!(blocks[1].hdmi(), None);
+        assert_eq!(blocks[0].hdmi_forum(), None);
+        assert_eq!(
+            blocks[1].hdmi_forum(),
+            Some(HdmiForumVsdb {
+                version: 1,
+                max_tmds_char_rate: Some(600_000),
+                flags: HdmiForumVsdb::SCDC_PRESENT | HdmiForumVsdb::LTE_340MCSC_SCRAMBLE,
+                max_frl_rate: 0,
+                uhd_vic: false,
+                dc_420: HdmiForumVsdb::DC_30BIT_420,
+                features: 0,
+                vrr_min: None,
+                vrr_max: None,
+                dsc: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_hdmi_forum_vsdb_21() {
+        let block = CtaBuilder::new()
+            .raw_data_block(
+                3,
+                &[
+                    0xd8, 0x5d, 0xc4, 0x01, 0x00, 0x80, 0x63, 0x02, 0x70, 0x20, 0x8b, 0x57, 0x0f,
+                ],
+            )
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let hf = vendor_blocks(&data)[0].hdmi_forum().unwrap();
+        assert_eq!(hf.max_tmds_char_rate, None);
+        assert_eq!(hf.max_frl_rate, 6);
+        assert_eq!(HdmiForumVsdb::frl_rate(hf.max_frl_rate), Some((4, 12)));
+        assert_eq!(
+            hf.dc_420,
+            HdmiForumVsdb::DC_36BIT_420 | HdmiForumVsdb::DC_30BIT_420
+        );
+        assert_eq!(hf.features, HdmiForumVsdb::FEATURE_ALLM);
+        assert_eq!(hf.vrr_min, Some(48));
+        assert_eq!(hf.vrr_max, Some(288));
+        assert_eq!(
+            hf.dsc,
+            Some(HdmiDsc {
+                flags: HdmiDsc::DSC_1P2 | HdmiDsc::ALL_BPP | HdmiDsc::BPC_12 | HdmiDsc::BPC_10,
+                max_frl_rate: 5,
+                max_slices: 7,
+                total_chunk_kbytes: 15,
+            })
+        );
+        assert_eq!(HdmiForumVsdb::frl_rate(5), Some((4, 10)));
+        assert_eq!(HdmiForumVsdb::frl_rate(7), None);
+    }
+
+    #[test]
+    fn test_hdmi_vsdb_minimal() {
+        let blocks = vendor_blocks(include_bytes!("../testdata/card0-HDMI-1.bin"));
+        assert_eq!(
+            blocks[0].hdmi(),
+            Some(HdmiVsdb {
+                physical_address: [1, 0, 0, 0],
+                flags: 0,
+                max_tmds_clock: None,
+                content_types: 0,
+                latency: None,
+                interlaced_latency: None,
+                video: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_hdmi_vsdb_latency() {
+        let block = CtaBuilder::new()
+            .raw_data_block(
+                3,
+                &[
+                    0x03, 0x0c, 0x00, 0x12, 0x34, 0x80, 0x00, 0xc9, 11, 6, 21, 255,
+                ],
+            )
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let hdmi = vendor_blocks(&data)[0].hdmi().unwrap();
+        assert_eq!(hdmi.physical_address, [1, 2, 3, 4]);
+        assert_eq!(hdmi.flags, HdmiVsdb::SUPPORTS_AI);
+        assert_eq!(
+            hdmi.content_types,
+            HdmiVsdb::CNC_GRAPHICS | HdmiVsdb::CNC_GAME
+        );
+        assert_eq!(
+            hdmi.latency,
+            Some(Latency {
+                video: Some(20),
+                audio: Some(10),
+            })
+        );
+        assert_eq!(
+            hdmi.interlaced_latency,
+            Some(Latency {
+                video: Some(40),
+                audio: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_hdmi_vsdb_3d() {
+        let block = CtaBuilder::new()
+            .raw_data_block(
+                3,
+                &[
+                    0x03, 0x0c, 0x00, 0x10, 0x00, 0x00, 0x00, 0x20, 0xc0, 0x27, 0x01, 0x01, 0x41,
+                    0x00, 0x03, 0x10, 0x28, 0x10,
+                ],
+            )
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let video = vendor_blocks(&data)[0].hdmi().unwrap().video.unwrap();
+        assert_eq!(video.vics, vec![1]);
+        assert_eq!(HdmiVideo::cta_vic(video.vics[0]), Some(95));
+        assert!(video.s3d_present);
+        assert_eq!(
+            video.s3d_structure_all,
+            Some(
+                HdmiVideo::S3D_FRAME_PACKING
+                    | HdmiVideo::S3D_TOP_AND_BOTTOM
+                    | HdmiVideo::S3D_SIDE_BY_SIDE_HALF
+            )
+        );
+        assert_eq!(video.s3d_mask, Some(0x3));
+        assert_eq!(
+            video.s3d_vics,
+            vec![
+                Hdmi3dVic {
+                    svd_index: 1,
+                    structure: 0,
+                    detail: None,
+                },
+                Hdmi3dVic {
+                    svd_index: 2,
+                    structure: 8,
+                    detail: Some(1),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hdmi_forum_scdb() {
+        let block = CtaBuilder::new()
+            .raw_data_block(7, &[0x79, 0x00, 0x00, 0x01, 0x78, 0x80, 0x30])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, edid) = parse(&data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        let expected = HdmiForumVsdb {
+            version: 1,
+            max_tmds_char_rate: Some(600_000),
+            flags: HdmiForumVsdb::SCDC_PRESENT,
+            max_frl_rate: 3,
+            uhd_vic: false,
+            dc_420: 0,
+            features: 0,
+            vrr_min: None,
+            vrr_max: None,
+            dsc: None,
+        };
+        assert_eq!(
+            cta.blocks,
+            vec![DataBlock::Extended(
+                ExtendedDataBlock::HdmiForumSinkCapability(expected.clone())
+            )]
+        );
+        assert_eq!(cta.hdmi_forum(), Some(expected));
+
+        // The HF-VSDB is used when present
+        let d = include_bytes!("../testdata/card0-HDMI-2.bin");
+        let (_, edid) = parse(d).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        assert_eq!(
+            cta.hdmi_forum().unwrap().dc_420,
+            HdmiForumVsdb::DC_30BIT_420
+        );
+    }
+}
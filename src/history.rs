@@ -0,0 +1,159 @@
+use std::{collections::VecDeque, fmt};
+
+use crate::edid::{year_ad, EDID};
+
+/// Hash of the raw EDID bytes, FNV-1a so it's stable across runs and
+/// platforms.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Fingerprint(pub u64);
+
+impl Fingerprint {
+    pub fn of(data: &[u8]) -> Self {
+        let hash = data.iter().fold(0xcbf29ce484222325u64, |hash, b| {
+            (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+        });
+        Fingerprint(hash)
+    }
+}
+
+/// Identity of a monitor, e.g. to key per-monitor settings: unlike
+/// `Fingerprint` it stays the same when a firmware update changes the rest
+/// of the EDID.
+///
+/// Formats as `VEN-product-serial-serial_number-yearWweek`, e.g.
+/// `DEL-a08b-30455951-67Y4J34A0EYQ-2013w15`.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct MonitorFingerprint {
+    pub vendor: [char; 3],
+    pub product: u16,
+    /// The base block serial, or the DisplayID one when it's 0.
+    pub serial: u32,
+    /// Contents of the serial number descriptor, if any.
+    pub serial_number: Option<String>,
+    pub week: u8,
+    pub year: u8,
+}
+
+impl MonitorFingerprint {
+    /// A 64-bit hash of the formatted identity, stable across runs and
+    /// platforms.
+    pub fn to_fingerprint(&self) -> Fingerprint {
+        Fingerprint::of(self.to_string().as_bytes())
+    }
+
+    /// See `Header::year_ad`.
+    pub fn year_ad(&self) -> u16 {
+        year_ad(self.year)
+    }
+}
+
+impl fmt::Display for MonitorFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{:04x}-{:08x}-{}-{}w{}",
+            self.vendor.iter().collect::<String>(),
+            self.product,
+            self.serial,
+            self.serial_number.as_deref().unwrap_or(""),
+            self.year_ad(),
+            self.week
+        )
+    }
+}
+
+impl EDID {
+    /// Identifies the monitor by vendor, product, serial numbers and date of
+    /// manufacture.
+    pub fn fingerprint(&self) -> MonitorFingerprint {
+        let info = self.product_info();
+        MonitorFingerprint {
+            vendor: info.vendor,
+            product: info.product,
+            serial: info.serial,
+            serial_number: info.serial_number,
+            week: info.week,
+            year: info.year,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ConnectorEvent {
+    /// A monitor showed up, on an empty connector or replacing another one.
+    Connected(Fingerprint),
+    /// The same monitor, by vendor, product and serial, now reports a
+    /// different EDID.
+    Changed {
+        from: Fingerprint,
+        to: Fingerprint,
+    },
+    Removed(Fingerprint),
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+struct Reading {
+    fingerprint: Fingerprint,
+    /// Vendor, product and serial bytes of the base block.
+    identity: Option<[u8; 8]>,
+}
+
+/// Tracks the successive EDIDs read on a single connector.
+///
+/// Only changes are stored, at most `capacity` of them, oldest first.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ConnectorHistory {
+    capacity: usize,
+    current: Option<Reading>,
+    events: VecDeque<ConnectorEvent>,
+}
+
+impl ConnectorHistory {
+    pub fn new(capacity: usize) -> Self {
+        ConnectorHistory {
+            capacity,
+            current: None,
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records a read, `None` meaning no EDID could be read, and returns the
+    /// resulting event if anything changed.
+    pub fn record(&mut self, data: Option<&[u8]>) -> Option<ConnectorEvent> {
+        let reading = data.map(|data| Reading {
+            fingerprint: Fingerprint::of(data),
+            identity: data.get(8..16).map(|id| id.try_into().unwrap()),
+        });
+        let event = match (self.current, reading) {
+            (None, None) => None,
+            (Some(old), None) => Some(ConnectorEvent::Removed(old.fingerprint)),
+            (None, Some(new)) => Some(ConnectorEvent::Connected(new.fingerprint)),
+            (Some(old), Some(new)) if old.fingerprint == new.fingerprint => None,
+            (Some(old), Some(new)) if old.identity.is_some() && old.identity == new.identity => {
+                Some(ConnectorEvent::Changed {
+                    from: old.fingerprint,
+                    to: new.fingerprint,
+                })
+            }
+            (Some(_), Some(new)) => Some(ConnectorEvent::Connected(new.fingerprint)),
+        };
+        self.current = reading;
+
+        if let (Some(event), true) = (event, self.capacity > 0) {
+            if self.events.len() == self.capacity {
+                self.events.pop_front();
+            }
+            self.events.push_back(event);
+        }
+        event
+    }
+
+    /// Fingerprint of the EDID currently on the connector.
+    pub fn current(&self) -> Option<Fingerprint> {
+        self.current.map(|reading| reading.fingerprint)
+    }
+
+    pub fn events(&self) -> impl Iterator<Item = &ConnectorEvent> {
+        self.events.iter()
+    }
+}
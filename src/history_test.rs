@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::parse, history::*};
+
+    #[test]
+    fn test_connector_history() {
+        let vga = include_bytes!("../testdata/card0-VGA-1.bin");
+        let hdmi = include_bytes!("../testdata/card0-HDMI-1.bin");
+        // Same monitor, different EDID
+        let mut vga_updated = vga.to_vec();
+        vga_updated[127] = vga_updated[127].wrapping_add(1);
+
+        let vga_fp = Fingerprint::of(vga);
+        let updated_fp = Fingerprint::of(&vga_updated);
+        let hdmi_fp = Fingerprint::of(hdmi);
+        assert_ne!(vga_fp, updated_fp);
+
+        let mut history = ConnectorHistory::new(3);
+        assert_eq!(history.record(None), None);
+        assert_eq!(
+            history.record(Some(vga)),
+            Some(ConnectorEvent::Connected(vga_fp))
+        );
+        assert_eq!(history.record(Some(vga)), None);
+        assert_eq!(
+            history.record(Some(&vga_updated)),
+            Some(ConnectorEvent::Changed {
+                from: vga_fp,
+                to: updated_fp
+            })
+        );
+        assert_eq!(
+            history.record(Some(hdmi)),
+            Some(ConnectorEvent::Connected(hdmi_fp))
+        );
+        assert_eq!(history.current(), Some(hdmi_fp));
+        assert_eq!(history.record(None), Some(ConnectorEvent::Removed(hdmi_fp)));
+        assert_eq!(history.current(), None);
+
+        // The oldest event was dropped
+        assert_eq!(
+            history.events().copied().collect::<Vec<_>>(),
+            vec![
+                ConnectorEvent::Changed {
+                    from: vga_fp,
+                    to: updated_fp
+                },
+                ConnectorEvent::Connected(hdmi_fp),
+                ConnectorEvent::Removed(hdmi_fp),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_monitor_fingerprint() {
+        let data = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(data).unwrap();
+        let fingerprint = edid.fingerprint();
+        assert_eq!(
+            fingerprint.to_string(),
+            "DEL-a08b-30455951-67Y4J34A0EYQ-2013w15"
+        );
+
+        // A firmware update changing the timings keeps the identity
+        let mut updated = data.to_vec();
+        updated[54] = updated[54].wrapping_add(1);
+        let (_, updated) = parse(&updated).unwrap();
+        assert_eq!(updated.fingerprint(), fingerprint);
+        assert_eq!(
+            updated.fingerprint().to_fingerprint(),
+            fingerprint.to_fingerprint()
+        );
+
+        let (_, other) = parse(include_bytes!("../testdata/card0-HDMI-2.bin")).unwrap();
+        assert_ne!(other.fingerprint(), fingerprint);
+    }
+}
@@ -0,0 +1,198 @@
+use std::fmt;
+
+#[cfg(feature = "cta")]
+use crate::cta::DataBlock;
+use crate::{
+    checksum::{checksum_mismatches, ChecksumMismatch},
+    edid::{parse_edid_with, BLOCK_SIZE, EDID, HEADER_MAGIC},
+    error::EdidError,
+    extension::{or_unknown, parse_extension_block, parse_typed_extension, Extension},
+};
+
+/// A defect [`EDID::parse_lenient`] recovered from.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseWarning {
+    /// The header magic was wrong and ignored.
+    HeaderMagic {
+        found: [u8; 8],
+    },
+    /// Fewer extension blocks than the base block announces, in blocks.
+    /// Only the complete ones were parsed.
+    MissingExtensions {
+        expected: usize,
+        found: usize,
+    },
+    /// Bytes after the last complete block were dropped.
+    PartialBlock {
+        len: usize,
+    },
+    Checksum(ChecksumMismatch),
+    /// A CTA data block runs past the data block collection of extension
+    /// block `extension`, it and the following blocks were dropped.
+    TruncatedDataBlock {
+        extension: usize,
+        offset: usize,
+    },
+    /// A CTA vendor-specific data block of extension block `extension` is
+    /// too short for its OUI, it was kept as
+    /// `DataBlock::TruncatedVendorSpecific`.
+    #[cfg(feature = "cta")]
+    TruncatedVendorSpecific {
+        extension: usize,
+        len: usize,
+    },
+    /// Extension block `extension` doesn't parse as the extension its tag
+    /// names, it was kept as `Extension::Unknown`.
+    MalformedExtension {
+        extension: usize,
+        tag: u8,
+    },
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseWarning::HeaderMagic { found } => {
+                write!(f, "invalid header magic {:02X?} ignored", found)
+            }
+            ParseWarning::MissingExtensions { expected, found } => write!(
+                f,
+                "{} extension blocks announced, {} found",
+                expected, found
+            ),
+            ParseWarning::PartialBlock { len } => {
+                write!(f, "{} trailing bytes short of a block dropped", len)
+            }
+            ParseWarning::Checksum(mismatch) => write!(
+                f,
+                "invalid checksum in block {}, expected {:#04x}, found {:#04x}",
+                mismatch.block, mismatch.expected, mismatch.found
+            ),
+            ParseWarning::TruncatedDataBlock { extension, offset } => write!(
+                f,
+                "data block at byte {} of extension block {} is truncated",
+                offset, extension
+            ),
+            #[cfg(feature = "cta")]
+            ParseWarning::TruncatedVendorSpecific { extension, len } => write!(
+                f,
+                "vendor-specific data block of {} bytes in extension block {} is too short for an OUI",
+                len, extension
+            ),
+            ParseWarning::MalformedExtension { extension, tag } => write!(
+                f,
+                "extension block {} with tag {:#04x} is malformed, kept as unknown",
+                extension, tag
+            ),
+        }
+    }
+}
+
+impl EDID {
+    /// Like [`EDID::parse`], recovering from the defects listed in
+    /// [`ParseWarning`] instead of failing. Only an incomplete base block is
+    /// an error, malformed extension blocks are kept as
+    /// `Extension::Unknown`.
+    pub fn parse_lenient(data: &[u8]) -> Result<(EDID, Vec<ParseWarning>), EdidError> {
+        if data.len() < BLOCK_SIZE {
+            return EDID::parse(data).map(|edid| (edid, Vec::new()));
+        }
+
+        // Checksums are those of the blocks as given
+        let mismatches = checksum_mismatches(data);
+        let mut warnings: Vec<_> = mismatches
+            .iter()
+            .copied()
+            .map(ParseWarning::Checksum)
+            .collect();
+        let mut data = data.to_vec();
+        if data[..8] != HEADER_MAGIC {
+            let mut found = [0; 8];
+            found.copy_from_slice(&data[..8]);
+            warnings.push(ParseWarning::HeaderMagic { found });
+            data[..8].copy_from_slice(&HEADER_MAGIC);
+        }
+
+        // Keep the announced blocks that are complete, and no more
+        let announced = data[126] as usize;
+        let complete = data.len() / BLOCK_SIZE - 1;
+        let count = announced.min(complete);
+        if count < announced {
+            warnings.push(ParseWarning::MissingExtensions {
+                expected: announced,
+                found: complete,
+            });
+            let partial = data.len() % BLOCK_SIZE;
+            if partial != 0 {
+                warnings.push(ParseWarning::PartialBlock { len: partial });
+            }
+        }
+        // Blocks past the announced ones may be read through an EEODB
+        data.truncate(data.len() - data.len() % BLOCK_SIZE);
+        data[126] = count as u8;
+
+        let extensions = data[BLOCK_SIZE..].chunks_exact(BLOCK_SIZE).take(count);
+        for (index, block) in extensions.enumerate() {
+            if let Some(offset) = truncated_data_block(block) {
+                warnings.push(ParseWarning::TruncatedDataBlock {
+                    extension: index,
+                    offset,
+                });
+            }
+        }
+
+        let (_, mut edid) =
+            parse_edid_with(&data, |input| or_unknown(parse_extension_block)(input))
+                .map_err(|err| EdidError::from_nom(&data, err))?;
+        edid.checksum_mismatches = mismatches
+            .into_iter()
+            .filter(|mismatch| mismatch.block <= edid.extensions.len())
+            .collect();
+        let blocks = data[BLOCK_SIZE..].chunks_exact(BLOCK_SIZE);
+        for (index, block) in blocks.take(edid.extensions.len()).enumerate() {
+            if parse_typed_extension(block).is_err() {
+                warnings.push(ParseWarning::MalformedExtension {
+                    extension: index,
+                    tag: block[0],
+                });
+            }
+        }
+        #[cfg(feature = "cta")]
+        for (index, extension) in edid.extensions.iter().enumerate() {
+            let blocks = match extension {
+                Extension::Cta(cta) => &cta.blocks,
+                _ => continue,
+            };
+            for block in blocks {
+                if let DataBlock::TruncatedVendorSpecific(block) = block {
+                    warnings.push(ParseWarning::TruncatedVendorSpecific {
+                        extension: index,
+                        len: block.payload.len(),
+                    });
+                }
+            }
+        }
+        Ok((edid, warnings))
+    }
+}
+
+/// The offset of the first CTA data block that overruns the data block
+/// collection, mirroring the bounds `cta::parse_extension` uses.
+fn truncated_data_block(block: &[u8]) -> Option<usize> {
+    if block[0] != Extension::CTA || block[1] < 2 {
+        return None;
+    }
+    let end = match block[2] as usize {
+        offset @ 4..=127 => offset,
+        _ => return None,
+    };
+    let mut offset = 4;
+    while offset < end {
+        let len = (block[offset] & 0x1f) as usize;
+        if offset + 1 + len > end {
+            return Some(offset);
+        }
+        offset += 1 + len;
+    }
+    None
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use crate::{checksum::ChecksumMismatch, edid::EDID, lenient::*};
+
+    #[test]
+    fn test_parse_lenient_clean() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (edid, warnings) = EDID::parse_lenient(d).unwrap();
+        assert_eq!(edid, EDID::parse(d).unwrap());
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn test_parse_lenient_recovers() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut data = d[..200].to_vec();
+        data[0] = 0x01;
+
+        assert!(EDID::parse(&data).is_err());
+        let (edid, warnings) = EDID::parse_lenient(&data).unwrap();
+        assert_eq!(edid.header, EDID::parse(&d[..]).unwrap().header);
+        assert_eq!(edid.extensions, vec![]);
+        assert_eq!(
+            warnings,
+            vec![
+                ParseWarning::Checksum(ChecksumMismatch {
+                    block: 0,
+                    expected: d[127].wrapping_sub(1),
+                    found: d[127],
+                }),
+                ParseWarning::HeaderMagic {
+                    found: [0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00],
+                },
+                ParseWarning::MissingExtensions {
+                    expected: 1,
+                    found: 0,
+                },
+                ParseWarning::PartialBlock { len: 72 },
+            ]
+        );
+        assert_eq!(
+            warnings[2].to_string(),
+            "1 extension blocks announced, 0 found"
+        );
+
+        assert!(EDID::parse_lenient(&d[..100]).is_err());
+    }
+
+    #[test]
+    fn test_truncated_data_block() {
+        let mut data = include_bytes!("../testdata/card0-HDMI-1.bin").to_vec();
+        // Make the first data block run past the DTD offset
+        data[128 + 4] = (data[128 + 4] & 0xe0) | 0x1f;
+        data[128 + 2] = 8;
+
+        let (edid, warnings) = EDID::parse_lenient(&data).unwrap();
+        assert!(warnings.contains(&ParseWarning::TruncatedDataBlock {
+            extension: 0,
+            offset: 4,
+        }));
+        assert_eq!(edid.extensions.len(), 1);
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_truncated_vendor_specific() {
+        use crate::builder::CtaBuilder;
+
+        // A vendor block holding 2 of the 3 OUI bytes
+        let block = CtaBuilder::new()
+            .raw_data_block(3, &[0x03, 0x0c])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data[127] = data[127].wrapping_sub(1);
+        data.extend_from_slice(&block);
+
+        let (_, warnings) = EDID::parse_lenient(&data).unwrap();
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::TruncatedVendorSpecific {
+                extension: 0,
+                len: 2
+            }]
+        );
+        assert_eq!(
+            warnings[0].to_string(),
+            "vendor-specific data block of 2 bytes in extension block 0 is too short for an OUI"
+        );
+    }
+
+    #[cfg(feature = "displayid")]
+    #[test]
+    fn test_malformed_extension() {
+        use crate::extension::Extension;
+
+        // A DisplayID section running past the block
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data[127] = data[127].wrapping_sub(1);
+        data.extend_from_slice(&[Extension::DISPLAY_ID, 0x12, 200, 0, 0]);
+        data.resize(256, 0);
+        let sum = data[128..255]
+            .iter()
+            .fold(0u8, |sum, b| sum.wrapping_add(*b));
+        data[255] = sum.wrapping_neg();
+
+        let (edid, warnings) = EDID::parse_lenient(&data).unwrap();
+        assert_eq!(
+            warnings,
+            vec![ParseWarning::MalformedExtension {
+                extension: 0,
+                tag: Extension::DISPLAY_ID,
+            }]
+        );
+        assert_eq!(edid.extensions[0].tag(), Extension::DISPLAY_ID);
+        assert!(matches!(edid.extensions[0], Extension::Unknown { .. }));
+    }
+}
@@ -1,9 +1,175 @@
+#[cfg(feature = "cta")]
+mod amd;
+#[cfg(all(test, feature = "cta"))]
+mod amd_test;
+mod block_map;
+mod borrowed;
+#[cfg(test)]
+mod borrowed_test;
+#[cfg(feature = "cta")]
+mod builder;
+#[cfg(all(test, feature = "cta"))]
+mod builder_test;
+mod capabilities;
+#[cfg(all(test, feature = "cta"))]
+mod capabilities_test;
+mod checksum;
+#[cfg(test)]
+mod checksum_test;
+mod coverage;
+#[cfg(test)]
+mod coverage_test;
 mod cp437;
+#[cfg(feature = "cta")]
+mod cta;
+#[cfg(feature = "di-ext")]
+mod di_ext;
+mod diff;
+#[cfg(all(test, feature = "cta"))]
+mod diff_test;
+#[cfg(feature = "displayid")]
+mod displayid;
+#[cfg(all(test, feature = "displayid"))]
+mod displayid_test;
+mod dmt;
+#[cfg(feature = "drm")]
+mod drm_mode;
+#[cfg(all(test, feature = "drm", feature = "cta"))]
+mod drm_mode_test;
 mod edid;
 #[cfg(test)]
 mod edid_test;
-mod extension;
+mod encode;
+#[cfg(all(test, feature = "cta", feature = "displayid", feature = "vtb"))]
+mod encode_test;
+mod error;
+#[cfg(test)]
+mod error_test;
+mod established;
 #[cfg(test)]
+mod established_test;
+#[cfg(feature = "cta")]
+mod extended;
+mod extension;
+#[cfg(all(test, feature = "cta", feature = "di-ext", feature = "vtb"))]
 mod extension_test;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
+#[cfg(feature = "cta")]
+mod hdmi;
+#[cfg(all(test, feature = "cta"))]
+mod hdmi_test;
+mod history;
+#[cfg(test)]
+mod history_test;
+mod lenient;
+#[cfg(test)]
+mod lenient_test;
+#[cfg(test)]
+mod libdisplay_info_test;
+mod limits;
+#[cfg(test)]
+mod limits_test;
+mod ls_ext;
+#[cfg(test)]
+mod malformed_test;
+#[cfg(feature = "cta")]
+mod microsoft;
+#[cfg(all(test, feature = "cta"))]
+mod microsoft_test;
+mod mode;
+#[cfg(test)]
+mod mode_test;
+mod pnp;
+#[cfg(test)]
+mod pnp_test;
+pub mod prelude;
+mod preserve;
+#[cfg(test)]
+mod preserve_test;
+mod reader;
+#[cfg(test)]
+mod reader_test;
+#[cfg(all(test, feature = "cta"))]
+mod samples_test;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(all(test, feature = "serde"))]
+mod serialize_test;
+mod strict;
+#[cfg(test)]
+mod strict_test;
+mod text;
+#[cfg(test)]
+mod text_test;
+mod validate;
+#[cfg(all(test, feature = "cta"))]
+mod validate_test;
+mod vic;
+#[cfg(test)]
+mod vic_test;
+mod vrr;
+#[cfg(all(test, feature = "cta"))]
+mod vrr_test;
+#[cfg(feature = "vtb")]
+mod vtb;
 
-pub use edid::{parse, EDID, };
+#[cfg(feature = "cta")]
+pub use amd::FreeSync;
+pub use block_map::{BlockMap, BlockMapMismatch};
+pub use borrowed::{DescriptorRef, EdidRef};
+#[cfg(feature = "cta")]
+pub use builder::{BuildError, CtaBuilder, DetailedTimingBuilder, EdidBuilder};
+pub use capabilities::{CapabilitySource, EdidCapabilities};
+pub use checksum::{checksum_mismatches, fix_checksums, ChecksumMismatch};
+pub use coverage::Coverage;
+#[cfg(feature = "cta")]
+pub use cta::{
+    AudioFormat, CtaExtensions, DataBlock, DataBlockHeader, ExtendedAudioFormat, FormatDetail,
+    ShortAudioDescriptor, SpeakerAllocation, Speakers, VendorSpecific,
+};
+#[cfg(feature = "di-ext")]
+pub use di_ext::{ColorEncoding, ContentProtection, DiExt, DiExtDisplayDevice, DiExtInterface};
+pub use diff::{capability_diff_json, CapabilityDiff, Difference, SetDiff};
+#[cfg(feature = "displayid")]
+pub use displayid::{
+    DisplayId, DisplayIdBlockHeader, DisplayIdBlockReserved, DisplayIdDataBlock, DisplayIdTiming,
+    ProductId, TileBezel, TiledTopology,
+};
+pub use edid::{
+    parse, AspectRatio, Descriptor, DetailedTiming, DigitalInterface, Display, FeatureSupport,
+    Header, JoinedText, ProductInfo, RangeLimits, ScreenSize, StandardTiming, VideoInput, EDID,
+};
+pub use encode::EncodeError;
+pub use error::{EdidError, EdidErrorKind};
+pub use established::EstablishedTimings;
+#[cfg(feature = "cta")]
+pub use extended::{
+    Colorimetry, DolbyAudio, Eotf, ExtendedDataBlock, ExtendedDataBlockReserved, ExtensionOverride,
+    Hdr10Plus, HdrDynamicMetadata, HdrDynamicMetadataType, HdrStaticMetadata, InfoFrame,
+    NativeVideoResolution, RoomConfiguration, RoomPosition, ScanBehavior, ShortInfoFrame,
+    ShortVideoReference, SpeakerLocation, SpeakerLocations, VendorSpecificAudio,
+    VendorSpecificAudioPayload, VendorSpecificVideo, VendorSpecificVideoPayload, VideoCapability,
+    VideoFormatPreference, Ycbcr420CapabilityMap, Ycbcr420Video,
+};
+pub use extension::{Extension, ExtensionTag, ParseNameError};
+#[cfg(feature = "cta")]
+pub use hdmi::{Hdmi3dVic, HdmiDsc, HdmiForumVsdb, HdmiVideo, HdmiVsdb, Latency};
+pub use history::{ConnectorEvent, ConnectorHistory, Fingerprint, MonitorFingerprint};
+pub use lenient::ParseWarning;
+pub use limits::{MAX_DATA_BLOCKS, MAX_EXTENSIONS};
+pub use ls_ext::{LocalizedString, LsExt, StringEncoding, StringTable};
+#[cfg(feature = "cta")]
+pub use microsoft::MicrosoftVsdb;
+pub use mode::{cvt_timing, gtf_timing, GtfParams, TimingSource};
+pub use pnp::{register_vendor_names, vendor_name};
+pub use preserve::{parse_preserved, PreservedEdid};
+pub use reader::{iter_from_reader, EdidReader, ReadError};
+#[cfg(feature = "serde")]
+pub use serialize::{Decoded, JSON_FORMAT_VERSION};
+pub use validate::{Issue, Rule, Severity, Validator};
+pub use vic::{register_video_formats, video_format, VideoFormat};
+pub use vrr::{VrrInfo, VrrRange, VrrSource};
+#[cfg(feature = "vtb")]
+pub use vtb::{CvtCode, Vtb};
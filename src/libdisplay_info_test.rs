@@ -0,0 +1,137 @@
+#[cfg(all(test, feature = "libdisplay-info-compare"))]
+mod tests {
+    //! Runs libdisplay-info's `di-edid-decode` over the EDID corpus and
+    //! reports the fields on which it disagrees with this crate.
+    //!
+    //! The corpus is `testdata/`, or the directory in `EDID_CORPUS`.
+
+    use crate::edid::*;
+    use std::{
+        collections::BTreeMap,
+        env, fs,
+        io::{self, ErrorKind},
+        path::Path,
+        process::Command,
+    };
+
+    /// Fields extracted from the di-edid-decode text output, or the exit
+    /// status and error output if it fails on `path`.
+    fn decode_reference(path: &Path) -> io::Result<Result<BTreeMap<&'static str, String>, String>> {
+        let output = Command::new("di-edid-decode").arg(path).output()?;
+        if !output.status.success() {
+            return Ok(Err(format!(
+                "{}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        let text = String::from_utf8_lossy(&output.stdout);
+
+        let mut fields = BTreeMap::new();
+        let mut dtds = Vec::new();
+        for line in text.lines().map(str::trim) {
+            let value = |prefix: &str| {
+                line.strip_prefix(prefix)
+                    .map(|v| v.trim().trim_matches('\'').to_string())
+            };
+            if let Some(v) = value("EDID Structure Version & Revision:") {
+                fields.insert("version", v);
+            } else if let Some(v) = value("Manufacturer:") {
+                fields.insert("vendor", v);
+            } else if let Some(v) = value("Model:") {
+                fields.insert("product", v);
+            } else if let Some(v) = value("Display Product Name:") {
+                fields.insert("product_name", v);
+            } else if let Some(v) = value("Display Product Serial Number:") {
+                fields.insert("serial_number", v);
+            } else if line.starts_with("DTD ") {
+                if let Some(mode) = line.split_whitespace().nth(2) {
+                    dtds.push(mode.to_string());
+                }
+            }
+        }
+        fields.insert("dtds", dtds.join(" "));
+        Ok(Ok(fields))
+    }
+
+    fn decode_crate(edid: &EDID) -> BTreeMap<&'static str, String> {
+        let info = edid.product_info();
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "version",
+            format!("{}.{}", edid.header.version, edid.header.revision),
+        );
+        fields.insert("vendor", edid.header.vendor_string());
+        fields.insert("product", info.product.to_string());
+        if let Some(name) = info.product_name {
+            fields.insert("product_name", name);
+        }
+        if let Some(serial) = info.serial_number {
+            fields.insert("serial_number", serial);
+        }
+
+        let dtds = edid
+            .descriptors
+            .iter()
+            .filter_map(|d| match d {
+                Descriptor::DetailedTiming(t) => Some(t),
+                _ => None,
+            })
+            .chain(edid.cta_extensions().flat_map(|cta| cta.descriptors.iter()))
+            .map(|t| format!("{}x{}", t.horizontal_active_pixels, t.vertical_active_lines))
+            .collect::<Vec<_>>();
+        fields.insert("dtds", dtds.join(" "));
+        fields
+    }
+
+    #[test]
+    fn compare_with_libdisplay_info() {
+        let dir = env::var("EDID_CORPUS")
+            .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/testdata").to_string());
+
+        let mut disagreements = Vec::new();
+        for entry in fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension() != Some("bin".as_ref()) {
+                continue;
+            }
+
+            // Files the reference rejects say nothing about this crate
+            let reference = match decode_reference(&path) {
+                Ok(Ok(fields)) => fields,
+                Ok(Err(err)) => {
+                    eprintln!("{}: di-edid-decode failed: {}", path.display(), err);
+                    continue;
+                }
+                Err(err) if err.kind() == ErrorKind::NotFound => {
+                    eprintln!("di-edid-decode isn't installed, skipping the comparison");
+                    return;
+                }
+                Err(err) => panic!("running di-edid-decode: {}", err),
+            };
+
+            let data = fs::read(&path).unwrap();
+            let ours = match parse(&data) {
+                Ok((_, edid)) => decode_crate(&edid),
+                Err(err) => {
+                    disagreements.push(format!("{}: parse error: {:?}", path.display(), err));
+                    continue;
+                }
+            };
+            for (field, expected) in reference {
+                let found = ours.get(field).map(String::as_str).unwrap_or("");
+                if found != expected {
+                    disagreements.push(format!(
+                        "{}: {}: libdisplay-info {:?}, edidr {:?}",
+                        path.display(),
+                        field,
+                        expected,
+                        found
+                    ));
+                }
+            }
+        }
+
+        assert!(disagreements.is_empty(), "{}", disagreements.join("\n"));
+    }
+}
@@ -0,0 +1,62 @@
+use nom::{
+    error::{ErrorKind, ParseError, VerboseError, VerboseErrorKind},
+    IResult,
+};
+
+// Bounds on the number of items parsed. Exceeding one fails the parse
+// with a `VerboseErrorKind::Context` error naming the items.
+
+type ParseResult<'a, O> = IResult<&'a [u8], O, VerboseError<&'a [u8]>>;
+
+/// Maximum number of extension blocks, the most the base block or an EEODB
+/// can announce. Encoding more fails.
+pub const MAX_EXTENSIONS: usize = 255;
+/// Sanity bound on the number of data blocks in a CTA extension or
+/// DisplayID section, and of string tables in a Localized String extension.
+/// Block sizes already keep parsed counts within it: the 123 bytes between
+/// the CTA header and the checksum hold at most 123 empty blocks, DisplayID
+/// data blocks take at least 3 bytes and string tables at least 6.
+pub const MAX_DATA_BLOCKS: usize = 123;
+
+pub(crate) fn too_many<'a>(
+    input: &'a [u8],
+    what: &'static str,
+) -> nom::Err<VerboseError<&'a [u8]>> {
+    nom::Err::Failure(VerboseError {
+        errors: vec![(input, VerboseErrorKind::Context(what))],
+    })
+}
+
+/// Like `many0`, but fails once more than `max` items are parsed.
+pub(crate) fn many0_capped<'a, O, F>(
+    max: usize,
+    what: &'static str,
+    mut f: F,
+) -> impl FnMut(&'a [u8]) -> ParseResult<'a, Vec<O>>
+where
+    F: FnMut(&'a [u8]) -> ParseResult<'a, O>,
+{
+    move |mut input| {
+        let mut items = Vec::new();
+        loop {
+            match f(input) {
+                Err(nom::Err::Error(_)) => return Ok((input, items)),
+                Err(err) => return Err(err),
+                Ok((rest, item)) => {
+                    // Parsers not consuming input would loop forever
+                    if rest.len() == input.len() {
+                        return Err(nom::Err::Error(VerboseError::from_error_kind(
+                            input,
+                            ErrorKind::Many0,
+                        )));
+                    }
+                    if items.len() == max {
+                        return Err(too_many(input, what));
+                    }
+                    items.push(item);
+                    input = rest;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::*, limits::*};
+
+    #[test]
+    fn test_max_extensions() {
+        // As many extension blocks as the base block can announce
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = MAX_EXTENSIONS as u8;
+        data.resize(128 * (MAX_EXTENSIONS + 1), 0);
+
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(edid.extensions.len(), MAX_EXTENSIONS);
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_max_data_blocks() {
+        use crate::builder::{BuildError, CtaBuilder};
+
+        let cta = |count| {
+            (0..count)
+                .fold(CtaBuilder::new(), |builder, _| {
+                    builder.raw_data_block(6, &[])
+                })
+                .build()
+        };
+
+        // A CTA block full of empty data blocks is within the cap
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&cta(MAX_DATA_BLOCKS).unwrap());
+        let (_, parsed) = parse(&data).unwrap();
+        assert_eq!(
+            parsed.cta_extensions().next().unwrap().blocks.len(),
+            MAX_DATA_BLOCKS
+        );
+
+        assert_eq!(
+            cta(MAX_DATA_BLOCKS + 1),
+            Err(BuildError::BlockOverflow {
+                len: MAX_DATA_BLOCKS + 1
+            })
+        );
+    }
+}
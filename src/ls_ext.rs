@@ -0,0 +1,142 @@
+use nom::{
+    bytes::complete::take,
+    combinator::{map, verify},
+    error::VerboseError,
+    multi::length_data,
+    number::complete::{be_u16, le_u8},
+    sequence::tuple,
+    IResult,
+};
+
+use crate::limits::{many0_capped, MAX_DATA_BLOCKS};
+
+// See VESA Localized String Extension Standard
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum StringEncoding {
+    Utf8,
+    Utf16Be,
+    Utf32Be,
+    Reserved(u8),
+}
+
+/// A string as stored in the extension, plus a best-effort conversion.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LocalizedString {
+    pub raw: Vec<u8>,
+}
+
+impl LocalizedString {
+    /// Decodes the raw bytes, replacing invalid sequences.
+    pub fn to_string_lossy(&self, encoding: StringEncoding) -> String {
+        let s = match encoding {
+            StringEncoding::Utf16Be => {
+                let units: Vec<u16> = self
+                    .raw
+                    .chunks_exact(2)
+                    .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                    .collect();
+                String::from_utf16_lossy(&units)
+            }
+            StringEncoding::Utf32Be => self
+                .raw
+                .chunks_exact(4)
+                .map(|c| u32::from_be_bytes([c[0], c[1], c[2], c[3]]))
+                .map(|c| char::from_u32(c).unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect(),
+            StringEncoding::Utf8 | StringEncoding::Reserved(_) => {
+                String::from_utf8_lossy(&self.raw).into_owned()
+            }
+        };
+        s.trim_end_matches('\0').to_string()
+    }
+}
+
+/// Strings for one language.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct StringTable {
+    pub encoding: StringEncoding,
+    /// ISO 3166-3 country code.
+    pub country_code: u16,
+    /// ISO 639 language code.
+    pub language: [char; 3],
+    pub manufacturer_name: LocalizedString,
+    pub model_name: LocalizedString,
+    pub serial_number: LocalizedString,
+}
+
+/// Localized String extension (tag 0x50).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LsExt {
+    pub version: u8,
+    pub revision: u8,
+    pub tables: Vec<StringTable>,
+}
+
+fn parse_language(v: u16) -> [char; 3] {
+    let letter = |shift: u16| (((v >> shift) & 0x1f) as u8 + b'@') as char;
+    [letter(10), letter(5), letter(0)]
+}
+
+fn parse_string(input: &[u8]) -> IResult<&[u8], LocalizedString, VerboseError<&[u8]>> {
+    map(length_data(le_u8), |raw: &[u8]| LocalizedString {
+        raw: raw.to_vec(),
+    })(input)
+}
+
+fn parse_string_table(input: &[u8]) -> IResult<&[u8], StringTable, VerboseError<&[u8]>> {
+    // A zero header length ends the list of tables
+    let (input, header_len) = verify(le_u8, |len| *len >= 5)(input)?;
+    let (input, header) = take(header_len)(input)?;
+    let (_, (utf_type, country_code, language)) = tuple((le_u8, be_u16, be_u16))(header)?;
+    let (input, (manufacturer_name, model_name, serial_number)) =
+        tuple((parse_string, parse_string, parse_string))(input)?;
+    Ok((
+        input,
+        StringTable {
+            encoding: match utf_type & 0x7 {
+                0 => StringEncoding::Utf8,
+                1 => StringEncoding::Utf16Be,
+                2 => StringEncoding::Utf32Be,
+                other => StringEncoding::Reserved(other),
+            },
+            country_code: country_code & 0x3fff,
+            language: parse_language(language),
+            manufacturer_name,
+            model_name,
+            serial_number,
+        },
+    ))
+}
+
+fn parse_ls_ext_payload(input: &[u8]) -> IResult<&[u8], LsExt, VerboseError<&[u8]>> {
+    let (input, (version, revision)) = tuple((le_u8, le_u8))(input)?;
+    let (input, tables) =
+        many0_capped(MAX_DATA_BLOCKS, "string tables", parse_string_table)(input)?;
+    Ok((
+        input,
+        LsExt {
+            version,
+            revision,
+            tables,
+        },
+    ))
+}
+
+pub(crate) fn parse_ls_ext(input: &[u8]) -> IResult<&[u8], LsExt, VerboseError<&[u8]>> {
+    let (input, block) = take(128u8)(input)?;
+    let (_, ls_ext) = parse_ls_ext_payload(&block[1..127])?;
+    Ok((input, ls_ext))
+}
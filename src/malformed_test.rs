@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    //! Regression corpus of malformed EDIDs, e.g. minimized fuzzer findings.
+    //! Every file in `testdata/malformed/` must be rejected with an error.
+
+    use crate::edid::parse;
+    use proptest::prelude::*;
+    use std::{fs, panic, sync::mpsc, thread, time::Duration};
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn test_malformed_corpus() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/testdata/malformed");
+        let mut paths: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        paths.sort();
+        assert!(!paths.is_empty());
+
+        let mut failures = Vec::new();
+        for path in paths {
+            let data = fs::read(&path).unwrap();
+
+            // Parse on a separate thread to catch panics and infinite loops
+            let (tx, rx) = mpsc::channel();
+            thread::spawn(move || {
+                let result = panic::catch_unwind(|| parse(&data).is_err());
+                let _ = tx.send(result);
+            });
+            match rx.recv_timeout(TIMEOUT) {
+                Ok(Ok(true)) => {}
+                Ok(Ok(false)) => failures.push(format!("{}: parsed", path.display())),
+                Ok(Err(_)) => failures.push(format!("{}: panicked", path.display())),
+                Err(_) => failures.push(format!("{}: timed out", path.display())),
+            }
+        }
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    proptest! {
+        // A cheap stand-in for the fuzz targets, run with the tests
+        #[test]
+        fn prop_mutated_samples_dont_panic(
+            sample in 0..3usize,
+            edits in prop::collection::vec(any::<(u16, u8)>(), 0..32),
+            len in any::<u16>(),
+        ) {
+            let mut data = [
+                &include_bytes!("../testdata/card0-VGA-1.bin")[..],
+                &include_bytes!("../testdata/card0-HDMI-1.bin")[..],
+                &include_bytes!("../testdata/card0-HDMI-2.bin")[..],
+            ][sample]
+                .to_vec();
+            for (offset, value) in edits {
+                let offset = offset as usize % data.len();
+                data[offset] = value;
+            }
+            data.truncate(len as usize);
+            if let Ok((_, edid)) = parse(&data) {
+                let _ = edid.product_info();
+                let _ = edid.capabilities();
+                if let Ok(bytes) = edid.to_bytes() {
+                    prop_assert!(parse(&bytes).is_ok());
+                }
+            }
+        }
+    }
+}
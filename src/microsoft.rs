@@ -0,0 +1,84 @@
+use nom::{
+    bytes::complete::take, combinator::map, error::VerboseError, number::complete::le_u8,
+    sequence::tuple, IResult,
+};
+
+use crate::cta::VendorSpecific;
+
+/// Microsoft specialized display block, the payload following the Microsoft
+/// OUI. Marks head-mounted and other displays Windows shouldn't use as a
+/// regular desktop monitor.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct MicrosoftVsdb {
+    pub version: u8,
+    /// The display may be used as a desktop monitor, version 2 and later.
+    pub desktop_usage: bool,
+    /// The display may be driven by third-party compositors, version 2 and
+    /// later.
+    pub third_party_usage: bool,
+    /// Primary use case, see the `USE_CASE_*` consts.
+    pub primary_use_case: u8,
+    pub container_id: [u8; 16],
+}
+
+impl MicrosoftVsdb {
+    pub const USE_CASE_UNSPECIFIED: u8 = 0;
+    pub const USE_CASE_TEST_EQUIPMENT: u8 = 1;
+    pub const USE_CASE_GENERIC_DISPLAY: u8 = 2;
+    pub const USE_CASE_TELEVISION: u8 = 3;
+    pub const USE_CASE_DESKTOP_PRODUCTIVITY: u8 = 4;
+    pub const USE_CASE_DESKTOP_GAMING: u8 = 5;
+    pub const USE_CASE_PRESENTATION: u8 = 6;
+    pub const USE_CASE_VIRTUAL_REALITY: u8 = 7;
+    pub const USE_CASE_AUGMENTED_REALITY: u8 = 8;
+    pub const USE_CASE_VIDEO_WALL: u8 = 16;
+    pub const USE_CASE_MEDICAL_IMAGING: u8 = 17;
+    pub const USE_CASE_DEDICATED_GAMING: u8 = 18;
+    pub const USE_CASE_DEDICATED_VIDEO_MONITOR: u8 = 19;
+    pub const USE_CASE_ACCESSORY: u8 = 20;
+
+    /// Whether the display is a VR or AR headset.
+    pub fn is_head_mounted(&self) -> bool {
+        matches!(
+            self.primary_use_case,
+            MicrosoftVsdb::USE_CASE_VIRTUAL_REALITY | MicrosoftVsdb::USE_CASE_AUGMENTED_REALITY
+        )
+    }
+}
+
+fn parse_microsoft(payload: &[u8]) -> IResult<&[u8], MicrosoftVsdb, VerboseError<&[u8]>> {
+    let (payload, (version, usage)) = tuple((le_u8, le_u8))(payload)?;
+    let (payload, container_id) = map(take(16u8), |id: &[u8]| {
+        let mut container_id = [0; 16];
+        container_id.copy_from_slice(id);
+        container_id
+    })(payload)?;
+    // Version 1 blocks don't define the usage bits
+    let usage_bits = if version >= 2 { usage } else { 0 };
+    Ok((
+        payload,
+        MicrosoftVsdb {
+            version,
+            desktop_usage: usage_bits & 0x40 != 0,
+            third_party_usage: usage_bits & 0x20 != 0,
+            primary_use_case: usage & 0x1f,
+            container_id,
+        },
+    ))
+}
+
+impl VendorSpecific {
+    pub const MICROSOFT_OUI: u32 = 0xca125c;
+
+    /// Decodes the payload of Microsoft blocks, `None` for other vendors or
+    /// if the payload is too short.
+    pub fn microsoft(&self) -> Option<MicrosoftVsdb> {
+        if self.oui() != VendorSpecific::MICROSOFT_OUI {
+            return None;
+        }
+        parse_microsoft(&self.payload).ok().map(|(_, msft)| msft)
+    }
+}
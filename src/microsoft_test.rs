@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use crate::{builder::*, cta::*, edid::*, microsoft::*};
+
+    fn microsoft(payload: &[u8]) -> Option<MicrosoftVsdb> {
+        let mut block = vec![0x5c, 0x12, 0xca];
+        block.extend_from_slice(payload);
+        let cta = CtaBuilder::new().raw_data_block(3, &block).build().unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&cta);
+
+        let (_, edid) = parse(&data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        match &cta.blocks[0] {
+            DataBlock::VendorSpecific(vsdb) => vsdb.microsoft(),
+            block => panic!("unexpected block {:?}", block),
+        }
+    }
+
+    #[test]
+    fn test_microsoft() {
+        let container_id: Vec<u8> = (0..16).collect();
+
+        // A version 2 VR headset usable by third-party compositors
+        let mut payload = vec![0x02, 0x27];
+        payload.extend_from_slice(&container_id);
+        let msft = microsoft(&payload).unwrap();
+        assert_eq!(msft.version, 2);
+        assert!(!msft.desktop_usage);
+        assert!(msft.third_party_usage);
+        assert_eq!(
+            msft.primary_use_case,
+            MicrosoftVsdb::USE_CASE_VIRTUAL_REALITY
+        );
+        assert_eq!(&msft.container_id[..], &container_id[..]);
+        assert!(msft.is_head_mounted());
+
+        // Version 1 doesn't define the usage bits
+        let mut payload = vec![0x01, 0x64];
+        payload.extend_from_slice(&container_id);
+        let msft = microsoft(&payload).unwrap();
+        assert!(!msft.desktop_usage);
+        assert!(!msft.third_party_usage);
+        assert_eq!(
+            msft.primary_use_case,
+            MicrosoftVsdb::USE_CASE_DESKTOP_PRODUCTIVITY
+        );
+        assert!(!msft.is_head_mounted());
+
+        assert_eq!(microsoft(&[0x02, 0x07, 0x00]), None);
+    }
+}
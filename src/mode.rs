@@ -0,0 +1,509 @@
+#[cfg(feature = "cta")]
+use crate::cta::DataBlock;
+use crate::dmt::dmt_timing;
+use crate::edid::{Descriptor, DetailedTiming, RangeLimits, StandardTiming, EDID};
+use crate::extension::Extension;
+
+// See VESA CVT 1.1 and VESA GTF 1.1
+
+const CELL_GRANULARITY: f64 = 8.0;
+const MIN_VSYNC_BACK_PORCH: f64 = 550.0; // us
+const HSYNC_PERCENT: f64 = 8.0;
+
+// Refresh rates this close match, e.g. 59.94 Hz and 60 Hz
+const REFRESH_TOLERANCE: f64 = 0.5; // Hz
+
+// Separate digital sync, negative hsync, positive vsync
+const FEATURES_NHSYNC_PVSYNC: u8 = 0x1C;
+
+/// Where a timing listed by `EDID::timings` comes from.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum TimingSource {
+    /// A detailed timing descriptor of the base block.
+    BaseDescriptor,
+    /// A standard timing of the base block.
+    StandardTiming,
+    /// An established timing of the base block.
+    EstablishedTiming,
+    /// A DTD of a CTA extension.
+    CtaDescriptor,
+    /// A DTD of a VTB extension.
+    VtbDescriptor,
+    /// A standard timing of a VTB extension.
+    VtbStandardTiming,
+    /// A Type I or Type VII timing of a DisplayID extension.
+    DisplayId,
+}
+
+/// GTF blanking formula parameters.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct GtfParams {
+    pub c: f64,
+    pub m: f64,
+    pub k: f64,
+    pub j: f64,
+}
+
+impl GtfParams {
+    pub const DEFAULT: GtfParams = GtfParams {
+        c: 40.0,
+        m: 600.0,
+        k: 128.0,
+        j: 20.0,
+    };
+
+    fn c_prime(&self) -> f64 {
+        (self.c - self.j) * self.k / 256.0 + self.j
+    }
+
+    fn m_prime(&self) -> f64 {
+        self.k / 256.0 * self.m
+    }
+}
+
+fn cvt_vsync_width(width: u16, height: u16) -> u16 {
+    let (w, h) = (width as u32, height as u32);
+    if w * 3 == h * 4 {
+        4
+    } else if w * 9 == h * 16 {
+        5
+    } else if w * 10 == h * 16 {
+        6
+    } else if w * 4 == h * 5 || w * 9 == h * 15 {
+        7
+    } else {
+        10
+    }
+}
+
+/// Computes a CVT timing with standard blanking.
+pub fn cvt_timing(width: u16, height: u16, refresh: f64) -> DetailedTiming {
+    const MIN_V_PORCH: f64 = 3.0;
+    const MIN_V_BACK_PORCH: f64 = 6.0;
+    const CLOCK_STEP: f64 = 0.25; // MHz
+
+    let params = GtfParams::DEFAULT;
+    let h_pixels = (width as f64 / CELL_GRANULARITY).floor() * CELL_GRANULARITY;
+    let v_lines = height as f64;
+    let v_sync = cvt_vsync_width(width, height) as f64;
+
+    let h_period_est = (1.0 / refresh - MIN_VSYNC_BACK_PORCH / 1_000_000.0)
+        / (v_lines + MIN_V_PORCH)
+        * 1_000_000.0;
+    let mut v_sync_bp = (MIN_VSYNC_BACK_PORCH / h_period_est).floor() + 1.0;
+    if v_sync_bp < v_sync + MIN_V_BACK_PORCH {
+        v_sync_bp = v_sync + MIN_V_BACK_PORCH;
+    }
+    let v_blank = v_sync_bp + MIN_V_PORCH;
+
+    let ideal_duty_cycle = (params.c_prime() - params.m_prime() * h_period_est / 1000.0).max(20.0);
+    let h_blank =
+        (h_pixels * ideal_duty_cycle / (100.0 - ideal_duty_cycle) / (2.0 * CELL_GRANULARITY))
+            .floor()
+            * 2.0
+            * CELL_GRANULARITY;
+    let total_pixels = h_pixels + h_blank;
+    let pixel_clock = CLOCK_STEP * (total_pixels / h_period_est / CLOCK_STEP).floor();
+    let h_sync =
+        (HSYNC_PERCENT / 100.0 * total_pixels / CELL_GRANULARITY).floor() * CELL_GRANULARITY;
+    let h_front_porch = h_blank - h_sync - h_blank / 2.0;
+
+    DetailedTiming {
+        pixel_clock: (pixel_clock * 1000.0) as u32,
+        horizontal_active_pixels: h_pixels as u16,
+        horizontal_blanking_pixels: h_blank as u16,
+        vertical_active_lines: v_lines as u16,
+        vertical_blanking_lines: v_blank as u16,
+        horizontal_front_porch: h_front_porch as u16,
+        horizontal_sync_width: h_sync as u16,
+        vertical_front_porch: MIN_V_PORCH as u16,
+        vertical_sync_width: v_sync as u16,
+        features: FEATURES_NHSYNC_PVSYNC,
+        ..Default::default()
+    }
+}
+
+/// Computes a GTF timing with the given blanking formula parameters.
+pub fn gtf_timing(width: u16, height: u16, refresh: f64, params: &GtfParams) -> DetailedTiming {
+    const MIN_PORCH: f64 = 1.0;
+    const V_SYNC: f64 = 3.0;
+
+    let h_pixels = (width as f64 / CELL_GRANULARITY).round() * CELL_GRANULARITY;
+    let v_lines = height as f64;
+
+    let h_period_est =
+        (1.0 / refresh - MIN_VSYNC_BACK_PORCH / 1_000_000.0) / (v_lines + MIN_PORCH) * 1_000_000.0;
+    let v_sync_bp = (MIN_VSYNC_BACK_PORCH / h_period_est).round();
+    let total_v_lines = v_lines + v_sync_bp + MIN_PORCH;
+    let v_field_rate_est = 1.0 / h_period_est / total_v_lines * 1_000_000.0;
+    let h_period = h_period_est / (refresh / v_field_rate_est);
+
+    let ideal_duty_cycle = params.c_prime() - params.m_prime() * h_period / 1000.0;
+    let h_blank =
+        (h_pixels * ideal_duty_cycle / (100.0 - ideal_duty_cycle) / (2.0 * CELL_GRANULARITY))
+            .round()
+            * 2.0
+            * CELL_GRANULARITY;
+    let total_pixels = h_pixels + h_blank;
+    let pixel_clock = total_pixels / h_period;
+    let h_sync =
+        (HSYNC_PERCENT / 100.0 * total_pixels / CELL_GRANULARITY).round() * CELL_GRANULARITY;
+    let h_front_porch = h_blank / 2.0 - h_sync;
+
+    DetailedTiming {
+        // DTDs store the pixel clock with a 10 kHz resolution
+        pixel_clock: (pixel_clock * 100.0).round() as u32 * 10,
+        horizontal_active_pixels: h_pixels as u16,
+        horizontal_blanking_pixels: h_blank as u16,
+        vertical_active_lines: v_lines as u16,
+        vertical_blanking_lines: (v_sync_bp + MIN_PORCH) as u16,
+        horizontal_front_porch: h_front_porch as u16,
+        horizontal_sync_width: h_sync as u16,
+        vertical_front_porch: MIN_PORCH as u16,
+        vertical_sync_width: V_SYNC as u16,
+        features: FEATURES_NHSYNC_PVSYNC,
+        ..Default::default()
+    }
+}
+
+impl DetailedTiming {
+    fn total_pixels(&self) -> u32 {
+        (self.horizontal_active_pixels + self.horizontal_blanking_pixels) as u32
+    }
+
+    fn total_lines(&self) -> u32 {
+        (self.vertical_active_lines + self.vertical_blanking_lines) as u32
+    }
+
+    /// Horizontal rate in kHz.
+    pub fn horizontal_rate(&self) -> f64 {
+        match self.total_pixels() {
+            0 => 0.0,
+            total => self.pixel_clock as f64 / total as f64,
+        }
+    }
+
+    pub fn is_interlaced(&self) -> bool {
+        self.features & 0x80 != 0
+    }
+
+    /// Vertical rate in Hz.
+    pub fn refresh_rate(&self) -> f64 {
+        match self.total_lines() {
+            0 => 0.0,
+            total => self.horizontal_rate() * 1000.0 / total as f64,
+        }
+    }
+
+    /// The active, sync start, sync end and total of the horizontal and
+    /// vertical timings, counted from the start of the active area as in a
+    /// modeline. Vertical timings are per frame for interlaced timings.
+    pub(crate) fn sync_positions(&self) -> ([u32; 4], [u32; 4]) {
+        let h_active = self.horizontal_active_pixels as u32;
+        let h_sync_start = h_active + self.horizontal_front_porch as u32;
+        let h_sync_end = h_sync_start + self.horizontal_sync_width as u32;
+        // Vertical timings of a DTD are per field
+        let fields = if self.is_interlaced() { 2 } else { 1 };
+        let v_active = self.vertical_active_lines as u32;
+        let v_sync_start = v_active + self.vertical_front_porch as u32;
+        let v_sync_end = v_sync_start + self.vertical_sync_width as u32;
+        // Plus the half line between the fields
+        let v_total = self.total_lines() * fields + fields - 1;
+        (
+            [h_active, h_sync_start, h_sync_end, self.total_pixels()],
+            [
+                v_active * fields,
+                v_sync_start * fields,
+                v_sync_end * fields,
+                v_total,
+            ],
+        )
+    }
+
+    /// Vertical rate in Hz, of the fields for interlaced timings. Unlike
+    /// `refresh_rate`, it accounts for the half line of interlaced timings.
+    pub(crate) fn field_rate(&self) -> f64 {
+        let ([.., h_total], [.., v_total]) = self.sync_positions();
+        let fields = if self.is_interlaced() { 2.0 } else { 1.0 };
+        match h_total * v_total {
+            0 => 0.0,
+            total => self.pixel_clock as f64 * 1000.0 * fields / total as f64,
+        }
+    }
+
+    /// Formats the timing as an xorg.conf `Modeline`, e.g.
+    /// `Modeline "1920x1080_60.00" 148.50 1920 2008 2052 2200 1080 1084 1089 1125 +hsync +vsync`.
+    ///
+    /// Interlaced timings are listed with frame vertical timings. Borders
+    /// aren't included.
+    pub fn to_modeline(&self) -> String {
+        let (h, v) = self.sync_positions();
+        let interlace = if self.is_interlaced() { "i" } else { "" };
+        let mut modeline = format!(
+            "Modeline \"{}x{}{}_{:.2}\" {:.2} {} {} {} {} {} {} {} {}",
+            h[0],
+            v[0],
+            interlace,
+            self.field_rate(),
+            self.pixel_clock as f64 / 1000.0,
+            h[0],
+            h[1],
+            h[2],
+            h[3],
+            v[0],
+            v[1],
+            v[2],
+            v[3],
+        );
+        let polarity = |positive: bool| if positive { '+' } else { '-' };
+        match self.features & 0x18 {
+            // Digital separate sync
+            0x18 => {
+                modeline += &format!(
+                    " {}hsync {}vsync",
+                    polarity(self.features & 0x02 != 0),
+                    polarity(self.features & 0x04 != 0)
+                )
+            }
+            // Digital composite sync
+            0x10 => modeline += &format!(" Composite {}csync", polarity(self.features & 0x02 != 0)),
+            _ => modeline += " Composite",
+        }
+        if self.is_interlaced() {
+            modeline += " Interlace";
+        }
+        modeline
+    }
+}
+
+impl RangeLimits {
+    /// Secondary GTF parameters, if advertised.
+    pub fn secondary_gtf(&self) -> Option<(u16, GtfParams)> {
+        if self.video_timing_support != RangeLimits::SECONDARY_GTF {
+            return None;
+        }
+        let d = &self.video_timing_data;
+        let start_frequency = d[1] as u16 * 2; // kHz
+        let params = GtfParams {
+            c: d[2] as f64 / 2.0,
+            m: u16::from_le_bytes([d[3], d[4]]) as f64,
+            k: d[5] as f64,
+            j: d[6] as f64 / 2.0,
+        };
+        Some((start_frequency, params))
+    }
+
+    /// Whether the timing lies within these limits.
+    pub fn accepts(&self, timing: &DetailedTiming) -> bool {
+        let h_rate = timing.horizontal_rate();
+        let v_rate = timing.refresh_rate();
+        (self.max_pixel_clock == 0 || timing.pixel_clock <= self.max_pixel_clock)
+            && h_rate >= self.min_horizontal_rate as f64
+            && h_rate <= self.max_horizontal_rate as f64
+            && v_rate.round() >= self.min_vertical_rate as f64
+            && v_rate.round() <= self.max_vertical_rate as f64
+    }
+}
+
+impl EDID {
+    /// The native mode of the display: the preferred timing, else the first
+    /// CTA native DTD, else the first native SVD.
+    ///
+    /// Interlaced candidates are skipped in favor of a later progressive one,
+    /// as some TVs prefer 1080i over their native progressive mode.
+    pub fn native_mode(&self) -> Option<DetailedTiming> {
+        let candidates = self.preferred_timing().into_iter().copied();
+        #[cfg(feature = "cta")]
+        let candidates = candidates
+            .chain(self.cta_extensions().flat_map(|cta| {
+                let native = cta.native_dtd.number_of_native_dtd as usize;
+                cta.descriptors.iter().take(native).copied()
+            }))
+            .chain(self.cta_extensions().flat_map(|cta| {
+                cta.blocks
+                    .iter()
+                    .filter_map(|block| match block {
+                        DataBlock::VideoBlock(video) => Some(&video.descriptors),
+                        _ => None,
+                    })
+                    .flatten()
+                    .filter(|svd| svd.is_native != 0)
+                    .filter_map(|svd| svd.video_format())
+                    .map(|format| format.timing)
+            }));
+
+        let mut first = None;
+        for timing in candidates {
+            if !timing.is_interlaced() {
+                return Some(timing);
+            }
+            first.get_or_insert(timing);
+        }
+        first
+    }
+
+    /// Every timing of the EDID with the block it was read from: the base
+    /// block DTDs, the expanded standard timings, the established timings,
+    /// then the timings of the extensions in block order.
+    ///
+    /// Standard timings use their DMT timing, else the timing formula of the
+    /// range limits, CVT if there are none on EDID 1.4 and GTF before. Those
+    /// without a DMT timing aren't listed when the range limits support no
+    /// formula. Established
+    /// timings have fixed DMT or vendor timings; manufacturer's timings
+    /// aren't listed.
+    pub fn timings(&self) -> impl Iterator<Item = (TimingSource, DetailedTiming)> {
+        let mut timings: Vec<_> = self
+            .descriptors
+            .iter()
+            .filter_map(|descriptor| match descriptor {
+                Descriptor::DetailedTiming(timing) => Some((TimingSource::BaseDescriptor, *timing)),
+                _ => None,
+            })
+            .collect();
+        timings.extend(
+            self.standard_timing.iter().filter_map(|standard| {
+                Some((TimingSource::StandardTiming, self.expand(standard)?))
+            }),
+        );
+        timings.extend(
+            self.established_timing
+                .timings()
+                .map(|timing| (TimingSource::EstablishedTiming, timing)),
+        );
+
+        for extension in &self.extensions {
+            match extension {
+                #[cfg(feature = "cta")]
+                Extension::Cta(cta) => timings.extend(
+                    cta.descriptors
+                        .iter()
+                        .map(|timing| (TimingSource::CtaDescriptor, *timing)),
+                ),
+                #[cfg(feature = "vtb")]
+                Extension::Vtb(vtb) => {
+                    timings.extend(
+                        vtb.detailed_timings
+                            .iter()
+                            .map(|timing| (TimingSource::VtbDescriptor, *timing)),
+                    );
+                    timings.extend(vtb.standard_timings.iter().filter_map(|standard| {
+                        Some((TimingSource::VtbStandardTiming, self.expand(standard)?))
+                    }));
+                }
+                #[cfg(feature = "displayid")]
+                Extension::DisplayId(displayid) => timings.extend(
+                    displayid
+                        .timings()
+                        .map(|timing| (TimingSource::DisplayId, timing.timing)),
+                ),
+                #[cfg(feature = "di-ext")]
+                Extension::DiExt(_) => {}
+                Extension::LsExt(_) | Extension::BlockMap(_) | Extension::Unknown { .. } => {}
+            }
+        }
+        timings.into_iter()
+    }
+
+    /// Expands a standard timing, which only gives the active area and rate,
+    /// to its DMT timing, else with the timing formula. `None` if it isn't
+    /// a DMT mode and the range limits don't allow a formula.
+    fn expand(&self, standard: &StandardTiming) -> Option<DetailedTiming> {
+        let width = standard.horizontal_active_pixels;
+        let height = standard.vertical_active_lines();
+        if let Some(timing) = dmt_timing(width, height, standard.refresh_rate) {
+            return Some(timing);
+        }
+        let refresh = standard.refresh_rate as f64;
+        let cvt = match self
+            .range_limits()
+            .map(|limits| limits.video_timing_support)
+        {
+            Some(RangeLimits::RANGE_LIMITS_ONLY) => return None,
+            Some(support) => support == RangeLimits::CVT,
+            None => (self.header.version, self.header.revision) >= (1, 4),
+        };
+        if cvt {
+            Some(cvt_timing(width, height, refresh))
+        } else {
+            Some(self.gtf_mode(width, height, refresh))
+        }
+    }
+
+    /// A GTF timing, on the secondary curve of the range limits above its
+    /// start frequency.
+    fn gtf_mode(&self, width: u16, height: u16, refresh: f64) -> DetailedTiming {
+        let timing = gtf_timing(width, height, refresh, &GtfParams::DEFAULT);
+        match self.range_limits().and_then(RangeLimits::secondary_gtf) {
+            Some((start_frequency, params))
+                if timing.horizontal_rate() >= start_frequency as f64 =>
+            {
+                gtf_timing(width, height, refresh, &params)
+            }
+            _ => timing,
+        }
+    }
+
+    /// Whether the display can show the mode: it is listed by one of
+    /// `timings`, by a CTA SVD, or lies within the range limits of a
+    /// continuous frequency display. `height` is the frame height for
+    /// interlaced modes.
+    pub fn supports_mode(&self, width: u16, height: u16, refresh: f64) -> bool {
+        let matches = |w: u16, h: u16, rate: f64| {
+            w == width && h == height && (rate - refresh).abs() <= REFRESH_TOLERANCE
+        };
+
+        let listed = self.timings().any(|(_, timing)| {
+            let fields = if timing.is_interlaced() { 2 } else { 1 };
+            let lines = timing.vertical_active_lines * fields;
+            matches(
+                timing.horizontal_active_pixels,
+                lines,
+                timing.refresh_rate(),
+            )
+        });
+        #[cfg(feature = "cta")]
+        let listed = listed
+            || self
+                .cta_extensions()
+                .flat_map(|cta| &cta.blocks)
+                .filter_map(|block| match block {
+                    DataBlock::VideoBlock(video) => Some(&video.descriptors),
+                    _ => None,
+                })
+                .flatten()
+                .filter_map(|svd| svd.video_format())
+                .any(|format| matches(format.width, format.height, format.refresh_rate as f64));
+
+        listed
+            || (self.display.decoded_features().continuous_frequency
+                && self.propose_mode(width, height, refresh).is_some())
+    }
+
+    /// Generates a timing for a mode the EDID doesn't list explicitly, using
+    /// the timing formula advertised in the range limits descriptor.
+    ///
+    /// Returns `None` if the EDID has no range limits, if they support no
+    /// timing formula, or if the resulting timing falls outside of them.
+    pub fn propose_mode(&self, width: u16, height: u16, refresh: f64) -> Option<DetailedTiming> {
+        let limits = self.range_limits()?;
+        if width == 0 || height == 0 || refresh <= 0.0 {
+            return None;
+        }
+
+        let mut timing = match limits.video_timing_support {
+            RangeLimits::RANGE_LIMITS_ONLY => return None,
+            RangeLimits::CVT => cvt_timing(width, height, refresh),
+            _ => self.gtf_mode(width, height, refresh),
+        };
+        let (width_mm, height_mm) = self.physical_size_mm().unwrap_or((0, 0));
+        timing.horizontal_size = width_mm;
+        timing.vertical_size = height_mm;
+
+        if limits.accepts(&timing) {
+            Some(timing)
+        } else {
+            None
+        }
+    }
+}
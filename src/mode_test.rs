@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::*, mode::*};
+
+    #[test]
+    fn test_cvt_timing() {
+        // cvt 1920 1080 60
+        let timing = cvt_timing(1920, 1080, 60.0);
+        assert_eq!(timing.pixel_clock, 173000);
+        assert_eq!(timing.horizontal_blanking_pixels, 656);
+        assert_eq!(timing.horizontal_front_porch, 128);
+        assert_eq!(timing.horizontal_sync_width, 200);
+        assert_eq!(timing.vertical_blanking_lines, 40);
+        assert_eq!(timing.vertical_front_porch, 3);
+        assert_eq!(timing.vertical_sync_width, 5);
+    }
+
+    #[test]
+    fn test_gtf_timing() {
+        // gtf 1920 1080 60
+        let timing = gtf_timing(1920, 1080, 60.0, &GtfParams::DEFAULT);
+        assert_eq!(timing.pixel_clock, 172800);
+        assert_eq!(timing.horizontal_blanking_pixels, 656);
+        assert_eq!(timing.horizontal_front_porch, 120);
+        assert_eq!(timing.horizontal_sync_width, 208);
+        assert_eq!(timing.vertical_blanking_lines, 38);
+        assert_eq!(timing.vertical_front_porch, 1);
+        assert_eq!(timing.vertical_sync_width, 3);
+    }
+
+    #[test]
+    fn test_propose_mode() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let (_, edid) = parse(d).unwrap();
+
+        let timing = edid.propose_mode(1280, 1024, 60.0).unwrap();
+        assert_eq!(timing.horizontal_active_pixels, 1280);
+        assert_eq!(timing.vertical_active_lines, 1024);
+        assert_eq!(timing.horizontal_size, 474);
+
+        // Exceeds the 170 MHz pixel clock limit
+        assert_eq!(edid.propose_mode(1920, 1080, 60.0), None);
+        // Exceeds the 75 Hz vertical rate limit
+        assert_eq!(edid.propose_mode(1024, 768, 85.0), None);
+
+        // Range limits only, without a timing formula
+        let mut data = d.to_vec();
+        data[82] = RangeLimits::RANGE_LIMITS_ONLY;
+        // 1680x1050@61 has no DMT timing
+        data[39] = 0x01;
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(edid.propose_mode(1280, 1024, 60.0), None);
+        let standard = edid
+            .timings()
+            .filter(|(source, _)| *source == TimingSource::StandardTiming)
+            .count();
+        assert_eq!(standard, 3);
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_native_mode() {
+        use crate::builder::{CtaBuilder, EdidBuilder};
+
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(edid.native_mode().as_ref(), edid.preferred_timing());
+
+        // An interlaced preferred timing gives way to a progressive one
+        let mut data = d.to_vec();
+        let interlaced = d[128 + 49..128 + 67].to_vec();
+        data[54..72].copy_from_slice(&interlaced);
+        let (_, edid) = parse(&data).unwrap();
+        assert!(edid.preferred_timing().unwrap().is_interlaced());
+        let native = edid.native_mode().unwrap();
+        assert!(!native.is_interlaced());
+        assert_eq!(native.vertical_active_lines, 1080);
+
+        // Without DTDs, from the native SVD
+        let data = EdidBuilder::new()
+            .cta(CtaBuilder::new().raw_data_block(2, &[0x04, 0x90]))
+            .build()
+            .unwrap();
+        let (_, edid) = parse(&data).unwrap();
+        let native = edid.native_mode().unwrap();
+        assert_eq!(native.horizontal_active_pixels, 1920);
+        assert_eq!(native.refresh_rate().round(), 60.0);
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_timings() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        let timings: Vec<_> = edid.timings().collect();
+        let sources: Vec<_> = timings.iter().map(|(source, _)| *source).collect();
+        assert_eq!(
+            sources,
+            [
+                TimingSource::BaseDescriptor,
+                TimingSource::StandardTiming,
+                TimingSource::StandardTiming,
+                TimingSource::StandardTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::EstablishedTiming,
+                TimingSource::CtaDescriptor,
+                TimingSource::CtaDescriptor,
+                TimingSource::CtaDescriptor,
+                TimingSource::CtaDescriptor,
+            ]
+        );
+        assert_eq!(Some(&timings[0].1), edid.preferred_timing());
+
+        // DMT 1280x1024@60
+        let (_, standard) = timings[2];
+        assert_eq!(standard.horizontal_active_pixels, 1280);
+        assert_eq!(standard.vertical_active_lines, 1024);
+        assert_eq!(standard.pixel_clock, 108000);
+        assert_eq!(standard.horizontal_blanking_pixels, 408);
+        assert_eq!(standard.vertical_blanking_lines, 42);
+
+        // Not a DMT mode, EDID 1.3 with GTF range limits, gtf 1280 1024 61
+        let mut data = d.to_vec();
+        data[41] = 0x81;
+        let (_, edid) = parse(&data).unwrap();
+        let (_, standard) = edid.timings().nth(2).unwrap();
+        assert_eq!(standard.horizontal_active_pixels, 1280);
+        assert_eq!(
+            standard.pixel_clock,
+            gtf_timing(1280, 1024, 61.0, &GtfParams::DEFAULT).pixel_clock
+        );
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_supports_mode() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        // Preferred DTD, CTA DTDs, standard timing
+        assert!(edid.supports_mode(1920, 1080, 60.0));
+        assert!(edid.supports_mode(1920, 1080, 59.94));
+        assert!(edid.supports_mode(720, 480, 60.0));
+        assert!(edid.supports_mode(1280, 1024, 60.0));
+        // Established timing
+        assert!(edid.supports_mode(1280, 1024, 75.0));
+        assert!(!edid.supports_mode(1280, 1024, 85.0));
+        // VIC 31
+        assert!(edid.supports_mode(1920, 1080, 50.0));
+        assert!(!edid.supports_mode(2560, 1440, 120.0));
+
+        // Within the range limits only on continuous frequency displays
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert!(!edid.supports_mode(1152, 864, 70.0));
+        let mut data = d.to_vec();
+        data[24] |= 0x01;
+        let (_, edid) = parse(&data).unwrap();
+        assert!(edid.supports_mode(1152, 864, 70.0));
+        assert!(!edid.supports_mode(1920, 1080, 60.0));
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_to_modeline() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        let modelines: Vec<_> = edid
+            .timings()
+            .map(|(_, timing)| timing.to_modeline())
+            .collect();
+        assert_eq!(
+            modelines[0],
+            "Modeline \"1920x1080_60.00\" 148.50 1920 2008 2052 2200 1080 1084 1089 1125 +hsync +vsync"
+        );
+        assert_eq!(
+            modelines[13],
+            "Modeline \"1920x1080i_60.00\" 74.25 1920 2008 2052 2200 1080 1084 1094 1125 +hsync +vsync Interlace"
+        );
+        assert_eq!(
+            modelines[15],
+            "Modeline \"720x480_59.94\" 27.00 720 736 798 858 480 489 495 525 -hsync -vsync"
+        );
+
+        let composite = DetailedTiming {
+            features: 0x12,
+            ..cvt_timing(1024, 768, 60.0)
+        };
+        assert!(composite.to_modeline().ends_with(" Composite +csync"));
+    }
+}
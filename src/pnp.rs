@@ -0,0 +1,78 @@
+use std::{collections::HashMap, sync::OnceLock};
+
+use crate::edid::Header;
+
+// A subset of the PNP ID registry, see https://uefi.org/pnp_id_list
+const VENDOR_NAMES: &[(&str, &str)] = &[
+    ("AAC", "AcerView"),
+    ("ACI", "Ancor Communications"),
+    ("ACR", "Acer Technologies"),
+    ("AOC", "AOC International"),
+    ("APP", "Apple Computer"),
+    ("AUO", "AU Optronics"),
+    ("BNQ", "BenQ Corporation"),
+    ("BOE", "BOE Display Technology"),
+    ("CMN", "Chimei Innolux Corporation"),
+    ("CMO", "Chi Mei Optoelectronics"),
+    ("DEL", "Dell Inc."),
+    ("DON", "Denon"),
+    ("ENC", "Eizo Nanao Corporation"),
+    ("EPI", "Envision Peripherals"),
+    ("FUS", "Fujitsu Siemens Computers"),
+    ("GSM", "LG Electronics"),
+    ("HPN", "HP Inc."),
+    ("HSD", "HannStar Display"),
+    ("HWP", "Hewlett Packard"),
+    ("IVM", "Iiyama North America"),
+    ("LEN", "Lenovo Group Limited"),
+    ("LGD", "LG Display"),
+    ("LPL", "LG Philips"),
+    ("MED", "Medion"),
+    ("MEI", "Panasonic Industry Company"),
+    ("MSI", "Microstep"),
+    ("MSH", "Microsoft"),
+    ("NEC", "NEC Corporation"),
+    ("ONK", "Onkyo Corporation"),
+    ("PHL", "Philips Consumer Electronics Company"),
+    ("PIO", "Pioneer Electronic Corporation"),
+    ("SAM", "Samsung Electric Company"),
+    ("SDC", "Samsung Display Corp"),
+    ("SHP", "Sharp Corporation"),
+    ("SNY", "Sony"),
+    ("TSB", "Toshiba America Info Systems"),
+    ("VIZ", "VIZIO, Inc"),
+    ("VSC", "ViewSonic Corporation"),
+    ("YMH", "Yamaha Corporation"),
+];
+
+static VENDOR_OVERRIDES: OnceLock<HashMap<[char; 3], String>> = OnceLock::new();
+
+/// Registers additional vendor names, e.g. company-internal PNP IDs. They
+/// take precedence over the built-in table.
+///
+/// This can only be done once per process: returns `false` if a table was
+/// already registered.
+pub fn register_vendor_names<I>(names: I) -> bool
+where
+    I: IntoIterator<Item = ([char; 3], String)>,
+{
+    VENDOR_OVERRIDES.set(names.into_iter().collect()).is_ok()
+}
+
+/// Looks up the manufacturer name of a PNP ID.
+pub fn vendor_name(vendor: [char; 3]) -> Option<&'static str> {
+    if let Some(name) = VENDOR_OVERRIDES.get().and_then(|names| names.get(&vendor)) {
+        return Some(name);
+    }
+
+    VENDOR_NAMES
+        .iter()
+        .find(|(id, _)| id.chars().eq(vendor.iter().copied()))
+        .map(|(_, name)| *name)
+}
+
+impl Header {
+    pub fn vendor_name(&self) -> Option<&'static str> {
+        vendor_name(self.vendor)
+    }
+}
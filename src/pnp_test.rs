@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::*, pnp::*};
+
+    #[test]
+    fn test_vendor_name() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(edid.header.vendor_name(), Some("Dell Inc."));
+        assert_eq!(vendor_name(['Z', 'Z', 'Y']), None);
+
+        assert!(register_vendor_names(vec![
+            (['Z', 'Z', 'Y'], "Internal Panel Co.".to_string()),
+            (['D', 'E', 'L'], "Dell".to_string()),
+        ]));
+        assert!(!register_vendor_names(vec![]));
+
+        assert_eq!(vendor_name(['Z', 'Z', 'Y']), Some("Internal Panel Co."));
+        assert_eq!(edid.header.vendor_name(), Some("Dell"));
+        assert_eq!(
+            vendor_name(['S', 'A', 'M']),
+            Some("Samsung Electric Company")
+        );
+    }
+}
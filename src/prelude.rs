@@ -0,0 +1,23 @@
+//! The commonly used types, for a glob import:
+//!
+//! ```
+//! use edidr::prelude::*;
+//! ```
+
+#[cfg(feature = "cta")]
+pub use crate::cta::{CtaExtensions, DataBlock, VendorSpecific};
+#[cfg(feature = "displayid")]
+pub use crate::displayid::{DisplayId, DisplayIdDataBlock};
+#[cfg(feature = "cta")]
+pub use crate::extended::ExtendedDataBlock;
+pub use crate::{
+    capabilities::{CapabilitySource, EdidCapabilities},
+    coverage::Coverage,
+    edid::{parse, Descriptor, DetailedTiming, Display, Header, StandardTiming, EDID},
+    error::{EdidError, EdidErrorKind},
+    extension::{Extension, ExtensionTag},
+    lenient::ParseWarning,
+    reader::{iter_from_reader, ReadError},
+    validate::{Issue, Rule, Severity, Validator},
+    vrr::{VrrInfo, VrrRange},
+};
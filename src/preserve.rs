@@ -0,0 +1,120 @@
+use nom::Offset;
+
+use crate::{
+    edid::{parse, EDID},
+    encode::{base_block, extension_block, finish_block, has_extension_override, EncodeError},
+    error::EdidError,
+    limits::MAX_EXTENSIONS,
+};
+
+/// An EDID kept along with the exact bytes it was parsed from.
+///
+/// `encode` emits the input bytes unchanged, padding, reserved bits and
+/// checksums included, so the parsed view can't alter evidence.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PreservedEdid {
+    pub edid: EDID,
+    bytes: Vec<u8>,
+}
+
+impl PreservedEdid {
+    /// The bytes of the base block and its extensions, as parsed.
+    pub fn encode(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The raw 128 bytes of a block, the base block being 0.
+    pub fn block(&self, index: usize) -> Option<&[u8]> {
+        self.bytes.chunks(128).nth(index)
+    }
+
+    /// The raw 128 bytes of the extension block at `index`, in the order of
+    /// `edid.extensions`.
+    pub fn extension_block(&self, index: usize) -> Option<&[u8]> {
+        self.block(index + 1)
+    }
+
+    /// The raw 18 bytes of the base block descriptor at `index`, in the
+    /// order of `edid.descriptors`.
+    pub fn descriptor(&self, index: usize) -> Option<&[u8]> {
+        if index >= 4 {
+            return None;
+        }
+        let start = 54 + index * 18;
+        self.bytes.get(start..start + 18)
+    }
+
+    /// Encodes `edid`, keeping the parsed bytes of every part that wasn't
+    /// edited: the output equals `as_bytes()` when `edid` is unchanged.
+    ///
+    /// The base block is patched field by field, so the chromaticity
+    /// coordinates and untouched descriptors keep their bytes, and an
+    /// extension block is only re-encoded when its model differs from the
+    /// parsed one, or when it holds an EEODB and the extension count
+    /// changed.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        // Only bytes deserialized from elsewhere can fail to parse
+        let original = match parse(&self.bytes) {
+            Ok((_, original)) => original,
+            Err(_) => return self.edid.to_bytes(),
+        };
+        if self.edid == original {
+            return Ok(self.bytes.clone());
+        }
+        let edid = &self.edid;
+        let count = edid.extensions.len();
+        if count > MAX_EXTENSIONS {
+            return Err(EncodeError::TooManyExtensions { count });
+        }
+
+        let encoded = base_block(edid)?;
+        let mut base = self.bytes[..127].to_vec();
+        let mut patch = |range: std::ops::Range<usize>, edited: bool| {
+            if edited {
+                base[range.clone()].copy_from_slice(&encoded[range]);
+            }
+        };
+        patch(8..20, edid.header != original.header);
+        patch(20..25, edid.display != original.display);
+        patch(
+            35..38,
+            edid.established_timing != original.established_timing,
+        );
+        patch(38..54, edid.standard_timing != original.standard_timing);
+        for i in 0..4 {
+            let start = 54 + i * 18;
+            let edited = edid.descriptors.get(i) != original.descriptors.get(i);
+            patch(start..start + 18, edited);
+        }
+        patch(126..127, encoded[126] != self.bytes[126]);
+        let mut data = finish_block(base);
+
+        let count_changed = count != original.extensions.len();
+        for (i, extension) in edid.extensions.iter().enumerate() {
+            let kept = original.extensions.get(i) == Some(extension)
+                && !(count_changed && has_extension_override(extension));
+            match self.extension_block(i) {
+                Some(block) if kept => data.extend_from_slice(block),
+                _ => data.extend_from_slice(&extension_block(extension, i + 1, count)?),
+            }
+        }
+        Ok(data)
+    }
+
+    pub fn into_inner(self) -> EDID {
+        self.edid
+    }
+}
+
+/// Like `parse`, keeping the consumed bytes for a byte-exact re-emit.
+pub fn parse_preserved(data: &[u8]) -> Result<(&[u8], PreservedEdid), EdidError> {
+    let (rest, edid) = parse(data)?;
+    let bytes = data[..data.offset(rest)].to_vec();
+    Ok((rest, PreservedEdid { edid, bytes }))
+}
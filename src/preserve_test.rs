@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use crate::{checksum::fix_checksums, edid::*, preserve::*};
+    use proptest::prelude::*;
+
+    const SAMPLES: [&[u8]; 5] = [
+        include_bytes!("../testdata/card0-VGA-1.bin"),
+        include_bytes!("../testdata/card0-HDMI-1.bin"),
+        include_bytes!("../testdata/card0-HDMI-2.bin"),
+        include_bytes!("../testdata/card0-eDP-1.bin"),
+        include_bytes!("../testdata/card0-LVDS-1.bin"),
+    ];
+
+    /// A sample with some bytes replaced, past the header and other than
+    /// the extension count, with valid checksums.
+    fn mutated(sample: usize, edits: &[(u16, u8)]) -> Vec<u8> {
+        let mut data = SAMPLES[sample].to_vec();
+        for (offset, value) in edits {
+            let offset = 8 + *offset as usize % (data.len() - 8);
+            if offset != 126 {
+                data[offset] = *value;
+            }
+        }
+        fix_checksums(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_preserved_round_trip() {
+        for name in ["card0-VGA-1", "card0-HDMI-1", "card0-HDMI-2", "card0-eDP-1"] {
+            let data = std::fs::read(format!("testdata/{}.bin", name)).unwrap();
+            let (_, preserved) = parse_preserved(&data).unwrap();
+            assert_eq!(preserved.encode(), data, "{}", name);
+            assert_eq!(preserved.edid, parse(&data).unwrap().1, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_preserved_trailing_bytes() {
+        let mut data = include_bytes!("../testdata/card0-HDMI-1.bin").to_vec();
+        let blocks = data.clone();
+        data.extend_from_slice(&[0xaa; 7]);
+
+        let (rest, preserved) = parse_preserved(&data).unwrap();
+        assert_eq!(rest, &[0xaa; 7]);
+        assert_eq!(preserved.as_bytes(), &blocks[..]);
+        assert_eq!(preserved.block(1), Some(&blocks[128..256]));
+        assert_eq!(preserved.block(2), None);
+    }
+
+    #[test]
+    fn test_preserved_descriptors() {
+        let data = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, preserved) = parse_preserved(data).unwrap();
+        assert_eq!(preserved.descriptor(0), Some(&data[54..72]));
+        assert_eq!(preserved.descriptor(3), Some(&data[108..126]));
+        assert_eq!(preserved.descriptor(4), None);
+        assert_eq!(preserved.extension_block(0), Some(&data[128..256]));
+        assert_eq!(preserved.extension_block(1), None);
+
+        if let Descriptor::DetailedTiming(timing) = &preserved.edid.descriptors[0] {
+            assert_eq!(&timing.to_bytes()[..], preserved.descriptor(0).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_preserved_to_bytes_edit() {
+        let data = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, mut preserved) = parse_preserved(data).unwrap();
+        assert_eq!(preserved.to_bytes().unwrap(), &data[..]);
+
+        preserved.edid.descriptors[2] = Descriptor::ProductName("EDITED".to_string());
+        let encoded = preserved.to_bytes().unwrap();
+        assert_eq!(&encoded[..90], &data[..90]);
+        assert_eq!(&encoded[108..127], &data[108..127]);
+        assert_eq!(&encoded[128..], &data[128..]);
+        assert_eq!(parse(&encoded).unwrap().1, preserved.edid);
+
+        preserved.edid.descriptors[2] = Descriptor::ProductName("A name too long".to_string());
+        assert!(preserved.to_bytes().is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_preserved_round_trip(
+            sample in 0..SAMPLES.len(),
+            edits in prop::collection::vec(any::<(u16, u8)>(), 0..16),
+        ) {
+            let data = mutated(sample, &edits);
+            if let Ok((_, preserved)) = parse_preserved(&data) {
+                prop_assert_eq!(preserved.to_bytes().unwrap(), data);
+            }
+        }
+
+        #[test]
+        fn prop_preserved_edit_keeps_other_bytes(
+            sample in 0..SAMPLES.len(),
+            edits in prop::collection::vec(any::<(u16, u8)>(), 0..16),
+            name in "[A-Z0-9-]{1,13}",
+        ) {
+            let data = mutated(sample, &edits);
+            let (_, mut preserved) = match parse_preserved(&data) {
+                Ok(parsed) => parsed,
+                Err(_) => return Ok(()),
+            };
+            let index = preserved.edid.descriptors.len() - 1;
+            preserved.edid.descriptors[index] = Descriptor::ProductName(name);
+
+            let encoded = preserved.to_bytes().unwrap();
+            prop_assert_eq!(&encoded[..108], &data[..108]);
+            prop_assert_eq!(&encoded[128..], &data[128..]);
+            let (_, reparsed) = parse_preserved(&encoded).unwrap();
+            prop_assert_eq!(&reparsed.edid.descriptors, &preserved.edid.descriptors);
+            prop_assert_eq!(reparsed.to_bytes().unwrap(), encoded);
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::{fmt, io};
+
+#[cfg(feature = "cta")]
+use crate::extension::{parse_extension_block, Extension};
+use crate::{
+    edid::{BLOCK_SIZE, EDID},
+    error::EdidError,
+};
+
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    /// The blocks were read but couldn't be parsed.
+    Parse(EdidError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReadError::Io(err) => write!(f, "failed to read EDID: {}", err),
+            ReadError::Parse(err) => write!(f, "failed to parse EDID: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Io(err) => Some(err),
+            ReadError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(err: io::Error) -> Self {
+        ReadError::Io(err)
+    }
+}
+
+/// Iterator over a stream of concatenated EDIDs, see [`iter_from_reader`].
+pub struct EdidReader<R> {
+    reader: R,
+    done: bool,
+}
+
+/// Reads concatenated EDIDs from `reader` one at a time.
+///
+/// Each EDID is sized from the extension count in its base block. Parse
+/// errors don't stop the iteration, but I/O errors do.
+pub fn iter_from_reader<R: io::Read>(reader: R) -> EdidReader<R> {
+    EdidReader {
+        reader,
+        done: false,
+    }
+}
+
+impl<R: io::Read> EdidReader<R> {
+    /// Fills `buf`, returning `false` on a clean end of stream.
+    fn read_block(&mut self, buf: &mut [u8]) -> io::Result<bool> {
+        let mut read = 0;
+        while read < buf.len() {
+            match self.reader.read(&mut buf[read..]) {
+                Ok(0) if read == 0 => return Ok(false),
+                Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+                Ok(n) => read += n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(true)
+    }
+
+    fn read_edid(&mut self) -> Result<Option<Vec<u8>>, ReadError> {
+        let mut data = vec![0; BLOCK_SIZE];
+        if !self.read_block(&mut data)? {
+            return Ok(None);
+        }
+
+        let extensions = data[126] as usize;
+        data.resize(BLOCK_SIZE * (extensions + 1), 0);
+        self.reader.read_exact(&mut data[BLOCK_SIZE..])?;
+
+        #[cfg(feature = "cta")]
+        if let Some(total) = extension_override(&data).filter(|total| *total > extensions) {
+            data.resize(BLOCK_SIZE * (total + 1), 0);
+            self.reader
+                .read_exact(&mut data[BLOCK_SIZE * (extensions + 1)..])?;
+        }
+        Ok(Some(data))
+    }
+}
+
+/// The extension count of an EEODB in the first extension, if any.
+#[cfg(feature = "cta")]
+fn extension_override(data: &[u8]) -> Option<usize> {
+    match parse_extension_block(data.get(BLOCK_SIZE..)?) {
+        Ok((_, Extension::Cta(cta))) => cta.extension_override().map(usize::from),
+        _ => None,
+    }
+}
+
+impl EDID {
+    /// Reads and parses one EDID from `reader`, e.g. a sysfs `edid` file.
+    ///
+    /// Reads the base block, then as many extension blocks as it announces,
+    /// leaving any further bytes unread. An empty stream is an
+    /// `UnexpectedEof` I/O error.
+    pub fn from_reader<R: io::Read>(reader: R) -> Result<EDID, ReadError> {
+        match iter_from_reader(reader).read_edid()? {
+            Some(data) => EDID::parse(&data).map_err(ReadError::Parse),
+            None => Err(ReadError::Io(io::ErrorKind::UnexpectedEof.into())),
+        }
+    }
+}
+
+impl<R: io::Read> Iterator for EdidReader<R> {
+    type Item = Result<EDID, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let data = match self.read_edid() {
+            Ok(Some(data)) => data,
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(err) => {
+                self.done = true;
+                return Some(Err(err));
+            }
+        };
+
+        Some(EDID::parse(&data).map_err(ReadError::Parse))
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::EDID, reader::*};
+    use std::io::Cursor;
+
+    #[test]
+    fn test_iter_from_reader() {
+        let mut data = Vec::new();
+        data.extend_from_slice(include_bytes!("../testdata/card0-VGA-1.bin"));
+        data.extend_from_slice(include_bytes!("../testdata/card0-HDMI-1.bin"));
+        data.extend_from_slice(include_bytes!("../testdata/card0-eDP-1.bin"));
+
+        let edids = iter_from_reader(Cursor::new(data))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let vendors: Vec<_> = edids.iter().map(|edid| edid.header.vendor).collect();
+        assert_eq!(
+            vendors,
+            vec![['S', 'A', 'M'], ['D', 'E', 'L'], ['S', 'H', 'P']]
+        );
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut data = d.to_vec();
+        data.extend_from_slice(&[0xaa; 7]);
+
+        let mut cursor = Cursor::new(data);
+        let edid = EDID::from_reader(&mut cursor).unwrap();
+        assert_eq!(edid, EDID::parse(d).unwrap());
+        // Only the announced blocks are consumed
+        assert_eq!(cursor.position(), d.len() as u64);
+
+        assert!(matches!(
+            EDID::from_reader(Cursor::new(&d[..200])),
+            Err(ReadError::Io(_))
+        ));
+        assert!(matches!(
+            EDID::from_reader(Cursor::new(Vec::new())),
+            Err(ReadError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_iter_from_reader_truncated() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let mut iter = iter_from_reader(Cursor::new(&d[..200]));
+
+        assert!(matches!(iter.next(), Some(Err(ReadError::Io(_)))));
+        assert!(iter.next().is_none());
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_iter_from_reader_extension_override() {
+        use crate::builder::CtaBuilder;
+
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(
+            &CtaBuilder::new()
+                .raw_data_block(7, &[0x78, 2])
+                .build()
+                .unwrap(),
+        );
+        data.extend_from_slice(&CtaBuilder::new().build().unwrap());
+        data.extend_from_slice(include_bytes!("../testdata/card0-HDMI-1.bin"));
+
+        let edids = iter_from_reader(Cursor::new(data))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(edids.len(), 2);
+        assert_eq!(edids[0].extensions.len(), 2);
+        assert_eq!(edids[1].header.vendor, ['D', 'E', 'L']);
+    }
+}
@@ -0,0 +1,155 @@
+// Example EDIDs in the spirit of the VESA E-EDID and CTA-861 samples,
+// generated with the builders and checked against the parsed structures.
+#[cfg(test)]
+mod tests {
+    use crate::{builder::*, cta::*, edid::*, mode::cvt_timing};
+
+    /// DMT 1920x1080 at 60 Hz, 531x299 mm.
+    fn dmt_1080p() -> DetailedTiming {
+        DetailedTiming {
+            pixel_clock: 148_500,
+            horizontal_active_pixels: 1920,
+            horizontal_blanking_pixels: 280,
+            vertical_active_lines: 1080,
+            vertical_blanking_lines: 45,
+            horizontal_front_porch: 88,
+            horizontal_sync_width: 44,
+            vertical_front_porch: 4,
+            vertical_sync_width: 5,
+            horizontal_size: 531,
+            vertical_size: 299,
+            horizontal_border_pixels: 0,
+            vertical_border_pixels: 0,
+            features: 0x1e,
+        }
+    }
+
+    /// CTA-861 720p60, VIC 4.
+    fn cta_720p() -> DetailedTiming {
+        DetailedTiming {
+            pixel_clock: 74_250,
+            horizontal_active_pixels: 1280,
+            horizontal_blanking_pixels: 370,
+            vertical_active_lines: 720,
+            vertical_blanking_lines: 30,
+            horizontal_front_porch: 110,
+            horizontal_sync_width: 40,
+            vertical_front_porch: 5,
+            vertical_sync_width: 5,
+            horizontal_size: 531,
+            vertical_size: 299,
+            horizontal_border_pixels: 0,
+            vertical_border_pixels: 0,
+            features: 0x1e,
+        }
+    }
+
+    #[test]
+    fn test_sample_edid_1_4_monitor() {
+        let data = EdidBuilder::new()
+            .vendor(['V', 'E', 'S'])
+            .product(0x1234)
+            .serial(1)
+            .manufacture_date(12, 2021)
+            // Digital, 8 bits per color, DisplayPort
+            .video_input(0xa5)
+            .screen_size_cm(53, 30)
+            .detailed_timing(&dmt_1080p())
+            .detailed_timing(&cvt_timing(1280, 1024, 60.0))
+            .product_name("VESA SAMPLE")
+            .serial_number("0000001")
+            .build()
+            .unwrap();
+        assert_eq!(data.len(), 128);
+
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(edid.header.vendor_string(), "VES");
+        assert_eq!((edid.header.version, edid.header.revision), (1, 4));
+        assert_eq!(edid.header.year_ad(), 2021);
+        assert_eq!(
+            edid.display.decoded_video_input(),
+            VideoInput::Digital {
+                color_bit_depth: Some(8),
+                interface: DigitalInterface::DisplayPort,
+            }
+        );
+        assert_eq!(
+            edid.descriptors[..2],
+            [
+                Descriptor::DetailedTiming(dmt_1080p()),
+                Descriptor::DetailedTiming(cvt_timing(1280, 1024, 60.0)),
+            ]
+        );
+        let info = edid.product_info();
+        assert_eq!(info.product_name.as_deref(), Some("VESA SAMPLE"));
+        assert_eq!(info.serial_number.as_deref(), Some("0000001"));
+    }
+
+    #[test]
+    fn test_sample_cta_861_tv() {
+        let cta = CtaBuilder::new()
+            .flags(
+                CtaExtensions::DTD_BASIC_AUDIO
+                    | CtaExtensions::DTD_YUV444
+                    | CtaExtensions::DTD_YUV422,
+            )
+            .native_dtds(1)
+            // VIC 16 (native), 4, 3 and 2
+            .raw_data_block(2, &[0x90, 0x04, 0x03, 0x02])
+            // 2 channel LPCM at 32, 44.1 and 48 kHz, 16-24 bit
+            .raw_data_block(1, &[0x09, 0x07, 0x07])
+            .raw_data_block(4, &[0x01, 0x00, 0x00])
+            .raw_data_block(3, &[0x03, 0x0c, 0x00, 0x10, 0x00])
+            .detailed_timing(&cta_720p());
+        let data = EdidBuilder::new()
+            .vendor(['C', 'T', 'A'])
+            .video_input(0x80)
+            .detailed_timing(&dmt_1080p())
+            .product_name("CTA SAMPLE")
+            .cta(cta)
+            .build()
+            .unwrap();
+        assert_eq!(data.len(), 256);
+
+        let (_, edid) = parse(&data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        assert!(cta.is_v3());
+        assert_eq!(cta.native_dtd.number_of_native_dtd, 1);
+        assert_eq!(cta.native_dtd.basic_audio, 1);
+        assert_eq!(
+            cta.short_video_descriptors()
+                .map(|svd| (svd.cea861_index, svd.is_native))
+                .collect::<Vec<_>>(),
+            vec![(16, 1), (4, 0), (3, 0), (2, 0)]
+        );
+        assert!(matches!(
+            &cta.blocks[1],
+            DataBlock::AudioBlock(audio) if audio.descriptors[0].format() == AudioFormat::Lpcm
+                && audio.descriptors[0].number_of_channels == 2
+        ));
+        assert!(matches!(
+            &cta.blocks[2],
+            DataBlock::SpeakerAllocation(alloc) if alloc.speakers == Speakers::FL_FR
+        ));
+        let hdmi = match &cta.blocks[3] {
+            DataBlock::VendorSpecific(vsdb) => vsdb.hdmi().unwrap(),
+            block => panic!("unexpected block {:?}", block),
+        };
+        assert_eq!(hdmi.physical_address, [1, 0, 0, 0]);
+        assert_eq!(cta.descriptors, vec![cta_720p()]);
+    }
+
+    #[test]
+    fn test_detailed_timing_round_trip() {
+        // Vertical porch and sync use the upper bits of byte 11
+        let timing = DetailedTiming {
+            vertical_front_porch: 48,
+            vertical_sync_width: 20,
+            horizontal_front_porch: 600,
+            horizontal_sync_width: 300,
+            ..cta_720p()
+        };
+        let (_, parsed) = parse_detailed_timing(&timing.to_bytes()).unwrap();
+        assert_eq!(parsed, timing);
+    }
+}
@@ -0,0 +1,260 @@
+//! Serde support, behind the `serde` feature. The `schemars` feature adds
+//! `JsonSchema` to the same types.
+
+use serde::{Serialize, Serializer};
+
+use crate::edid::{Descriptor, DetailedTiming, Display, FeatureSupport, VideoInput, EDID};
+
+/// Serializes the wrapped `EDID` or `Display` with its bitfield bytes
+/// verbose: next to the raw integer, `video_input` and `features` get a hex
+/// string (`video_input_hex`) and their decoded form
+/// (`video_input_decoded`). An `EDID` keeps its derived fields and gets
+/// them in `display_decoded`. Deserializing into the plain type ignores the
+/// extra fields, and the JSON Schema of the `schemars` feature describes the
+/// plain output.
+///
+/// ```
+/// # let data = include_bytes!("../testdata/card0-VGA-1.bin");
+/// let edid = edidr::EDID::parse(data).unwrap();
+/// let json = serde_json::to_string(&edidr::Decoded(&edid)).unwrap();
+/// assert!(json.contains(r#""video_input_hex":"0x0e""#));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct Decoded<'a, T>(pub &'a T);
+
+// The fields `Decoded` adds to a `Display`
+#[derive(Serialize)]
+struct DisplayBitfields {
+    video_input_hex: String,
+    video_input_decoded: VideoInput,
+    features_hex: String,
+    features_decoded: FeatureSupport,
+}
+
+impl DisplayBitfields {
+    fn new(display: &Display) -> Self {
+        DisplayBitfields {
+            video_input_hex: format!("{:#04x}", display.video_input),
+            video_input_decoded: display.decoded_video_input(),
+            features_hex: format!("{:#04x}", display.features),
+            features_decoded: display.decoded_features(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DecodedDisplay<'a> {
+    #[serde(flatten)]
+    display: &'a Display,
+    #[serde(flatten)]
+    bitfields: DisplayBitfields,
+}
+
+#[derive(Serialize)]
+struct DecodedEdid<'a> {
+    #[serde(flatten)]
+    edid: &'a EDID,
+    display_decoded: DisplayBitfields,
+}
+
+impl Serialize for Decoded<'_, Display> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DecodedDisplay {
+            display: self.0,
+            bitfields: DisplayBitfields::new(self.0),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl Serialize for Decoded<'_, EDID> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DecodedEdid {
+            edid: self.0,
+            display_decoded: DisplayBitfields::new(&self.0.display),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Version of the `EDID::to_json` layout, bumped on incompatible changes.
+pub const JSON_FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct JsonExport<'a> {
+    format_version: u32,
+    version: String,
+    vendor: String,
+    vendor_name: Option<&'static str>,
+    product_code: u16,
+    serial: u32,
+    product_name: Option<String>,
+    serial_number: Option<String>,
+    manufacture_week: u8,
+    manufacture_year: u16,
+    digital: bool,
+    physical_size_mm: Option<JsonSize>,
+    extensions: Vec<String>,
+    preferred_mode: Option<JsonMode>,
+    modes: Vec<JsonMode>,
+    edid: &'a EDID,
+}
+
+#[derive(Serialize)]
+struct JsonSize {
+    width: u16,
+    height: u16,
+}
+
+#[derive(Serialize, Clone)]
+struct JsonMode {
+    name: String,
+    width: u16,
+    height: u16,
+    refresh_rate: f64,
+    interlaced: bool,
+    pixel_clock_khz: Option<u32>,
+    source: &'static str,
+    vic: Option<u8>,
+}
+
+impl JsonMode {
+    fn new(
+        width: u16,
+        height: u16,
+        refresh_rate: f64,
+        interlaced: bool,
+        source: &'static str,
+    ) -> Self {
+        JsonMode {
+            name: format!(
+                "{}x{}{}@{}",
+                width,
+                height,
+                if interlaced { "i" } else { "" },
+                refresh_rate.round()
+            ),
+            width,
+            height,
+            refresh_rate: (refresh_rate * 1000.0).round() / 1000.0,
+            interlaced,
+            pixel_clock_khz: None,
+            source,
+            vic: None,
+        }
+    }
+
+    fn from_timing(timing: &DetailedTiming, source: &'static str) -> Self {
+        let interlaced = timing.is_interlaced();
+        let height = if interlaced {
+            timing.vertical_active_lines * 2
+        } else {
+            timing.vertical_active_lines
+        };
+        JsonMode {
+            pixel_clock_khz: Some(timing.pixel_clock),
+            ..JsonMode::new(
+                timing.horizontal_active_pixels,
+                height,
+                timing.refresh_rate(),
+                interlaced,
+                source,
+            )
+        }
+    }
+}
+
+fn json_modes(edid: &EDID) -> Vec<JsonMode> {
+    let mut modes = Vec::new();
+    for descriptor in &edid.descriptors {
+        if let Descriptor::DetailedTiming(timing) = descriptor {
+            modes.push(JsonMode::from_timing(timing, "detailed"));
+        }
+    }
+    for st in &edid.standard_timing {
+        modes.push(JsonMode::new(
+            st.horizontal_active_pixels,
+            st.vertical_active_lines(),
+            st.refresh_rate as f64,
+            false,
+            "standard",
+        ));
+    }
+    #[cfg(feature = "cta")]
+    for cta in edid.cta_extensions() {
+        for timing in &cta.descriptors {
+            modes.push(JsonMode::from_timing(timing, "cta_detailed"));
+        }
+        for svd in cta.short_video_descriptors() {
+            if let Some(format) = svd.video_format() {
+                modes.push(JsonMode {
+                    vic: Some(format.vic),
+                    ..JsonMode::from_timing(&format.timing, "vic")
+                });
+            }
+        }
+    }
+    modes
+}
+
+impl EDID {
+    /// Pretty-printed JSON meant for scripts, with derived values next to
+    /// the parsed data model:
+    ///
+    /// - `format_version`: `JSON_FORMAT_VERSION`
+    /// - `version`: EDID version, e.g. `"1.3"`
+    /// - `vendor`, `vendor_name`, `product_code`, `serial`, `product_name`,
+    ///   `serial_number`, `manufacture_week`, `manufacture_year`
+    /// - `digital`: whether the video input is digital
+    /// - `physical_size_mm`: `{"width", "height"}` or `null`
+    /// - `extensions`: extension names, e.g. `"cta"`, or the hex tag if unknown
+    /// - `preferred_mode`, `modes`: objects with `name` (e.g.
+    ///   `"1920x1080@60"`), `width`, `height`, `refresh_rate` in Hz,
+    ///   `interlaced`, `pixel_clock_khz` (`null` for standard timings),
+    ///   `source` (`"detailed"`, `"standard"`, `"cta_detailed"` or `"vic"`)
+    ///   and `vic`
+    /// - `edid`: the serde representation of the `EDID`
+    ///
+    /// Fields are only added within a format version.
+    ///
+    /// ```
+    /// # let data = include_bytes!("../testdata/card0-VGA-1.bin");
+    /// let edid = edidr::EDID::parse(data).unwrap();
+    /// let json: serde_json::Value = serde_json::from_str(&edid.to_json()).unwrap();
+    /// assert_eq!(json["preferred_mode"]["name"], "1680x1050@60");
+    /// ```
+    pub fn to_json(&self) -> String {
+        let info = self.product_info();
+        let modes = json_modes(self);
+        let export = JsonExport {
+            format_version: JSON_FORMAT_VERSION,
+            version: format!("{}.{}", self.header.version, self.header.revision),
+            vendor: self.header.vendor_string(),
+            vendor_name: self.header.vendor_name(),
+            product_code: info.product,
+            serial: info.serial,
+            product_name: info.product_name.clone(),
+            serial_number: info.serial_number.clone(),
+            manufacture_week: info.week,
+            manufacture_year: info.year_ad(),
+            digital: self.display.is_digital(),
+            physical_size_mm: self
+                .physical_size_mm()
+                .map(|(width, height)| JsonSize { width, height }),
+            extensions: self
+                .extensions
+                .iter()
+                .map(|ext| match ext.extension_tag() {
+                    Some(tag) => tag.as_str().to_string(),
+                    None => format!("{:#04x}", ext.tag()),
+                })
+                .collect(),
+            preferred_mode: self
+                .preferred_timing()
+                .map(|timing| JsonMode::from_timing(timing, "detailed")),
+            modes,
+            edid: self,
+        };
+        serde_json::to_string_pretty(&export).expect("EDID serializes to JSON")
+    }
+}
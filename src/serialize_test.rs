@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::EDID, serialize::*};
+
+    const SAMPLES: &[&[u8]] = &[
+        include_bytes!("../testdata/card0-HDMI-1.bin"),
+        include_bytes!("../testdata/card0-HDMI-2.bin"),
+        include_bytes!("../testdata/card0-LVDS-1.bin"),
+        include_bytes!("../testdata/card0-VGA-1.bin"),
+        include_bytes!("../testdata/card0-eDP-1.bin"),
+    ];
+
+    #[test]
+    fn test_json_round_trip() {
+        for data in SAMPLES {
+            let edid = EDID::parse(data).unwrap();
+            let json = serde_json::to_string(&edid).unwrap();
+            assert!(!json.contains("video_input_hex"));
+            let back: EDID = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, edid);
+
+            let json = serde_json::to_string(&Decoded(&edid)).unwrap();
+            assert!(json.contains("video_input_hex"));
+            let back: EDID = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, edid);
+        }
+    }
+
+    #[test]
+    fn test_serialize_decoded() {
+        let edid = EDID::parse(SAMPLES[0]).unwrap();
+        let value = serde_json::to_value(Decoded(&edid.display)).unwrap();
+        assert_eq!(value["video_input"], 0x80);
+        assert_eq!(value["video_input_hex"], "0x80");
+        assert!(value["video_input_decoded"]["Digital"].is_object());
+        assert!(value["features_decoded"]["dpms_standby"].is_boolean());
+
+        let value = serde_json::to_value(edid.display).unwrap();
+        assert!(value.get("video_input_hex").is_none());
+
+        // Every derived field is kept
+        let plain = serde_json::to_value(&edid).unwrap();
+        let value = serde_json::to_value(Decoded(&edid)).unwrap();
+        for (field, plain) in plain.as_object().unwrap() {
+            assert_eq!(&value[field], plain, "{}", field);
+        }
+        assert_eq!(value["display_decoded"]["features_hex"], "0xea");
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_to_json() {
+        let edid = EDID::parse(SAMPLES[0]).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&edid.to_json()).unwrap();
+        assert_eq!(value["format_version"], JSON_FORMAT_VERSION);
+        assert_eq!(value["vendor"], edid.header.vendor_string());
+        assert_eq!(value["digital"], true);
+        assert_eq!(value["extensions"][0], "cta");
+
+        let preferred = &value["preferred_mode"];
+        let timing = edid.preferred_timing().unwrap();
+        assert_eq!(preferred["width"], timing.horizontal_active_pixels);
+        assert_eq!(preferred["source"], "detailed");
+        assert!(preferred["name"].as_str().unwrap().contains('@'));
+
+        let modes = value["modes"].as_array().unwrap();
+        assert!(modes.iter().any(|mode| mode["source"] == "standard"));
+        assert!(modes.iter().any(|mode| mode["vic"].is_u64()));
+
+        let back: EDID = serde_json::from_value(value["edid"].clone()).unwrap();
+        assert_eq!(back, edid);
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(EDID)).unwrap();
+        for field in ["header", "display", "descriptors", "extensions"] {
+            assert!(schema["properties"][field].is_object(), "{}", field);
+        }
+        assert!(schema["$defs"]["DetailedTiming"].is_object());
+
+        // A serialized EDID has every required field of the schema
+        let value = serde_json::to_value(EDID::parse(SAMPLES[0]).unwrap()).unwrap();
+        for field in schema["required"].as_array().unwrap() {
+            assert!(value.get(field.as_str().unwrap()).is_some(), "{}", field);
+        }
+    }
+}
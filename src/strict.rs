@@ -0,0 +1,104 @@
+use crate::{
+    checksum::checksum_mismatches,
+    edid::{parse, BLOCK_SIZE, EDID},
+    error::{EdidError, EdidErrorKind},
+    extension::Extension,
+};
+
+const VIDEO_INPUT: usize = 20;
+const STANDARD_TIMINGS: usize = 38;
+const DESCRIPTORS: usize = 54;
+const RANGE_LIMITS_TAG: u8 = 0xFD;
+
+impl EDID {
+    /// Like [`EDID::parse`], also rejecting data that doesn't conform to
+    /// the spec: bad checksums, reserved bits or values, display
+    /// descriptors with reserved bytes set, and bytes after the last block.
+    pub fn parse_strict(data: &[u8]) -> Result<EDID, EdidError> {
+        let (rest, edid) = parse(data)?;
+        let len = data.len() - rest.len();
+        if !rest.is_empty() {
+            return Err(error(len, EdidErrorKind::TrailingData { len: rest.len() }));
+        }
+        if let Some(mismatch) = checksum_mismatches(data).into_iter().next() {
+            return Err(mismatch.into());
+        }
+
+        let v14 = (edid.header.version, edid.header.revision) >= (1, 4);
+        if edid.header.version != 1 {
+            return Err(invalid(18, "EDID version", edid.header.version));
+        }
+        check_video_input(data[VIDEO_INPUT], v14)?;
+        check_standard_timings(data)?;
+        check_descriptors(data, v14)?;
+        for (index, block) in data[BLOCK_SIZE..].chunks_exact(BLOCK_SIZE).enumerate() {
+            check_extension(block, BLOCK_SIZE * (index + 1))?;
+        }
+        Ok(edid)
+    }
+}
+
+fn error(offset: usize, kind: EdidErrorKind) -> EdidError {
+    EdidError { kind, offset }
+}
+
+fn invalid(offset: usize, field: &'static str, found: u8) -> EdidError {
+    error(offset, EdidErrorKind::InvalidField { field, found })
+}
+
+fn check_video_input(v: u8, v14: bool) -> Result<(), EdidError> {
+    let reserved = match (v & 0x80 != 0, v14) {
+        // Analog inputs use every bit
+        (false, _) => false,
+        (true, true) => (v >> 4) & 0x7 == 0x7 || v & 0xf > 5,
+        // Only the DFP 1.x compatibility bit is defined before EDID 1.4
+        (true, false) => v & 0x7e != 0,
+    };
+    if reserved {
+        return Err(invalid(VIDEO_INPUT, "video input definition", v));
+    }
+    Ok(())
+}
+
+fn check_standard_timings(data: &[u8]) -> Result<(), EdidError> {
+    let timings = &data[STANDARD_TIMINGS..DESCRIPTORS];
+    for (index, timing) in timings.chunks_exact(2).enumerate() {
+        // Unused slots are 01 01, a zero first byte has no meaning
+        if timing[0] == 0x00 {
+            return Err(invalid(
+                STANDARD_TIMINGS + 2 * index,
+                "standard timing",
+                timing[0],
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn check_descriptors(data: &[u8], v14: bool) -> Result<(), EdidError> {
+    let descriptors = &data[DESCRIPTORS..DESCRIPTORS + 4 * 18];
+    for (index, d) in descriptors.chunks_exact(18).enumerate() {
+        if d[0] != 0 || d[1] != 0 {
+            continue;
+        }
+        // Range limits use the low nibble of byte 4 for offsets since 1.4
+        let flags_mask = match d[3] {
+            RANGE_LIMITS_TAG if v14 => 0xf0,
+            _ => 0xff,
+        };
+        if d[2] != 0 || d[4] & flags_mask != 0 || (0x11..=0xF6).contains(&d[3]) {
+            return Err(error(
+                DESCRIPTORS + 18 * index,
+                EdidErrorKind::DescriptorFlags { index },
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn check_extension(block: &[u8], offset: usize) -> Result<(), EdidError> {
+    if block[0] == Extension::CTA && matches!(block[2], 1..=3 | 128..=255) {
+        return Err(invalid(offset + 2, "CTA DTD offset", block[2]));
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::EDID, error::*};
+
+    fn rejected(data: &[u8]) -> EdidError {
+        let mut data = data.to_vec();
+        // Keep the checksums valid so the other checks are reached
+        for block in data.chunks_exact_mut(128) {
+            let sum = block[..127].iter().fold(0u8, |sum, b| sum.wrapping_add(*b));
+            block[127] = 0u8.wrapping_sub(sum);
+        }
+        EDID::parse_strict(&data).unwrap_err()
+    }
+
+    #[test]
+    fn test_parse_strict() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        assert_eq!(EDID::parse_strict(d), EDID::parse(d));
+
+        let mut data = d.to_vec();
+        data[200] ^= 0x01;
+        assert!(matches!(
+            EDID::parse_strict(&data).unwrap_err().kind,
+            EdidErrorKind::Checksum { block: 1, .. }
+        ));
+
+        let mut data = d.to_vec();
+        data.push(0x00);
+        assert_eq!(
+            EDID::parse_strict(&data).unwrap_err(),
+            EdidError {
+                kind: EdidErrorKind::TrailingData { len: 1 },
+                offset: 256,
+            }
+        );
+    }
+
+    #[test]
+    fn test_reserved_bits() {
+        // An EDID 1.3 digital input only defines bit 0
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[18] = 1;
+        data[19] = 3;
+        data[20] = 0x82;
+        assert_eq!(
+            rejected(&data),
+            EdidError {
+                kind: EdidErrorKind::InvalidField {
+                    field: "video input definition",
+                    found: 0x82,
+                },
+                offset: 20,
+            }
+        );
+
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[38] = 0x00;
+        assert_eq!(
+            rejected(&data).kind,
+            EdidErrorKind::InvalidField {
+                field: "standard timing",
+                found: 0x00,
+            }
+        );
+
+        let mut data = include_bytes!("../testdata/card0-HDMI-1.bin").to_vec();
+        data[130] = 2;
+        assert_eq!(rejected(&data).offset, 130);
+    }
+
+    #[test]
+    fn test_descriptor_flags() {
+        let d = include_bytes!("../testdata/card0-VGA-1.bin");
+        // The last descriptor is a display descriptor
+        let offset = 54 + 3 * 18;
+        assert_eq!(d[offset..offset + 2], [0, 0]);
+
+        for (byte, value) in [(2, 0x01), (3, 0x20), (4, 0x10)] {
+            let mut data = d.to_vec();
+            data[offset + byte] = value;
+            assert_eq!(
+                rejected(&data),
+                EdidError {
+                    kind: EdidErrorKind::DescriptorFlags { index: 3 },
+                    offset,
+                },
+                "byte {} set to {:#04x}",
+                byte,
+                value
+            );
+        }
+    }
+}
@@ -0,0 +1,409 @@
+use std::fmt::{self, Write};
+
+#[cfg(feature = "displayid")]
+use crate::displayid::{DisplayId, DisplayIdDataBlock};
+#[cfg(feature = "cta")]
+use crate::{
+    cta::{CtaExtensions, DataBlock, FormatDetail, VendorSpecific},
+    extended::ExtendedDataBlock,
+};
+use crate::{
+    edid::{Descriptor, DetailedTiming, VideoInput, EDID},
+    extension::Extension,
+};
+
+fn timing(timing: &DetailedTiming) -> String {
+    format!(
+        "{}x{} {:.3} Hz, {:.3} MHz",
+        timing.horizontal_active_pixels,
+        timing.vertical_active_lines,
+        timing.refresh_rate(),
+        timing.pixel_clock as f64 / 1000.0
+    )
+}
+
+/// Writes a timing summary followed by the porches, sync widths and, for
+/// digital separate sync, the polarities.
+fn write_timing(out: &mut String, label: &str, t: &DetailedTiming) -> fmt::Result {
+    write!(out, "    {}: {}", label, timing(t))?;
+    if t.horizontal_size != 0 && t.vertical_size != 0 {
+        write!(out, " ({} mm x {} mm)", t.horizontal_size, t.vertical_size)?;
+    }
+    writeln!(out)?;
+
+    let polarity = |name: &str, bit: u8| match t.features & 0x18 {
+        0x18 if t.features & bit != 0 => format!(" {} P", name),
+        0x18 => format!(" {} N", name),
+        _ => String::new(),
+    };
+    let back = |blanking: u16, front: u16, sync: u16| blanking.saturating_sub(front + sync);
+    writeln!(
+        out,
+        "      Hfront {:4} Hsync {:3} Hback {:4}{}",
+        t.horizontal_front_porch,
+        t.horizontal_sync_width,
+        back(
+            t.horizontal_blanking_pixels,
+            t.horizontal_front_porch,
+            t.horizontal_sync_width
+        ),
+        polarity("Hpol", 0x02)
+    )?;
+    writeln!(
+        out,
+        "      Vfront {:4} Vsync {:3} Vback {:4}{}",
+        t.vertical_front_porch,
+        t.vertical_sync_width,
+        back(
+            t.vertical_blanking_lines,
+            t.vertical_front_porch,
+            t.vertical_sync_width
+        ),
+        polarity("Vpol", 0x04)
+    )
+}
+
+fn write_base_block(out: &mut String, edid: &EDID) -> fmt::Result {
+    let header = &edid.header;
+    writeln!(out, "Block 0, Base EDID:")?;
+    writeln!(
+        out,
+        "  EDID Structure Version & Revision: {}.{}",
+        header.version, header.revision
+    )?;
+    writeln!(out, "  Vendor & Product Identification:")?;
+    writeln!(out, "    Manufacturer: {}", header.vendor_string())?;
+    writeln!(out, "    Model: {}", header.product)?;
+    if header.serial != 0 {
+        writeln!(out, "    Serial Number: {}", header.serial)?;
+    }
+    match header.week {
+        0xff => writeln!(out, "    Model year: {}", header.year_ad())?,
+        0 => writeln!(out, "    Made in: {}", header.year_ad())?,
+        week => writeln!(out, "    Made in: week {} of {}", week, header.year_ad())?,
+    }
+
+    let display = &edid.display;
+    writeln!(out, "  Basic Display Parameters & Features:")?;
+    match display.decoded_video_input() {
+        VideoInput::Analog { .. } => writeln!(out, "    Analog display")?,
+        VideoInput::Digital {
+            color_bit_depth,
+            interface,
+        } => {
+            writeln!(out, "    Digital display")?;
+            if let Some(depth) = color_bit_depth {
+                writeln!(out, "    Bits per primary color channel: {}", depth)?;
+            }
+            writeln!(out, "    Interface: {:?}", interface)?;
+        }
+    }
+    if display.width != 0 && display.height != 0 {
+        writeln!(
+            out,
+            "    Maximum image size: {} cm x {} cm",
+            display.width, display.height
+        )?;
+    }
+    if display.gamma != 0xff {
+        writeln!(
+            out,
+            "    Gamma: {:.2}",
+            (display.gamma as f32 + 100.0) / 100.0
+        )?;
+    }
+
+    if !edid.standard_timing.is_empty() {
+        writeln!(out, "  Standard Timings:")?;
+        for st in &edid.standard_timing {
+            let (h, v) = st.aspect_ratio.ratio();
+            writeln!(
+                out,
+                "    {}x{} {} Hz {}:{}",
+                st.horizontal_active_pixels,
+                st.vertical_active_lines(),
+                st.refresh_rate,
+                h,
+                v
+            )?;
+        }
+    }
+
+    writeln!(out, "  Detailed Timing Descriptors:")?;
+    let mut dtd = 0;
+    for descriptor in &edid.descriptors {
+        match descriptor {
+            Descriptor::DetailedTiming(t) => {
+                dtd += 1;
+                write_timing(out, &format!("DTD {}", dtd), t)?;
+            }
+            Descriptor::ProductName(name) => writeln!(out, "    Display Product Name: '{}'", name)?,
+            Descriptor::SerialNumber(serial) => {
+                writeln!(out, "    Display Product Serial Number: '{}'", serial)?
+            }
+            Descriptor::UnspecifiedText(text) => {
+                writeln!(out, "    Alphanumeric Data String: '{}'", text)?
+            }
+            Descriptor::RangeLimits(limits) => writeln!(
+                out,
+                "    Display Range Limits: {}-{} Hz V, {}-{} kHz H, max dotclock {} MHz",
+                limits.min_vertical_rate,
+                limits.max_vertical_rate,
+                limits.min_horizontal_rate,
+                limits.max_horizontal_rate,
+                limits.max_pixel_clock / 1000
+            )?,
+            Descriptor::Dummy => writeln!(out, "    Dummy Descriptor")?,
+            other => writeln!(out, "    {:?}", other)?,
+        }
+    }
+    writeln!(out, "  Extension blocks: {}", edid.extensions.len())
+}
+
+#[cfg(feature = "cta")]
+fn data_block_name(block: &DataBlock) -> String {
+    match block {
+        DataBlock::AudioBlock(_) => "Audio Data Block".into(),
+        DataBlock::VideoBlock(_) => "Video Data Block".into(),
+        DataBlock::VendorSpecific(vsdb) => {
+            let [a, b, c] = vsdb.identifier;
+            let name = match vsdb.oui() {
+                VendorSpecific::HDMI_OUI => " (HDMI)",
+                VendorSpecific::HDMI_FORUM_OUI => " (HDMI Forum)",
+                VendorSpecific::AMD_OUI => " (AMD)",
+                VendorSpecific::NVIDIA_OUI => " (NVIDIA)",
+                VendorSpecific::MICROSOFT_OUI => " (Microsoft)",
+                _ => "",
+            };
+            format!(
+                "Vendor-Specific Data Block{}, OUI {:02X}-{:02X}-{:02X}",
+                name, c, b, a
+            )
+        }
+        DataBlock::TruncatedVendorSpecific(_) => "Vendor-Specific Data Block, truncated".into(),
+        DataBlock::SpeakerAllocation(_) => "Speaker Allocation Data Block".into(),
+        DataBlock::Extended(block) => extended_block_name(block),
+        DataBlock::Reserved(block) => format!("Unknown Data Block (tag {})", block.header.type_tag),
+    }
+}
+
+#[cfg(feature = "cta")]
+fn extended_block_name(block: &ExtendedDataBlock) -> String {
+    let name = match block {
+        ExtendedDataBlock::VideoCapability(_) => "Video Capability Data Block",
+        ExtendedDataBlock::VendorSpecificVideo(_) => "Vendor-Specific Video Data Block",
+        ExtendedDataBlock::Colorimetry(_) => "Colorimetry Data Block",
+        ExtendedDataBlock::HdrStaticMetadata(_) => "HDR Static Metadata Data Block",
+        ExtendedDataBlock::HdrDynamicMetadata(_) => "HDR Dynamic Metadata Data Block",
+        ExtendedDataBlock::NativeVideoResolution(_) => "Native Video Resolution Data Block",
+        ExtendedDataBlock::VideoFormatPreference(_) => "Video Format Preference Data Block",
+        ExtendedDataBlock::Ycbcr420Video(_) => "YCbCr 4:2:0 Video Data Block",
+        ExtendedDataBlock::Ycbcr420CapabilityMap(_) => "YCbCr 4:2:0 Capability Map Data Block",
+        ExtendedDataBlock::VendorSpecificAudio(_) => "Vendor-Specific Audio Data Block",
+        ExtendedDataBlock::RoomConfiguration(_) => "Room Configuration Data Block",
+        ExtendedDataBlock::SpeakerLocations(_) => "Speaker Location Data Block",
+        ExtendedDataBlock::InfoFrame(_) => "InfoFrame Data Block",
+        ExtendedDataBlock::HdmiForumSinkCapability(_) => "HDMI Forum Sink Capability Data Block",
+        ExtendedDataBlock::ExtensionOverride(_) => "HDMI Forum EDID Extension Override Data Block",
+        ExtendedDataBlock::Reserved(_) => {
+            return format!("Unknown Extended Data Block (tag {})", block.extended_tag())
+        }
+    };
+    name.into()
+}
+
+/// Writes the decoded contents of the data blocks that have a short form.
+#[cfg(feature = "cta")]
+fn write_data_block(out: &mut String, block: &DataBlock) -> fmt::Result {
+    match block {
+        DataBlock::VideoBlock(video) => {
+            for svd in &video.descriptors {
+                let native = if svd.is_native != 0 { " (native)" } else { "" };
+                match svd.video_format() {
+                    Some(format) => {
+                        writeln!(out, "    VIC {:3}: {}{}", svd.cea861_index, format, native)?
+                    }
+                    None => writeln!(out, "    VIC {:3}{}", svd.cea861_index, native)?,
+                }
+            }
+        }
+        DataBlock::AudioBlock(audio) => {
+            for sad in &audio.descriptors {
+                writeln!(out, "    {}:", sad.format())?;
+                writeln!(out, "      Max channels: {}", sad.number_of_channels)?;
+                let rates: Vec<_> = sad
+                    .sampling_rates()
+                    .iter()
+                    .map(|rate| (*rate as f64 / 1000.0).to_string())
+                    .collect();
+                writeln!(
+                    out,
+                    "      Supported sample rates (kHz): {}",
+                    rates.join(" ")
+                )?;
+                match sad.format_detail() {
+                    FormatDetail::Lpcm { bit_depths } => {
+                        let depths: Vec<_> = bit_depths.iter().map(u8::to_string).collect();
+                        writeln!(
+                            out,
+                            "      Supported sample sizes (bits): {}",
+                            depths.join(" ")
+                        )?
+                    }
+                    FormatDetail::MaxBitrate(rate) => {
+                        writeln!(out, "      Maximum bit rate: {} kb/s", rate)?
+                    }
+                    FormatDetail::Other(_) => {}
+                }
+            }
+        }
+        DataBlock::SpeakerAllocation(allocation) => {
+            for speaker in allocation.speakers.iter() {
+                writeln!(out, "    {}", speaker.name().unwrap_or("?"))?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(feature = "cta")]
+fn write_cta(out: &mut String, cta: &CtaExtensions) -> fmt::Result {
+    writeln!(out, "  Revision: {}", cta.revision())?;
+    let flags = &cta.native_dtd;
+    let yes_no = |flag: u8| if flag != 0 { "yes" } else { "no" };
+    writeln!(
+        out,
+        "  Underscans IT Video Formats by default: {}",
+        yes_no(flags.underscan)
+    )?;
+    writeln!(out, "  Basic audio support: {}", yes_no(flags.basic_audio))?;
+    writeln!(out, "  Supports YCbCr 4:4:4: {}", yes_no(flags.ycbcr444))?;
+    writeln!(out, "  Supports YCbCr 4:2:2: {}", yes_no(flags.ycbcr422))?;
+    writeln!(
+        out,
+        "  Native detailed modes: {}",
+        flags.number_of_native_dtd
+    )?;
+    for block in &cta.blocks {
+        writeln!(out, "  {}:", data_block_name(block))?;
+        write_data_block(out, block)?;
+    }
+    if !cta.descriptors.is_empty() {
+        writeln!(out, "  Detailed Timing Descriptors:")?;
+        for (i, t) in cta.descriptors.iter().enumerate() {
+            write_timing(out, &format!("DTD {}", i + 1), t)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "displayid")]
+fn write_displayid(out: &mut String, displayid: &DisplayId) -> fmt::Result {
+    writeln!(
+        out,
+        "  Version: {}.{}",
+        displayid.version, displayid.revision
+    )?;
+    for block in &displayid.blocks {
+        match block {
+            DisplayIdDataBlock::TypeITimings(timings)
+            | DisplayIdDataBlock::TypeViiTimings(timings) => {
+                let kind = match block {
+                    DisplayIdDataBlock::TypeITimings(_) => "I",
+                    _ => "VII",
+                };
+                writeln!(
+                    out,
+                    "  Video Timing Modes Type {} - Detailed Timings:",
+                    kind
+                )?;
+                for (i, t) in timings.iter().enumerate() {
+                    let preferred = if t.preferred { " (preferred)" } else { "" };
+                    write_timing(out, &format!("DTD {}{}", i + 1, preferred), &t.timing)?;
+                }
+            }
+            DisplayIdDataBlock::TiledTopology(tile) => {
+                writeln!(out, "  Tiled Display Topology:")?;
+                writeln!(
+                    out,
+                    "    Tile {}x{} at ({}, {}) of {}x{}",
+                    tile.tile_width,
+                    tile.tile_height,
+                    tile.horizontal_location,
+                    tile.vertical_location,
+                    tile.horizontal_tiles,
+                    tile.vertical_tiles
+                )?;
+            }
+            DisplayIdDataBlock::ProductId(product) => {
+                writeln!(out, "  Product Identification:")?;
+                writeln!(out, "    Product Code: {}", product.product_code)?;
+                if !product.product_name.is_empty() {
+                    writeln!(out, "    Product Name: '{}'", product.product_name)?;
+                }
+            }
+            #[cfg(feature = "cta")]
+            DisplayIdDataBlock::Cta(blocks) => {
+                writeln!(out, "  CTA-861 DisplayID Data Block:")?;
+                for block in blocks {
+                    writeln!(out, "  {}:", data_block_name(block))?;
+                    write_data_block(out, block)?;
+                }
+            }
+            DisplayIdDataBlock::Reserved(block) => {
+                writeln!(out, "  Unknown Data Block (tag 0x{:02x})", block.header.tag)?
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_extension(out: &mut String, index: usize, extension: &Extension) -> fmt::Result {
+    let name = match extension {
+        #[cfg(feature = "cta")]
+        Extension::Cta(_) => "CTA-861 Extension Block",
+        #[cfg(feature = "vtb")]
+        Extension::Vtb(_) => "Video Timing Extension Block",
+        #[cfg(feature = "di-ext")]
+        Extension::DiExt(_) => "Display Information Extension Block",
+        Extension::LsExt(_) => "Localized String Extension Block",
+        #[cfg(feature = "displayid")]
+        Extension::DisplayId(_) => "DisplayID Extension Block",
+        Extension::BlockMap(_) => "Block Map Extension Block",
+        Extension::Unknown { .. } => "Unknown Extension Block",
+    };
+    writeln!(out, "Block {}, {}:", index, name)?;
+    match extension {
+        #[cfg(feature = "cta")]
+        Extension::Cta(cta) => write_cta(out, cta),
+        #[cfg(feature = "displayid")]
+        Extension::DisplayId(displayid) => write_displayid(out, displayid),
+        Extension::BlockMap(map) => {
+            for tag in &map.tags {
+                writeln!(out, "  Tag 0x{:02x}", tag)?;
+            }
+            Ok(())
+        }
+        other => writeln!(out, "  Tag 0x{:02x}", other.tag()),
+    }
+}
+
+impl EDID {
+    /// Renders the EDID as text, in the spirit of `edid-decode`.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        // Writing to a String never fails
+        let _ = write_base_block(&mut out, self);
+        for (i, extension) in self.extensions.iter().enumerate() {
+            let _ = write_extension(&mut out, i + 1, extension);
+        }
+        out
+    }
+}
+
+impl fmt::Display for EDID {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
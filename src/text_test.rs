@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use crate::edid::*;
+
+    #[test]
+    fn test_to_text() {
+        let data = include_bytes!("../testdata/card0-eDP-1.bin");
+        let (_, edid) = parse(data).unwrap();
+        let text = edid.to_text();
+        assert!(text.starts_with("Block 0, Base EDID:\n"));
+        assert!(text.contains("  EDID Structure Version & Revision: 1.4\n"));
+        assert!(text.contains("    Digital display\n"));
+        assert!(text.contains("    DTD 1: "));
+        assert!(text.ends_with("  Extension blocks: 0\n"));
+    }
+
+    #[cfg(feature = "cta")]
+    #[test]
+    fn test_to_text_cta() {
+        let data = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(data).unwrap();
+        let text = edid.to_string();
+        assert_eq!(text, edid.to_text());
+
+        for line in [
+            "    DTD 1: 1920x1080 60.000 Hz, 148.500 MHz (531 mm x 299 mm)\n",
+            "      Hfront   88 Hsync  44 Hback  148 Hpol P\n",
+            "      Vfront    4 Vsync   5 Vback   36 Vpol P\n",
+            "Block 1, CTA-861 Extension Block:\n",
+            "    VIC  16: 1920x1080p60 16:9 (native)\n",
+            "    LPCM:\n      Max channels: 2\n",
+            "      Supported sample rates (kHz): 32 44.1 48\n",
+            "      Supported sample sizes (bits): 16 20 24\n",
+            "  Vendor-Specific Data Block (HDMI), OUI 00-0C-03:\n",
+            "  Speaker Allocation Data Block:\n    FL/FR\n",
+            "      Hfront   16 Hsync  62 Hback   60 Hpol N\n",
+        ] {
+            assert!(text.contains(line), "missing {:?}", line);
+        }
+    }
+}
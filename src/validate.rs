@@ -0,0 +1,313 @@
+use crate::edid::{Descriptor, DetailedTiming, DigitalInterface, VideoInput, EDID};
+#[cfg(feature = "cta")]
+use crate::{cta::DataBlock, extended::ShortVideoReference, vic::video_format};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Rule {
+    /// The base block preferred timing differs from the CTA native timing,
+    /// given by the native DTDs or a Native Video Resolution Data Block.
+    #[cfg(feature = "cta")]
+    PreferredTimingNotNative,
+    /// A CTA block declares speakers but no audio support, or the reverse.
+    #[cfg(feature = "cta")]
+    SpeakerAllocationWithoutAudio,
+    /// A CTA block below revision 3 contains a data block collection.
+    #[cfg(feature = "cta")]
+    DataBlockInCtaRevision2,
+    /// A CTA vendor-specific block is too short to hold an OUI.
+    #[cfg(feature = "cta")]
+    TruncatedVendorSpecific,
+    /// A DisplayPort sink lists a DTD without digital separate sync.
+    DisplayPortAnalogSync,
+    /// A product name or serial number descriptor isn't printable ASCII.
+    /// Off by default.
+    UnprintableText,
+}
+
+impl Rule {
+    pub const ALL: &'static [Rule] = &[
+        #[cfg(feature = "cta")]
+        Rule::PreferredTimingNotNative,
+        #[cfg(feature = "cta")]
+        Rule::SpeakerAllocationWithoutAudio,
+        #[cfg(feature = "cta")]
+        Rule::DataBlockInCtaRevision2,
+        #[cfg(feature = "cta")]
+        Rule::TruncatedVendorSpecific,
+        Rule::DisplayPortAnalogSync,
+        Rule::UnprintableText,
+    ];
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            #[cfg(feature = "cta")]
+            Rule::PreferredTimingNotNative => Severity::Warning,
+            #[cfg(feature = "cta")]
+            Rule::SpeakerAllocationWithoutAudio => Severity::Warning,
+            #[cfg(feature = "cta")]
+            Rule::DataBlockInCtaRevision2 => Severity::Warning,
+            #[cfg(feature = "cta")]
+            Rule::TruncatedVendorSpecific => Severity::Warning,
+            Rule::DisplayPortAnalogSync => Severity::Warning,
+            Rule::UnprintableText => Severity::Warning,
+        }
+    }
+
+    /// Whether the rule is checked by a default `Validator`.
+    pub fn enabled_by_default(&self) -> bool {
+        !matches!(self, Rule::UnprintableText)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Issue {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Checks an EDID against a set of rules.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Validator {
+    rules: Vec<Rule>,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Validator {
+            rules: Rule::ALL
+                .iter()
+                .copied()
+                .filter(Rule::enabled_by_default)
+                .collect(),
+        }
+    }
+}
+
+impl Validator {
+    pub fn enable(mut self, rule: Rule) -> Self {
+        if !self.rules.contains(&rule) {
+            self.rules.push(rule);
+        }
+        self
+    }
+
+    pub fn disable(mut self, rule: Rule) -> Self {
+        self.rules.retain(|r| *r != rule);
+        self
+    }
+
+    pub fn validate(&self, edid: &EDID) -> Vec<Issue> {
+        let mut issues = Vec::new();
+        for rule in &self.rules {
+            let mut report = |message: String| {
+                issues.push(Issue {
+                    rule: *rule,
+                    severity: rule.severity(),
+                    message,
+                })
+            };
+            match rule {
+                #[cfg(feature = "cta")]
+                Rule::PreferredTimingNotNative => check_preferred_timing_native(edid, &mut report),
+                #[cfg(feature = "cta")]
+                Rule::SpeakerAllocationWithoutAudio => {
+                    check_speaker_allocation_audio(edid, &mut report)
+                }
+                #[cfg(feature = "cta")]
+                Rule::DataBlockInCtaRevision2 => check_cta_revision(edid, &mut report),
+                #[cfg(feature = "cta")]
+                Rule::TruncatedVendorSpecific => check_vendor_specific_len(edid, &mut report),
+                Rule::DisplayPortAnalogSync => check_display_port_sync(edid, &mut report),
+                Rule::UnprintableText => check_printable_text(edid, &mut report),
+            }
+        }
+        issues
+    }
+}
+
+impl EDID {
+    /// Checks the EDID against the default rules.
+    pub fn validate(&self) -> Vec<Issue> {
+        Validator::default().validate(self)
+    }
+}
+
+fn describe(timing: &DetailedTiming) -> String {
+    format!(
+        "{}x{}@{:.2}",
+        timing.horizontal_active_pixels,
+        timing.vertical_active_lines,
+        timing.refresh_rate()
+    )
+}
+
+#[cfg(feature = "cta")]
+fn same_mode(a: &DetailedTiming, b: &DetailedTiming) -> bool {
+    a.horizontal_active_pixels == b.horizontal_active_pixels
+        && a.vertical_active_lines == b.vertical_active_lines
+        && (a.refresh_rate() - b.refresh_rate()).abs() < 0.5
+}
+
+#[cfg(feature = "cta")]
+fn check_preferred_timing_native(edid: &EDID, report: &mut dyn FnMut(String)) {
+    let preferred = match edid.preferred_timing() {
+        Some(timing) => timing,
+        None => return,
+    };
+
+    for cta in edid.cta_extensions() {
+        // The first DTDs of the CTA block are the native ones
+        let native_count = cta.native_dtd.number_of_native_dtd as usize;
+        let native = &cta.descriptors[..native_count.min(cta.descriptors.len())];
+        if !native.is_empty() && !native.iter().any(|t| same_mode(t, preferred)) {
+            report(format!(
+                "preferred timing {} is not a CTA native timing (first native timing is {})",
+                describe(preferred),
+                describe(&native[0])
+            ));
+        }
+
+        let nvrdb = match cta.native_video_resolution() {
+            Some(nvrdb) => nvrdb,
+            None => continue,
+        };
+        // The resolution fields take precedence over the SVR
+        if let Some((width, height)) = nvrdb.resolution {
+            let size = (
+                preferred.horizontal_active_pixels,
+                preferred.vertical_active_lines,
+            );
+            if size != (width, height) {
+                report(format!(
+                    "preferred timing {} is not the native video resolution {}x{}",
+                    describe(preferred),
+                    width,
+                    height
+                ));
+            }
+            continue;
+        }
+        let native = match nvrdb.native {
+            Some(ShortVideoReference::Vic(vic)) => video_format(vic).map(|format| format.timing),
+            Some(ShortVideoReference::Dtd(index)) => nth_dtd(edid, index).cloned(),
+            _ => None,
+        };
+        if let Some(native) = native {
+            if !same_mode(&native, preferred) {
+                report(format!(
+                    "preferred timing {} is not the native video format {}",
+                    describe(preferred),
+                    describe(&native)
+                ));
+            }
+        }
+    }
+}
+
+/// The DTD at a 1-based index counting over the base block and the CTA
+/// extensions.
+#[cfg(feature = "cta")]
+fn nth_dtd(edid: &EDID, index: u8) -> Option<&DetailedTiming> {
+    let base = edid.descriptors.iter().filter_map(|d| match d {
+        Descriptor::DetailedTiming(timing) => Some(timing),
+        _ => None,
+    });
+    base.chain(edid.cta_extensions().flat_map(|cta| cta.descriptors.iter()))
+        .nth((index as usize).checked_sub(1)?)
+}
+
+#[cfg(feature = "cta")]
+fn check_speaker_allocation_audio(edid: &EDID, report: &mut dyn FnMut(String)) {
+    for cta in edid.cta_extensions() {
+        let has_speakers = cta
+            .blocks
+            .iter()
+            .any(|block| matches!(block, DataBlock::SpeakerAllocation(_)));
+        let has_audio = cta.native_dtd.basic_audio != 0
+            || cta
+                .blocks
+                .iter()
+                .any(|block| matches!(block, DataBlock::AudioBlock(_)));
+
+        if has_speakers && !has_audio {
+            report("speaker allocation block present without basic audio or audio block".into());
+        } else if has_audio && !has_speakers {
+            report("audio supported without a speaker allocation block".into());
+        }
+    }
+}
+
+#[cfg(feature = "cta")]
+fn check_cta_revision(edid: &EDID, report: &mut dyn FnMut(String)) {
+    for cta in edid.cta_extensions() {
+        // Revision 1 blocks are parsed as DTD-only
+        if cta.revision() == 2 && !cta.blocks.is_empty() {
+            report(format!(
+                "CTA revision 2 block contains {} data blocks, which need revision 3",
+                cta.blocks.len()
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "cta")]
+fn check_vendor_specific_len(edid: &EDID, report: &mut dyn FnMut(String)) {
+    for cta in edid.cta_extensions() {
+        for block in &cta.blocks {
+            if let DataBlock::TruncatedVendorSpecific(block) = block {
+                report(format!(
+                    "vendor-specific data block has {} bytes, too short for an OUI",
+                    block.header.len
+                ));
+            }
+        }
+    }
+}
+
+fn check_display_port_sync(edid: &EDID, report: &mut dyn FnMut(String)) {
+    let interface = match edid.display.decoded_video_input() {
+        VideoInput::Digital { interface, .. } => interface,
+        VideoInput::Analog { .. } => return,
+    };
+    if interface != DigitalInterface::DisplayPort {
+        return;
+    }
+
+    let timings = edid.descriptors.iter().filter_map(|d| match d {
+        Descriptor::DetailedTiming(timing) => Some(timing),
+        _ => None,
+    });
+    #[cfg(feature = "cta")]
+    let timings = timings.chain(edid.cta_extensions().flat_map(|cta| cta.descriptors.iter()));
+
+    for timing in timings {
+        // Bits 4:3 of the features byte are 0b11 for digital separate sync
+        if (timing.features >> 3) & 0b11 != 0b11 {
+            report(format!(
+                "DisplayPort sink lists {} without digital separate sync",
+                describe(timing)
+            ));
+        }
+    }
+}
+
+fn check_printable_text(edid: &EDID, report: &mut dyn FnMut(String)) {
+    for descriptor in &edid.descriptors {
+        let (kind, text) = match descriptor {
+            Descriptor::ProductName(text) => ("product name", text),
+            Descriptor::SerialNumber(text) => ("serial number", text),
+            _ => continue,
+        };
+        // The line feed terminator and space padding are already stripped
+        if !text.chars().all(|c| c == ' ' || c.is_ascii_graphic()) {
+            report(format!("{} {:?} is not printable ASCII", kind, text));
+        }
+    }
+}
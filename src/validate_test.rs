@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use crate::{builder::CtaBuilder, cta::*, edid::*, validate::*};
+
+    #[test]
+    fn test_preferred_timing_not_native() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(edid.validate(), vec![]);
+
+        // Replace the preferred timing with the 1080i one from the CTA block
+        let mut data = d.to_vec();
+        let interlaced = d[128 + 49..128 + 67].to_vec();
+        data[54..72].copy_from_slice(&interlaced);
+        let (_, edid) = parse(&data).unwrap();
+
+        let issues = edid.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::PreferredTimingNotNative);
+        assert_eq!(issues[0].severity, Severity::Warning);
+
+        let validator = Validator::default().disable(Rule::PreferredTimingNotNative);
+        assert_eq!(validator.validate(&edid), vec![]);
+    }
+
+    #[test]
+    fn test_preferred_timing_not_native_resolution() {
+        let with_nvrdb = |payload: &[u8]| {
+            let block = CtaBuilder::new()
+                .raw_data_block(7, payload)
+                .build()
+                .unwrap();
+            let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+            data[126] = 1;
+            data.extend_from_slice(&block);
+            parse(&data).unwrap().1
+        };
+
+        // The preferred timing is 1680x1050, either as resolution or DTD 1
+        let edid = with_nvrdb(&[8, 0x00, 0x00, 0x8f, 0x06, 0x19, 0x04]);
+        assert_eq!(edid.validate(), vec![]);
+        assert_eq!(with_nvrdb(&[8, 0x81]).validate(), vec![]);
+
+        // VIC 16 is 1920x1080
+        let issues = with_nvrdb(&[8, 16]).validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::PreferredTimingNotNative);
+        let issues = with_nvrdb(&[8, 16, 0x00, 0x7f, 0x07, 0x37, 0x04]).validate();
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("1920x1080"));
+    }
+
+    #[test]
+    fn test_speaker_allocation_without_audio() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let validator = Validator::default().disable(Rule::PreferredTimingNotNative);
+
+        // Turn the speaker allocation block into a reserved one
+        let mut data = d.to_vec();
+        assert_eq!(data[128 + 27], 0x83);
+        data[128 + 27] = 0x03;
+        let (_, edid) = parse(&data).unwrap();
+        let issues = validator.validate(&edid);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::SpeakerAllocationWithoutAudio);
+
+        // Drop the audio block and the basic audio flag instead
+        let mut data = d.to_vec();
+        assert_eq!(data[128 + 17], 0x23);
+        data[128 + 17] = 0x03;
+        data[128 + 3] &= !0x40;
+        let (_, edid) = parse(&data).unwrap();
+        let issues = validator.validate(&edid);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::SpeakerAllocationWithoutAudio);
+    }
+
+    #[test]
+    fn test_data_block_in_cta_revision_2() {
+        let mut data = include_bytes!("../testdata/card0-HDMI-1.bin").to_vec();
+        let (_, edid) = parse(&data).unwrap();
+        assert!(edid.cta_extensions().next().unwrap().is_v3());
+
+        data[129] = 2;
+        let (_, edid) = parse(&data).unwrap();
+        let issues = edid.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::DataBlockInCtaRevision2);
+    }
+
+    #[test]
+    fn test_display_port_analog_sync() {
+        let d = include_bytes!("../testdata/card0-eDP-1.bin");
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(edid.validate(), vec![]);
+
+        // Switch the preferred timing to analog composite sync
+        let mut data = d.to_vec();
+        data[71] &= !0x18;
+        let (_, edid) = parse(&data).unwrap();
+        let issues = edid.validate();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::DisplayPortAnalogSync);
+
+        // Not a DisplayPort sink
+        let mut data = include_bytes!("../testdata/card0-HDMI-1.bin").to_vec();
+        data[71] &= !0x18;
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(edid.validate(), vec![]);
+    }
+
+    #[test]
+    fn test_truncated_vendor_specific() {
+        // A vendor block holding 2 of the 3 OUI bytes, then an audio block
+        let block = CtaBuilder::new()
+            .raw_data_block(3, &[0x03, 0x0c])
+            .raw_data_block(1, &[0x09, 0x07, 0x07])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&block);
+
+        let (_, edid) = parse(&data).unwrap();
+        let cta = edid.cta_extensions().next().unwrap();
+        assert_eq!(cta.blocks.len(), 2);
+        assert_eq!(
+            cta.blocks[0],
+            DataBlock::TruncatedVendorSpecific(DataBlockReserved {
+                header: DataBlockHeader {
+                    type_tag: 3,
+                    len: 2
+                },
+                payload: vec![0x03, 0x0c],
+            })
+        );
+        assert!(matches!(cta.blocks[1], DataBlock::AudioBlock(_)));
+
+        let issues = edid.validate();
+        let issue = issues
+            .iter()
+            .find(|issue| issue.rule == Rule::TruncatedVendorSpecific)
+            .unwrap();
+        assert_eq!(issue.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_unprintable_text() {
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let validator = Validator::default().enable(Rule::UnprintableText);
+        let (_, edid) = parse(d).unwrap();
+        assert_eq!(validator.validate(&edid), vec![]);
+
+        // Garbage in the product name, only reported when enabled
+        let mut data = d.to_vec();
+        data[90 + 6] = 0xff;
+        data[90 + 7] = 0x01;
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(edid.validate(), vec![]);
+        let issues = validator.validate(&edid);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule, Rule::UnprintableText);
+        assert!(issues[0].message.starts_with("product name"));
+    }
+}
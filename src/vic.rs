@@ -0,0 +1,352 @@
+use std::{collections::HashMap, fmt, str::FromStr, sync::OnceLock};
+
+#[cfg(feature = "cta")]
+use crate::cta::ShortVideoDescriptor;
+use crate::{edid::DetailedTiming, extension::ParseNameError};
+
+// DTD features bytes: digital separate sync with the sync polarities
+const PP: u8 = 0x1e;
+const PN: u8 = 0x1a;
+const NN: u8 = 0x18;
+
+// VIC, name, active pixels and lines, interlaced, nominal rate, picture
+// aspect ratio, pixel clock in kHz, horizontal front porch, sync and
+// blanking, vertical ones per field, features
+#[rustfmt::skip]
+type Row = (u8, &'static str, u16, u16, bool, u16, (u16, u16), u32, [u16; 3], [u16; 3], u8);
+
+// The CTA-861 VIC table, with the clocks of the integer rates
+#[rustfmt::skip]
+const VIDEO_FORMATS: &[Row] = &[
+    (1, "640x480p60_4_3", 640, 480, false, 60, (4, 3), 25200, [16, 96, 160], [10, 2, 45], NN),
+    (2, "720x480p60_4_3", 720, 480, false, 60, (4, 3), 27027, [16, 62, 138], [9, 6, 45], NN),
+    (3, "720x480p60_16_9", 720, 480, false, 60, (16, 9), 27027, [16, 62, 138], [9, 6, 45], NN),
+    (4, "1280x720p60_16_9", 1280, 720, false, 60, (16, 9), 74250, [110, 40, 370], [5, 5, 30], PP),
+    (5, "1920x1080i60_16_9", 1920, 1080, true, 60, (16, 9), 74250, [88, 44, 280], [2, 5, 22], PP),
+    (6, "1440x480i60_4_3", 1440, 480, true, 60, (4, 3), 27027, [38, 124, 276], [4, 3, 22], NN),
+    (7, "1440x480i60_16_9", 1440, 480, true, 60, (16, 9), 27027, [38, 124, 276], [4, 3, 22], NN),
+    (8, "1440x240p60_4_3", 1440, 240, false, 60, (4, 3), 27027, [38, 124, 276], [4, 3, 22], NN),
+    (9, "1440x240p60_16_9", 1440, 240, false, 60, (16, 9), 27027, [38, 124, 276], [4, 3, 22], NN),
+    (10, "2880x480i60_4_3", 2880, 480, true, 60, (4, 3), 54054, [76, 248, 552], [4, 3, 22], NN),
+    (11, "2880x480i60_16_9", 2880, 480, true, 60, (16, 9), 54054, [76, 248, 552], [4, 3, 22], NN),
+    (12, "2880x240p60_4_3", 2880, 240, false, 60, (4, 3), 54054, [76, 248, 552], [4, 3, 22], NN),
+    (13, "2880x240p60_16_9", 2880, 240, false, 60, (16, 9), 54054, [76, 248, 552], [4, 3, 22], NN),
+    (14, "1440x480p60_4_3", 1440, 480, false, 60, (4, 3), 54054, [32, 124, 276], [9, 6, 45], NN),
+    (15, "1440x480p60_16_9", 1440, 480, false, 60, (16, 9), 54054, [32, 124, 276], [9, 6, 45], NN),
+    (16, "1920x1080p60_16_9", 1920, 1080, false, 60, (16, 9), 148500, [88, 44, 280], [4, 5, 45], PP),
+    (17, "720x576p50_4_3", 720, 576, false, 50, (4, 3), 27000, [12, 64, 144], [5, 5, 49], NN),
+    (18, "720x576p50_16_9", 720, 576, false, 50, (16, 9), 27000, [12, 64, 144], [5, 5, 49], NN),
+    (19, "1280x720p50_16_9", 1280, 720, false, 50, (16, 9), 74250, [440, 40, 700], [5, 5, 30], PP),
+    (20, "1920x1080i50_16_9", 1920, 1080, true, 50, (16, 9), 74250, [528, 44, 720], [2, 5, 22], PP),
+    (21, "1440x576i50_4_3", 1440, 576, true, 50, (4, 3), 27000, [24, 126, 288], [2, 3, 24], NN),
+    (22, "1440x576i50_16_9", 1440, 576, true, 50, (16, 9), 27000, [24, 126, 288], [2, 3, 24], NN),
+    (23, "1440x288p50_4_3", 1440, 288, false, 50, (4, 3), 27000, [24, 126, 288], [2, 3, 24], NN),
+    (24, "1440x288p50_16_9", 1440, 288, false, 50, (16, 9), 27000, [24, 126, 288], [2, 3, 24], NN),
+    (25, "2880x576i50_4_3", 2880, 576, true, 50, (4, 3), 54000, [48, 252, 576], [2, 3, 24], NN),
+    (26, "2880x576i50_16_9", 2880, 576, true, 50, (16, 9), 54000, [48, 252, 576], [2, 3, 24], NN),
+    (27, "2880x288p50_4_3", 2880, 288, false, 50, (4, 3), 54000, [48, 252, 576], [2, 3, 24], NN),
+    (28, "2880x288p50_16_9", 2880, 288, false, 50, (16, 9), 54000, [48, 252, 576], [2, 3, 24], NN),
+    (29, "1440x576p50_4_3", 1440, 576, false, 50, (4, 3), 54000, [24, 128, 288], [5, 5, 49], NN),
+    (30, "1440x576p50_16_9", 1440, 576, false, 50, (16, 9), 54000, [24, 128, 288], [5, 5, 49], NN),
+    (31, "1920x1080p50_16_9", 1920, 1080, false, 50, (16, 9), 148500, [528, 44, 720], [4, 5, 45], PP),
+    (32, "1920x1080p24_16_9", 1920, 1080, false, 24, (16, 9), 74250, [638, 44, 830], [4, 5, 45], PP),
+    (33, "1920x1080p25_16_9", 1920, 1080, false, 25, (16, 9), 74250, [528, 44, 720], [4, 5, 45], PP),
+    (34, "1920x1080p30_16_9", 1920, 1080, false, 30, (16, 9), 74250, [88, 44, 280], [4, 5, 45], PP),
+    (35, "2880x480p60_4_3", 2880, 480, false, 60, (4, 3), 108108, [64, 248, 552], [9, 6, 45], NN),
+    (36, "2880x480p60_16_9", 2880, 480, false, 60, (16, 9), 108108, [64, 248, 552], [9, 6, 45], NN),
+    (37, "2880x576p50_4_3", 2880, 576, false, 50, (4, 3), 108000, [48, 256, 576], [5, 5, 49], NN),
+    (38, "2880x576p50_16_9", 2880, 576, false, 50, (16, 9), 108000, [48, 256, 576], [5, 5, 49], NN),
+    (39, "1920x1080i50_16_9_1250", 1920, 1080, true, 50, (16, 9), 72000, [32, 168, 384], [23, 5, 85], PN),
+    (40, "1920x1080i100_16_9", 1920, 1080, true, 100, (16, 9), 148500, [528, 44, 720], [2, 5, 22], PP),
+    (41, "1280x720p100_16_9", 1280, 720, false, 100, (16, 9), 148500, [440, 40, 700], [5, 5, 30], PP),
+    (42, "720x576p100_4_3", 720, 576, false, 100, (4, 3), 54000, [12, 64, 144], [5, 5, 49], NN),
+    (43, "720x576p100_16_9", 720, 576, false, 100, (16, 9), 54000, [12, 64, 144], [5, 5, 49], NN),
+    (44, "1440x576i100_4_3", 1440, 576, true, 100, (4, 3), 54000, [24, 126, 288], [2, 3, 24], NN),
+    (45, "1440x576i100_16_9", 1440, 576, true, 100, (16, 9), 54000, [24, 126, 288], [2, 3, 24], NN),
+    (46, "1920x1080i120_16_9", 1920, 1080, true, 120, (16, 9), 148500, [88, 44, 280], [2, 5, 22], PP),
+    (47, "1280x720p120_16_9", 1280, 720, false, 120, (16, 9), 148500, [110, 40, 370], [5, 5, 30], PP),
+    (48, "720x480p120_4_3", 720, 480, false, 120, (4, 3), 54054, [16, 62, 138], [9, 6, 45], NN),
+    (49, "720x480p120_16_9", 720, 480, false, 120, (16, 9), 54054, [16, 62, 138], [9, 6, 45], NN),
+    (50, "1440x480i120_4_3", 1440, 480, true, 120, (4, 3), 54054, [38, 124, 276], [4, 3, 22], NN),
+    (51, "1440x480i120_16_9", 1440, 480, true, 120, (16, 9), 54054, [38, 124, 276], [4, 3, 22], NN),
+    (52, "720x576p200_4_3", 720, 576, false, 200, (4, 3), 108000, [12, 64, 144], [5, 5, 49], NN),
+    (53, "720x576p200_16_9", 720, 576, false, 200, (16, 9), 108000, [12, 64, 144], [5, 5, 49], NN),
+    (54, "1440x576i200_4_3", 1440, 576, true, 200, (4, 3), 108000, [24, 126, 288], [2, 3, 24], NN),
+    (55, "1440x576i200_16_9", 1440, 576, true, 200, (16, 9), 108000, [24, 126, 288], [2, 3, 24], NN),
+    (56, "720x480p240_4_3", 720, 480, false, 240, (4, 3), 108108, [16, 62, 138], [9, 6, 45], NN),
+    (57, "720x480p240_16_9", 720, 480, false, 240, (16, 9), 108108, [16, 62, 138], [9, 6, 45], NN),
+    (58, "1440x480i240_4_3", 1440, 480, true, 240, (4, 3), 108108, [38, 124, 276], [4, 3, 22], NN),
+    (59, "1440x480i240_16_9", 1440, 480, true, 240, (16, 9), 108108, [38, 124, 276], [4, 3, 22], NN),
+    (60, "1280x720p24_16_9", 1280, 720, false, 24, (16, 9), 59400, [1760, 40, 2020], [5, 5, 30], PP),
+    (61, "1280x720p25_16_9", 1280, 720, false, 25, (16, 9), 74250, [2420, 40, 2680], [5, 5, 30], PP),
+    (62, "1280x720p30_16_9", 1280, 720, false, 30, (16, 9), 74250, [1760, 40, 2020], [5, 5, 30], PP),
+    (63, "1920x1080p120_16_9", 1920, 1080, false, 120, (16, 9), 297000, [88, 44, 280], [4, 5, 45], PP),
+    (64, "1920x1080p100_16_9", 1920, 1080, false, 100, (16, 9), 297000, [528, 44, 720], [4, 5, 45], PP),
+    (65, "1280x720p24_64_27", 1280, 720, false, 24, (64, 27), 59400, [1760, 40, 2020], [5, 5, 30], PP),
+    (66, "1280x720p25_64_27", 1280, 720, false, 25, (64, 27), 74250, [2420, 40, 2680], [5, 5, 30], PP),
+    (67, "1280x720p30_64_27", 1280, 720, false, 30, (64, 27), 74250, [1760, 40, 2020], [5, 5, 30], PP),
+    (68, "1280x720p50_64_27", 1280, 720, false, 50, (64, 27), 74250, [440, 40, 700], [5, 5, 30], PP),
+    (69, "1280x720p60_64_27", 1280, 720, false, 60, (64, 27), 74250, [110, 40, 370], [5, 5, 30], PP),
+    (70, "1280x720p100_64_27", 1280, 720, false, 100, (64, 27), 148500, [440, 40, 700], [5, 5, 30], PP),
+    (71, "1280x720p120_64_27", 1280, 720, false, 120, (64, 27), 148500, [110, 40, 370], [5, 5, 30], PP),
+    (72, "1920x1080p24_64_27", 1920, 1080, false, 24, (64, 27), 74250, [638, 44, 830], [4, 5, 45], PP),
+    (73, "1920x1080p25_64_27", 1920, 1080, false, 25, (64, 27), 74250, [528, 44, 720], [4, 5, 45], PP),
+    (74, "1920x1080p30_64_27", 1920, 1080, false, 30, (64, 27), 74250, [88, 44, 280], [4, 5, 45], PP),
+    (75, "1920x1080p50_64_27", 1920, 1080, false, 50, (64, 27), 148500, [528, 44, 720], [4, 5, 45], PP),
+    (76, "1920x1080p60_64_27", 1920, 1080, false, 60, (64, 27), 148500, [88, 44, 280], [4, 5, 45], PP),
+    (77, "1920x1080p100_64_27", 1920, 1080, false, 100, (64, 27), 297000, [528, 44, 720], [4, 5, 45], PP),
+    (78, "1920x1080p120_64_27", 1920, 1080, false, 120, (64, 27), 297000, [88, 44, 280], [4, 5, 45], PP),
+    (79, "1680x720p24_64_27", 1680, 720, false, 24, (64, 27), 59400, [1360, 40, 1620], [5, 5, 30], PP),
+    (80, "1680x720p25_64_27", 1680, 720, false, 25, (64, 27), 59400, [1228, 40, 1488], [5, 5, 30], PP),
+    (81, "1680x720p30_64_27", 1680, 720, false, 30, (64, 27), 59400, [700, 40, 960], [5, 5, 30], PP),
+    (82, "1680x720p50_64_27", 1680, 720, false, 50, (64, 27), 82500, [260, 40, 520], [5, 5, 30], PP),
+    (83, "1680x720p60_64_27", 1680, 720, false, 60, (64, 27), 99000, [260, 40, 520], [5, 5, 30], PP),
+    (84, "1680x720p100_64_27", 1680, 720, false, 100, (64, 27), 165000, [60, 40, 320], [5, 5, 105], PP),
+    (85, "1680x720p120_64_27", 1680, 720, false, 120, (64, 27), 198000, [60, 40, 320], [5, 5, 105], PP),
+    (86, "2560x1080p24_64_27", 2560, 1080, false, 24, (64, 27), 99000, [998, 44, 1190], [4, 5, 20], PP),
+    (87, "2560x1080p25_64_27", 2560, 1080, false, 25, (64, 27), 90000, [448, 44, 640], [4, 5, 45], PP),
+    (88, "2560x1080p30_64_27", 2560, 1080, false, 30, (64, 27), 118800, [768, 44, 960], [4, 5, 45], PP),
+    (89, "2560x1080p50_64_27", 2560, 1080, false, 50, (64, 27), 185625, [548, 44, 740], [4, 5, 45], PP),
+    (90, "2560x1080p60_64_27", 2560, 1080, false, 60, (64, 27), 198000, [248, 44, 440], [4, 5, 20], PP),
+    (91, "2560x1080p100_64_27", 2560, 1080, false, 100, (64, 27), 371250, [218, 44, 410], [4, 5, 170], PP),
+    (92, "2560x1080p120_64_27", 2560, 1080, false, 120, (64, 27), 495000, [548, 44, 740], [4, 5, 170], PP),
+    (93, "3840x2160p24_16_9", 3840, 2160, false, 24, (16, 9), 297000, [1276, 88, 1660], [8, 10, 90], PP),
+    (94, "3840x2160p25_16_9", 3840, 2160, false, 25, (16, 9), 297000, [1056, 88, 1440], [8, 10, 90], PP),
+    (95, "3840x2160p30_16_9", 3840, 2160, false, 30, (16, 9), 297000, [176, 88, 560], [8, 10, 90], PP),
+    (96, "3840x2160p50_16_9", 3840, 2160, false, 50, (16, 9), 594000, [1056, 88, 1440], [8, 10, 90], PP),
+    (97, "3840x2160p60_16_9", 3840, 2160, false, 60, (16, 9), 594000, [176, 88, 560], [8, 10, 90], PP),
+    (98, "4096x2160p24_256_135", 4096, 2160, false, 24, (256, 135), 297000, [1020, 88, 1404], [8, 10, 90], PP),
+    (99, "4096x2160p25_256_135", 4096, 2160, false, 25, (256, 135), 297000, [968, 88, 1184], [8, 10, 90], PP),
+    (100, "4096x2160p30_256_135", 4096, 2160, false, 30, (256, 135), 297000, [88, 88, 304], [8, 10, 90], PP),
+    (101, "4096x2160p50_256_135", 4096, 2160, false, 50, (256, 135), 594000, [968, 88, 1184], [8, 10, 90], PP),
+    (102, "4096x2160p60_256_135", 4096, 2160, false, 60, (256, 135), 594000, [88, 88, 304], [8, 10, 90], PP),
+    (103, "3840x2160p24_64_27", 3840, 2160, false, 24, (64, 27), 297000, [1276, 88, 1660], [8, 10, 90], PP),
+    (104, "3840x2160p25_64_27", 3840, 2160, false, 25, (64, 27), 297000, [1056, 88, 1440], [8, 10, 90], PP),
+    (105, "3840x2160p30_64_27", 3840, 2160, false, 30, (64, 27), 297000, [176, 88, 560], [8, 10, 90], PP),
+    (106, "3840x2160p50_64_27", 3840, 2160, false, 50, (64, 27), 594000, [1056, 88, 1440], [8, 10, 90], PP),
+    (107, "3840x2160p60_64_27", 3840, 2160, false, 60, (64, 27), 594000, [176, 88, 560], [8, 10, 90], PP),
+    (108, "1280x720p48_16_9", 1280, 720, false, 48, (16, 9), 90000, [960, 40, 1220], [5, 5, 30], PP),
+    (109, "1280x720p48_64_27", 1280, 720, false, 48, (64, 27), 90000, [960, 40, 1220], [5, 5, 30], PP),
+    (110, "1680x720p48_64_27", 1680, 720, false, 48, (64, 27), 99000, [810, 40, 1070], [5, 5, 30], PP),
+    (111, "1920x1080p48_16_9", 1920, 1080, false, 48, (16, 9), 148500, [638, 44, 830], [4, 5, 45], PP),
+    (112, "1920x1080p48_64_27", 1920, 1080, false, 48, (64, 27), 148500, [638, 44, 830], [4, 5, 45], PP),
+    (113, "2560x1080p48_64_27", 2560, 1080, false, 48, (64, 27), 198000, [998, 44, 1190], [4, 5, 20], PP),
+    (114, "3840x2160p48_16_9", 3840, 2160, false, 48, (16, 9), 594000, [1276, 88, 1660], [8, 10, 90], PP),
+    (115, "4096x2160p48_256_135", 4096, 2160, false, 48, (256, 135), 594000, [1020, 88, 1404], [8, 10, 90], PP),
+    (116, "3840x2160p48_64_27", 3840, 2160, false, 48, (64, 27), 594000, [1276, 88, 1660], [8, 10, 90], PP),
+    (117, "3840x2160p100_16_9", 3840, 2160, false, 100, (16, 9), 1188000, [1056, 88, 1440], [8, 10, 90], PP),
+    (118, "3840x2160p120_16_9", 3840, 2160, false, 120, (16, 9), 1188000, [176, 88, 560], [8, 10, 90], PP),
+    (119, "3840x2160p100_64_27", 3840, 2160, false, 100, (64, 27), 1188000, [1056, 88, 1440], [8, 10, 90], PP),
+    (120, "3840x2160p120_64_27", 3840, 2160, false, 120, (64, 27), 1188000, [176, 88, 560], [8, 10, 90], PP),
+    (121, "5120x2160p24_64_27", 5120, 2160, false, 24, (64, 27), 396000, [1996, 88, 2380], [8, 10, 40], PP),
+    (122, "5120x2160p25_64_27", 5120, 2160, false, 25, (64, 27), 396000, [1696, 88, 2080], [8, 10, 40], PP),
+    (123, "5120x2160p30_64_27", 5120, 2160, false, 30, (64, 27), 396000, [664, 88, 880], [8, 10, 40], PP),
+    (124, "5120x2160p48_64_27", 5120, 2160, false, 48, (64, 27), 742500, [746, 88, 1130], [8, 10, 315], PP),
+    (125, "5120x2160p50_64_27", 5120, 2160, false, 50, (64, 27), 742500, [1096, 88, 1480], [8, 10, 90], PP),
+    (126, "5120x2160p60_64_27", 5120, 2160, false, 60, (64, 27), 742500, [164, 88, 380], [8, 10, 90], PP),
+    (127, "5120x2160p100_64_27", 5120, 2160, false, 100, (64, 27), 1485000, [1096, 88, 1480], [8, 10, 90], PP),
+    (193, "5120x2160p120_64_27", 5120, 2160, false, 120, (64, 27), 1485000, [164, 88, 380], [8, 10, 90], PP),
+    (194, "7680x4320p24_16_9", 7680, 4320, false, 24, (16, 9), 1188000, [2552, 176, 3320], [16, 20, 180], PP),
+    (195, "7680x4320p25_16_9", 7680, 4320, false, 25, (16, 9), 1188000, [2352, 176, 3120], [16, 20, 80], PP),
+    (196, "7680x4320p30_16_9", 7680, 4320, false, 30, (16, 9), 1188000, [552, 176, 1320], [16, 20, 80], PP),
+    (197, "7680x4320p48_16_9", 7680, 4320, false, 48, (16, 9), 2376000, [2552, 176, 3320], [16, 20, 180], PP),
+    (198, "7680x4320p50_16_9", 7680, 4320, false, 50, (16, 9), 2376000, [2352, 176, 3120], [16, 20, 80], PP),
+    (199, "7680x4320p60_16_9", 7680, 4320, false, 60, (16, 9), 2376000, [552, 176, 1320], [16, 20, 80], PP),
+    (200, "7680x4320p100_16_9", 7680, 4320, false, 100, (16, 9), 4752000, [2112, 176, 2880], [16, 20, 180], PP),
+    (201, "7680x4320p120_16_9", 7680, 4320, false, 120, (16, 9), 4752000, [352, 176, 1120], [16, 20, 180], PP),
+    (202, "7680x4320p24_64_27", 7680, 4320, false, 24, (64, 27), 1188000, [2552, 176, 3320], [16, 20, 180], PP),
+    (203, "7680x4320p25_64_27", 7680, 4320, false, 25, (64, 27), 1188000, [2352, 176, 3120], [16, 20, 80], PP),
+    (204, "7680x4320p30_64_27", 7680, 4320, false, 30, (64, 27), 1188000, [552, 176, 1320], [16, 20, 80], PP),
+    (205, "7680x4320p48_64_27", 7680, 4320, false, 48, (64, 27), 2376000, [2552, 176, 3320], [16, 20, 180], PP),
+    (206, "7680x4320p50_64_27", 7680, 4320, false, 50, (64, 27), 2376000, [2352, 176, 3120], [16, 20, 80], PP),
+    (207, "7680x4320p60_64_27", 7680, 4320, false, 60, (64, 27), 2376000, [552, 176, 1320], [16, 20, 80], PP),
+    (208, "7680x4320p100_64_27", 7680, 4320, false, 100, (64, 27), 4752000, [2112, 176, 2880], [16, 20, 180], PP),
+    (209, "7680x4320p120_64_27", 7680, 4320, false, 120, (64, 27), 4752000, [352, 176, 1120], [16, 20, 180], PP),
+    (210, "10240x4320p24_64_27", 10240, 4320, false, 24, (64, 27), 1485000, [1492, 176, 2260], [16, 20, 630], PP),
+    (211, "10240x4320p25_64_27", 10240, 4320, false, 25, (64, 27), 1485000, [2492, 176, 3260], [16, 20, 80], PP),
+    (212, "10240x4320p30_64_27", 10240, 4320, false, 30, (64, 27), 1485000, [288, 176, 760], [16, 20, 180], PP),
+    (213, "10240x4320p48_64_27", 10240, 4320, false, 48, (64, 27), 2970000, [1492, 176, 2260], [16, 20, 630], PP),
+    (214, "10240x4320p50_64_27", 10240, 4320, false, 50, (64, 27), 2970000, [2492, 176, 3260], [16, 20, 80], PP),
+    (215, "10240x4320p60_64_27", 10240, 4320, false, 60, (64, 27), 2970000, [288, 176, 760], [16, 20, 180], PP),
+    (216, "10240x4320p100_64_27", 10240, 4320, false, 100, (64, 27), 5940000, [2192, 176, 2960], [16, 20, 180], PP),
+    (217, "10240x4320p120_64_27", 10240, 4320, false, 120, (64, 27), 5940000, [288, 176, 760], [16, 20, 180], PP),
+    (218, "4096x2160p100_256_135", 4096, 2160, false, 100, (256, 135), 1188000, [800, 88, 1184], [8, 10, 90], PP),
+    (219, "4096x2160p120_256_135", 4096, 2160, false, 120, (256, 135), 1188000, [88, 88, 304], [8, 10, 90], PP),
+];
+
+// Registered formats with their names
+static VIDEO_FORMAT_OVERRIDES: OnceLock<HashMap<u8, (VideoFormat, String)>> = OnceLock::new();
+
+/// A CTA-861 video format, identified by its VIC.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct VideoFormat {
+    pub vic: u8,
+    pub width: u16,
+    /// Active lines of a frame, i.e. of both fields for interlaced formats.
+    pub height: u16,
+    pub interlaced: bool,
+    /// Nominal field rate in Hz. The 1000/1001 rates share the VIC.
+    pub refresh_rate: u16,
+    /// Picture aspect ratio, e.g. `(16, 9)`.
+    pub aspect_ratio: (u16, u16),
+    /// The timing as a DTD would describe it, with per-field vertical
+    /// timings for interlaced formats.
+    pub timing: DetailedTiming,
+}
+
+impl VideoFormat {
+    /// Pixel clock in kHz at the nominal rate.
+    pub fn pixel_clock(&self) -> u32 {
+        self.timing.pixel_clock
+    }
+
+    /// Stable snake_case identifier of the VIC, e.g. `1920x1080p60_16_9`,
+    /// or `reserved` for VICs neither in the table nor registered.
+    pub fn as_str(&self) -> &'static str {
+        if let Some((_, name)) = VIDEO_FORMAT_OVERRIDES
+            .get()
+            .and_then(|formats| formats.get(&self.vic))
+        {
+            return name;
+        }
+        builtin_row(self.vic).map_or("reserved", |row| row.1)
+    }
+
+    fn from_row(row: &Row) -> Self {
+        let &(
+            vic,
+            _,
+            width,
+            height,
+            interlaced,
+            refresh_rate,
+            aspect_ratio,
+            pixel_clock,
+            h,
+            v,
+            sync,
+        ) = row;
+        VideoFormat {
+            vic,
+            width,
+            height,
+            interlaced,
+            refresh_rate,
+            aspect_ratio,
+            timing: DetailedTiming {
+                pixel_clock,
+                horizontal_active_pixels: width,
+                horizontal_blanking_pixels: h[2],
+                vertical_active_lines: if interlaced { height / 2 } else { height },
+                vertical_blanking_lines: v[2],
+                horizontal_front_porch: h[0],
+                horizontal_sync_width: h[1],
+                vertical_front_porch: v[0],
+                vertical_sync_width: v[1],
+                features: if interlaced { sync | 0x80 } else { sync },
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// E.g. "1920x1080p60 16:9".
+impl fmt::Display for VideoFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}x{}{}{} {}:{}",
+            self.width,
+            self.height,
+            if self.interlaced { 'i' } else { 'p' },
+            self.refresh_rate,
+            self.aspect_ratio.0,
+            self.aspect_ratio.1
+        )
+    }
+}
+
+/// The VIC is its name, e.g. `"1920x1080p60_16_9".parse()` gives VIC 16.
+impl FromStr for VideoFormat {
+    type Err = ParseNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Built-in names first, a registered VIC may share one
+        let registered = || {
+            VIDEO_FORMAT_OVERRIDES
+                .get()
+                .and_then(|formats| formats.values().find(|(_, name)| name == s))
+                .map(|(format, _)| format.vic)
+        };
+        VIDEO_FORMATS
+            .iter()
+            .find(|row| row.1 == s)
+            .map(|row| row.0)
+            .or_else(registered)
+            .and_then(video_format)
+            .ok_or_else(|| ParseNameError {
+                name: s.to_string(),
+            })
+    }
+}
+
+fn builtin_row(vic: u8) -> Option<&'static Row> {
+    VIDEO_FORMATS.iter().find(|row| row.0 == vic)
+}
+
+/// Registers additional video formats, e.g. for VICs defined after this
+/// crate's table. They take precedence over the built-in table, and keep
+/// the name of the VIC they replace. New VICs are named after their
+/// format.
+///
+/// This can only be done once per process: returns `false` if formats were
+/// already registered.
+pub fn register_video_formats<I>(formats: I) -> bool
+where
+    I: IntoIterator<Item = VideoFormat>,
+{
+    VIDEO_FORMAT_OVERRIDES
+        .set(
+            formats
+                .into_iter()
+                .map(|format| {
+                    let name = match builtin_row(format.vic) {
+                        Some(row) => row.1.to_string(),
+                        None => format!(
+                            "{}x{}{}{}_{}_{}",
+                            format.width,
+                            format.height,
+                            if format.interlaced { 'i' } else { 'p' },
+                            format.refresh_rate,
+                            format.aspect_ratio.0,
+                            format.aspect_ratio.1
+                        ),
+                    };
+                    (format.vic, (format, name))
+                })
+                .collect(),
+        )
+        .is_ok()
+}
+
+/// Looks up the video format of a VIC, `None` for reserved VICs.
+pub fn video_format(vic: u8) -> Option<VideoFormat> {
+    if let Some((format, _)) = VIDEO_FORMAT_OVERRIDES
+        .get()
+        .and_then(|formats| formats.get(&vic))
+    {
+        return Some(*format);
+    }
+
+    builtin_row(vic).map(VideoFormat::from_row)
+}
+
+#[cfg(feature = "cta")]
+impl ShortVideoDescriptor {
+    pub fn video_format(&self) -> Option<VideoFormat> {
+        video_format(self.cea861_index)
+    }
+}
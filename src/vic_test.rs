@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod tests {
+    use crate::{edid::*, extension::ParseNameError, vic::*};
+
+    #[test]
+    fn test_video_format() {
+        let vic16 = video_format(16).unwrap();
+        assert_eq!((vic16.width, vic16.height), (1920, 1080));
+        assert!(!vic16.interlaced);
+        assert_eq!(vic16.refresh_rate, 60);
+        assert_eq!(vic16.aspect_ratio, (16, 9));
+        assert_eq!(vic16.pixel_clock(), 148500);
+        assert_eq!(vic16.to_string(), "1920x1080p60 16:9");
+
+        assert_eq!(video_format(0), None);
+        assert_eq!(video_format(128), None);
+        assert_eq!(
+            video_format(219).unwrap().to_string(),
+            "4096x2160p120 256:135"
+        );
+
+        // The timings match the nominal rates, interlaced ones per field
+        for vic in (1..=127).chain(193..=219) {
+            let format = video_format(vic).unwrap();
+            let timing = &format.timing;
+            assert_eq!(timing.horizontal_active_pixels, format.width);
+            let rate = timing.refresh_rate();
+            assert!(
+                (rate - format.refresh_rate as f64).abs() < 0.5,
+                "VIC {} runs at {} Hz",
+                vic,
+                rate
+            );
+        }
+    }
+
+    #[test]
+    fn test_video_format_matches_dtd() {
+        // The CTA block of this sink lists 1080i60 as a DTD
+        let d = include_bytes!("../testdata/card0-HDMI-1.bin");
+        let (_, dtd) = parse_detailed_timing(&d[128 + 49..128 + 67]).unwrap();
+        let vic5 = video_format(5).unwrap();
+        assert_eq!(
+            DetailedTiming {
+                horizontal_size: dtd.horizontal_size,
+                vertical_size: dtd.vertical_size,
+                ..vic5.timing
+            },
+            dtd
+        );
+    }
+
+    #[test]
+    fn test_video_format_names() {
+        let vic16 = video_format(16).unwrap();
+        assert_eq!(vic16.as_str(), "1920x1080p60_16_9");
+        assert_eq!("1920x1080p60_16_9".parse(), Ok(vic16));
+
+        // 1080i50 has two VICs with different totals
+        assert_eq!(video_format(20).unwrap().as_str(), "1920x1080i50_16_9");
+        let vic39 = video_format(39).unwrap();
+        assert_eq!(vic39.as_str(), "1920x1080i50_16_9_1250");
+        assert_eq!("1920x1080i50_16_9_1250".parse(), Ok(vic39));
+
+        // Names are unique and round-trip
+        for vic in (1..=127).chain(193..=219) {
+            let format = video_format(vic).unwrap();
+            assert_eq!(format.as_str().parse::<VideoFormat>().unwrap().vic, vic);
+        }
+
+        assert_eq!(
+            "1920x1080p60".parse::<VideoFormat>(),
+            Err(ParseNameError {
+                name: "1920x1080p60".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_register_video_formats() {
+        // VIC 220 is reserved, so the other tests don't see the override
+        let custom = VideoFormat {
+            vic: 220,
+            ..video_format(16).unwrap()
+        };
+        assert_eq!(video_format(220), None);
+        assert!(register_video_formats(vec![custom]));
+        assert!(!register_video_formats(vec![]));
+        assert_eq!(video_format(220), Some(custom));
+        assert_eq!(custom.as_str(), "1920x1080p60_16_9");
+    }
+}
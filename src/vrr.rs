@@ -0,0 +1,145 @@
+#[cfg(feature = "displayid")]
+use crate::displayid::DisplayIdDataBlock;
+use crate::edid::EDID;
+#[cfg(feature = "displayid")]
+use crate::extension::Extension;
+#[cfg(feature = "cta")]
+use crate::{cta::DataBlock, cta::VendorSpecific};
+
+/// Where a VRR range was advertised.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum VrrSource {
+    /// VRRmin/VRRmax of the HF-VSDB or HF-SCDB.
+    HdmiForum,
+    /// AMD FreeSync vendor block.
+    FreeSync,
+    /// DisplayID 2.x Adaptive-Sync data block.
+    DisplayIdAdaptiveSync,
+}
+
+/// Refresh rate range in Hz.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct VrrRange {
+    pub min: u16,
+    /// `None` if the sink leaves it to the timing, as HDMI allows.
+    pub max: Option<u16>,
+    pub source: VrrSource,
+}
+
+/// VRR support aggregated over the extension blocks.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct VrrInfo {
+    pub ranges: Vec<VrrRange>,
+    /// An NVIDIA vendor block, as present on monitors with a G-Sync module.
+    /// The block content isn't documented, so no range is reported.
+    pub gsync_module: bool,
+}
+
+impl VrrInfo {
+    pub fn is_supported(&self) -> bool {
+        !self.ranges.is_empty() || self.gsync_module
+    }
+
+    /// The widest range advertised with both bounds.
+    pub fn range(&self) -> Option<(u16, u16)> {
+        self.ranges
+            .iter()
+            .filter_map(|range| Some((range.min, range.max?)))
+            .max_by_key(|(min, max)| max.saturating_sub(*min))
+    }
+}
+
+#[cfg(feature = "cta")]
+impl VendorSpecific {
+    pub const NVIDIA_OUI: u32 = 0x00044b;
+}
+
+#[cfg(feature = "cta")]
+fn cta_ranges(edid: &EDID) -> Vec<VrrRange> {
+    let mut ranges = Vec::new();
+    for cta in edid.cta_extensions() {
+        if let Some(hf) = cta.hdmi_forum() {
+            if let Some(min) = hf.vrr_min {
+                ranges.push(VrrRange {
+                    min: min as u16,
+                    max: hf.vrr_max,
+                    source: VrrSource::HdmiForum,
+                });
+            }
+        }
+        for block in &cta.blocks {
+            if let DataBlock::VendorSpecific(vsdb) = block {
+                if let Some(freesync) = vsdb.freesync() {
+                    ranges.push(VrrRange {
+                        min: freesync.min_refresh_rate as u16,
+                        max: Some(freesync.max_refresh_rate as u16),
+                        source: VrrSource::FreeSync,
+                    });
+                }
+            }
+        }
+    }
+    ranges
+}
+
+#[cfg(feature = "cta")]
+fn has_gsync_module(edid: &EDID) -> bool {
+    edid.cta_extensions().any(|cta| {
+        cta.blocks.iter().any(|block| {
+            matches!(block, DataBlock::VendorSpecific(vsdb)
+                if vsdb.oui() == VendorSpecific::NVIDIA_OUI)
+        })
+    })
+}
+
+#[cfg(feature = "displayid")]
+const ADAPTIVE_SYNC_TAG: u8 = 0x2b;
+
+#[cfg(feature = "displayid")]
+fn displayid_ranges(edid: &EDID) -> Vec<VrrRange> {
+    let blocks = edid.extensions.iter().flat_map(|ext| match ext {
+        Extension::DisplayId(displayid) => displayid.blocks.iter(),
+        _ => [].iter(),
+    });
+    let mut ranges = Vec::new();
+    for block in blocks {
+        let block = match block {
+            DisplayIdDataBlock::Reserved(block) if block.header.tag == ADAPTIVE_SYNC_TAG => block,
+            _ => continue,
+        };
+        // 6-byte descriptors: flags, max duration increase, min refresh
+        // rate, 10-bit max refresh rate minus one, max duration decrease
+        for descriptor in block.payload.chunks_exact(6) {
+            ranges.push(VrrRange {
+                min: descriptor[2] as u16,
+                max: Some(((descriptor[4] as u16 & 0x3) << 8 | descriptor[3] as u16) + 1),
+                source: VrrSource::DisplayIdAdaptiveSync,
+            });
+        }
+    }
+    ranges
+}
+
+#[cfg(not(feature = "cta"))]
+fn cta_ranges(_edid: &EDID) -> Vec<VrrRange> {
+    Vec::new()
+}
+
+#[cfg(not(feature = "cta"))]
+fn has_gsync_module(_edid: &EDID) -> bool {
+    false
+}
+
+#[cfg(not(feature = "displayid"))]
+fn displayid_ranges(_edid: &EDID) -> Vec<VrrRange> {
+    Vec::new()
+}
+
+impl EDID {
+    pub fn vrr_capabilities(&self) -> VrrInfo {
+        VrrInfo {
+            ranges: [cta_ranges(self), displayid_ranges(self)].concat(),
+            gsync_module: has_gsync_module(self),
+        }
+    }
+}
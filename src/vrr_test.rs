@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use crate::{builder::CtaBuilder, edid::*, vrr::*};
+
+    #[test]
+    fn test_no_vrr() {
+        let (_, edid) = parse(include_bytes!("../testdata/card0-HDMI-2.bin")).unwrap();
+        let vrr = edid.vrr_capabilities();
+        assert!(!vrr.is_supported());
+        assert_eq!(vrr.range(), None);
+    }
+
+    #[test]
+    fn test_cta_vrr() {
+        let cta = CtaBuilder::new()
+            // HF-VSDB with VRR 48-120 Hz
+            .raw_data_block(
+                3,
+                &[0xd8, 0x5d, 0xc4, 0x01, 0x00, 0x00, 0x00, 0x00, 0x30, 0x78],
+            )
+            // FreeSync 40-144 Hz
+            .raw_data_block(3, &[0x1a, 0x00, 0x00, 0x01, 0x00, 40, 144, 0x00])
+            // NVIDIA
+            .raw_data_block(3, &[0x4b, 0x04, 0x00, 0x01])
+            .build()
+            .unwrap();
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&cta);
+
+        let (_, edid) = parse(&data).unwrap();
+        let vrr = edid.vrr_capabilities();
+        assert_eq!(
+            vrr.ranges,
+            vec![
+                VrrRange {
+                    min: 48,
+                    max: Some(120),
+                    source: VrrSource::HdmiForum,
+                },
+                VrrRange {
+                    min: 40,
+                    max: Some(144),
+                    source: VrrSource::FreeSync,
+                },
+            ]
+        );
+        assert!(vrr.gsync_module);
+        assert_eq!(vrr.range(), Some((40, 144)));
+    }
+
+    #[cfg(feature = "displayid")]
+    #[test]
+    fn test_displayid_adaptive_sync() {
+        let checksum = |bytes: &[u8]| bytes.iter().fold(0u8, |sum, b| sum.wrapping_sub(*b));
+        // Adaptive-Sync block with one 48-165 Hz descriptor
+        let blocks = [0x2b, 0x00, 0x06, 0x00, 0x00, 48, 164, 0x00, 0x00];
+        let mut ext = vec![0x70, 0x20, blocks.len() as u8, 0x00, 0x00];
+        ext.extend_from_slice(&blocks);
+        ext.push(checksum(&ext[1..]));
+        ext.resize(127, 0);
+        ext.push(checksum(&ext));
+        let mut data = include_bytes!("../testdata/card0-VGA-1.bin").to_vec();
+        data[126] = 1;
+        data.extend_from_slice(&ext);
+
+        let (_, edid) = parse(&data).unwrap();
+        assert_eq!(
+            edid.vrr_capabilities().ranges,
+            vec![VrrRange {
+                min: 48,
+                max: Some(165),
+                source: VrrSource::DisplayIdAdaptiveSync,
+            }]
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use nom::{
+    bytes::complete::take, combinator::map, error::VerboseError, multi::count,
+    number::complete::le_u8, sequence::tuple, IResult,
+};
+
+use crate::edid::{
+    parse_detailed_timing, parse_standard_timing_entry, AspectRatio, DetailedTiming, StandardTiming,
+};
+
+/// 3-byte CVT timing code.
+#[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CvtCode {
+    pub vertical_active_lines: u16,
+    pub aspect_ratio: AspectRatio,
+    /// Preferred vertical rate in Hz.
+    pub preferred_refresh_rate: u8,
+    /// Supported vertical rates, see the associated constants.
+    pub supported_refresh_rates: u8,
+}
+
+impl CvtCode {
+    pub const RATE_50HZ: u8 = 1u8 << 4;
+    pub const RATE_60HZ: u8 = 1u8 << 3;
+    pub const RATE_75HZ: u8 = 1u8 << 2;
+    pub const RATE_85HZ: u8 = 1u8 << 1;
+    pub const RATE_60HZ_REDUCED_BLANKING: u8 = 1u8 << 0;
+
+    pub fn horizontal_active_pixels(&self) -> u16 {
+        let (h, v) = self.aspect_ratio.ratio();
+        let pixels = self.vertical_active_lines as u32 * h as u32 / v as u32;
+        (pixels / 8 * 8) as u16
+    }
+}
+
+pub(crate) fn parse_cvt_code(input: &[u8]) -> IResult<&[u8], CvtCode, VerboseError<&[u8]>> {
+    map(
+        tuple((le_u8, le_u8, le_u8)),
+        |(lines_lo, lines_hi, rates)| CvtCode {
+            vertical_active_lines: ((((lines_hi >> 4) as u16) << 8 | lines_lo as u16) + 1) * 2,
+            aspect_ratio: match (lines_hi >> 2) & 0x3 {
+                0b00 => AspectRatio::R4_3,
+                0b01 => AspectRatio::R16_9,
+                0b10 => AspectRatio::R16_10,
+                _ => AspectRatio::R15_9,
+            },
+            preferred_refresh_rate: match (rates >> 5) & 0x3 {
+                0b00 => 50,
+                0b01 => 60,
+                0b10 => 75,
+                _ => 85,
+            },
+            supported_refresh_rates: rates & 0x1f,
+        },
+    )(input)
+}
+
+/// Video Timing Block extension (tag 0x10).
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Vtb {
+    pub version: u8,
+    pub detailed_timings: Vec<DetailedTiming>,
+    pub cvt_codes: Vec<CvtCode>,
+    pub standard_timings: Vec<StandardTiming>,
+}
+
+fn bounded(count: u8, size: usize, input: &[u8]) -> usize {
+    (count as usize).min(input.len() / size)
+}
+
+fn parse_vtb_payload(input: &[u8]) -> IResult<&[u8], Vtb, VerboseError<&[u8]>> {
+    let (input, (version, dtds, cvts, sts)) = tuple((le_u8, le_u8, le_u8, le_u8))(input)?;
+    // Counts past the end of the block are clamped to the entries that fit
+    let (input, detailed_timings) = count(parse_detailed_timing, bounded(dtds, 18, input))(input)?;
+    let (input, cvt_codes) = count(parse_cvt_code, bounded(cvts, 3, input))(input)?;
+    // VTB-EXT requires EDID 1.3 or later
+    let (input, standard_timings) = count(
+        |i| parse_standard_timing_entry(i, 1, 3),
+        bounded(sts, 2, input),
+    )(input)?;
+    Ok((
+        input,
+        Vtb {
+            version,
+            detailed_timings,
+            cvt_codes,
+            standard_timings: standard_timings.into_iter().flatten().collect(),
+        },
+    ))
+}
+
+pub(crate) fn parse_vtb(input: &[u8]) -> IResult<&[u8], Vtb, VerboseError<&[u8]>> {
+    let (input, block) = take(128u8)(input)?;
+    let (_, vtb) = parse_vtb_payload(&block[1..127])?;
+    Ok((input, vtb))
+}